@@ -0,0 +1,103 @@
+//! shell 补全和 man 手册
+//!
+//! 补全脚本在用户按 Tab 时回调 var-gen（设置 `VAR_GEN_COMPLETE` 环境变量），
+//! 由 clap_complete 根据命令行定义给出候选项，因此新增的配置档、映射词无需重新生成脚本。
+
+use std::ffi::OsStr;
+use std::io::Write;
+use std::path::Path;
+
+use clap_complete::env::Shells;
+use clap_complete::{CompleteEnv, CompletionCandidate, Shell};
+
+use super::Args;
+use crate::config::profile::Profile;
+use crate::config::settings;
+use crate::config::SETTINGS;
+use crate::generator::rule::RuleGenerator;
+use crate::utils::error::Error;
+
+/// 补全脚本回调 var-gen 时设置的环境变量
+pub const COMPLETE_ENV: &str = "VAR_GEN_COMPLETE";
+
+const BIN_NAME: &str = "var-gen";
+
+/// 处理补全脚本的回调：设置了 `VAR_GEN_COMPLETE` 时输出候选项并退出，否则直接返回
+pub fn handle_request() {
+    CompleteEnv::with_factory(Args::localized_command).var(COMPLETE_ENV).complete();
+}
+
+/// 输出补全脚本；`static_script` 时生成不回调 var-gen 的脚本，只能补全固定的子命令和选项值
+pub fn write_script(shell: Shell, static_script: bool, out: &mut dyn Write) -> Result<(), Error> {
+    if static_script {
+        // clap_complete 写入失败时会 panic，先写到缓冲区
+        let mut script = Vec::new();
+        clap_complete::generate(shell, &mut Args::localized_command(), BIN_NAME, &mut script);
+        out.write_all(&script)?;
+        return Ok(());
+    }
+
+    let shells = Shells::builtins();
+    let completer = shells
+        .completer(&shell.to_string())
+        .ok_or_else(|| Error::ConfigError(t!("{shell} 不支持动态补全，请使用 --static", shell = shell)))?;
+    // 使用当前程序的绝对路径，PATH 中有多个版本时也回调同一个程序
+    let program = std::env::current_exe()
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| BIN_NAME.to_string());
+    completer.write_registration(COMPLETE_ENV, BIN_NAME, BIN_NAME, &program, out)?;
+    Ok(())
+}
+
+/// 生成 man 手册：指定目录时为主命令和每个子命令各写一页（var-gen.1、var-gen-gen.1 等），
+/// 否则把主命令的页面输出到标准输出
+pub fn write_man(dir: Option<&Path>) -> Result<(), Error> {
+    let cmd = Args::localized_command();
+    match dir {
+        Some(dir) => {
+            std::fs::create_dir_all(dir)?;
+            clap_mangen::generate_to(cmd, dir)?;
+        }
+        None => clap_mangen::Man::new(cmd).render(&mut std::io::stdout())?,
+    }
+    Ok(())
+}
+
+/// 可用的领域配置档（内置和 ~/.var-gen/profiles 中的）
+pub fn profiles() -> Vec<CompletionCandidate> {
+    Profile::available().into_iter().map(CompletionCandidate::new).collect()
+}
+
+/// 配置项，附带说明
+pub fn settings() -> Vec<CompletionCandidate> {
+    SETTINGS
+        .iter()
+        .map(|(key, description)| CompletionCandidate::new(key).help(Some((*description).into())))
+        .collect()
+}
+
+/// 映射表中以已输入内容开头的词，附带英文译名；未输入任何内容时不给候选，避免列出整张词表
+pub fn mapping_words(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(prefix) = current.to_str().filter(|prefix| !prefix.is_empty()) else {
+        return Vec::new();
+    };
+    let path = mapping_config_path();
+    let Ok(mappings) = RuleGenerator::load_mappings(path.as_deref()) else {
+        return Vec::new();
+    };
+
+    let mut words: Vec<(String, String)> = mappings
+        .into_iter()
+        .filter(|(word, _)| word.starts_with(prefix))
+        .collect();
+    words.sort();
+    words
+        .into_iter()
+        .map(|(word, english)| CompletionCandidate::new(word).help(Some(english.into())))
+        .collect()
+}
+
+/// 补全时不打开存储，只从环境变量和用户配置文件中读取映射文件路径；文件不存在时使用内置词典
+fn mapping_config_path() -> Option<String> {
+    settings::peek(|settings| settings.mapping_config_path).filter(|path| Path::new(path).is_file())
+}
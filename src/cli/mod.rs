@@ -1,209 +1,1581 @@
-use clap::{Parser, ValueEnum};
-use serde::{Deserialize, Serialize};
+use clap::error::ErrorKind;
+use clap::{ArgGroup, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum, ValueHint};
+use clap_complete::{ArgValueCandidates, ArgValueCompleter, Shell};
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
 
 use colored::Colorize;
 
 
-use crate::config::Config;
-use crate::generator::Generator;
-use crate::storage::Storage;
+use crate::config::mapping::MappingConfig;
+use crate::config::profile::Profile;
+use crate::config::project::{find_project_config, LintSettings, ProjectConfig};
+use crate::config::provider::Provider;
+use crate::config::secret::{self, ApiKey};
+use crate::config::settings::{self, Settings};
+use crate::config::{self, Config, Source};
+use crate::generator::rule::abbreviation::AbbreviationPreference;
+use crate::generator::rule::casing::{convert_identifier, detect_style, AcronymPolicy, Conversion};
+use crate::generator::collision::ProjectIndex;
+use crate::generator::context::CodeContext;
+use crate::generator::{GenerateOptions, Generation, Generator};
+use crate::i18n::{self, Locale};
+use crate::lint::{report, Linter};
+use crate::lsp;
+#[cfg(feature = "server")]
+use crate::server::{self, AppState, Limits};
+use crate::storage::{StorageBackend, Store};
+use crate::utils::duration::format_duration;
 use crate::utils::error::Error;
 
+pub use crate::naming::{IdentifierKind, Language, NamingStyle};
+
+mod complete;
+#[cfg(feature = "interactive")]
+mod repl;
+#[cfg(feature = "interactive")]
+use repl::run_interactive;
+#[cfg(feature = "tui")]
+mod tui;
+#[cfg(feature = "tui")]
+use tui::run_tui;
+
+/// 进程退出码，见 [`Error::exit_code`]
+const EXIT_CODES: &str = "退出码：
+  0  成功
+  1  执行失败（如生成失败、lint 发现命名问题）
+  2  命令行参数错误
+  3  配置错误，或编译时未启用所需特性
+  4  大模型服务不可用（网络错误、认证失败）
+  5  存储错误
+  6  文件读写错误";
+
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, after_help = EXIT_CODES)]
 pub struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    
+    /// 领域配置档名称（如 commerce、support、streaming）
+    #[arg(short, long, global = true, add = ArgValueCandidates::new(complete::profiles))]
+    profile: Option<String>,
+    
+    /// 本次使用的大模型服务商（不改变默认设置）
+    #[arg(long, global = true)]
+    provider: Option<Provider>,
+    
+    /// 本次使用的模型，可推断服务商时一并切换（如 qwen-max、generalv3.5）
+    #[arg(long, global = true)]
+    model: Option<String>,
+    
+    /// 本次使用的词汇映射配置文件路径（长期使用请执行 config set mapping_config_path）
+    #[arg(long, global = true, value_hint = ValueHint::FilePath)]
+    mapping_config: Option<String>,
+    
+    /// 界面语言（默认按用户配置 ui_lang 或 LANG 环境变量确定）
+    #[arg(long, global = true, value_name = "LOCALE")]
+    lang_ui: Option<Locale>,
+    
+    #[command(flatten)]
+    legacy: LegacyArgs,
+}
+
+impl Args {
+    /// 解析命令行参数；旧版本的参数不能与子命令同时使用（全局参数除外）。
+    /// 先确定界面语言，帮助信息和之后的输出都使用该语言；补全脚本的回调在这里处理后直接退出
+    pub fn parse_args() -> Self {
+        i18n::set_locale(ui_locale(std::env::args_os()));
+        complete::handle_request();
+        let mut command = Args::localized_command();
+        let args = Args::from_arg_matches(&command.get_matches_mut()).unwrap_or_else(|e| e.format(&mut command).exit());
+        if args.command.is_some() && args.legacy != LegacyArgs::default() {
+            command
+                .error(ErrorKind::ArgumentConflict, t!("旧版本的参数（如 -d、--file、--history）不能与子命令同时使用"))
+                .exit();
+        }
+        args
+    }
+    
+    /// 帮助文字按当前界面语言翻译的命令定义
+    pub fn localized_command() -> clap::Command {
+        localize(Args::command())
+    }
+}
+
+/// 翻译命令、子命令和参数的说明文字
+fn localize(command: clap::Command) -> clap::Command {
+    fn text(text: Option<&clap::builder::StyledStr>) -> Option<&'static str> {
+        i18n::lookup(i18n::locale(), &text?.to_string())
+    }
+    
+    let mut command = command
+        .mut_args(|arg| match text(arg.get_help()) {
+            Some(help) => arg.help(help),
+            None => arg,
+        })
+        .mut_subcommands(localize);
+    if let Some(about) = text(command.get_about()) {
+        command = command.about(about);
+    }
+    if let Some(after_help) = text(command.get_after_help()) {
+        command = command.after_help(after_help);
+    }
+    command
+}
+
+/// 界面语言：--lang-ui > 环境变量 VAR_GEN_UI_LANG > 用户配置 ui_lang > LC_ALL/LC_MESSAGES/LANG > zh-CN。
+/// 在解析命令行之前确定，以便翻译帮助信息；无效的 --lang-ui 留给 clap 报错
+fn ui_locale(args: impl IntoIterator<Item = std::ffi::OsString>) -> Locale {
+    let mut args = args.into_iter().map(|arg| arg.to_string_lossy().into_owned());
+    let mut from_args = None;
+    while let Some(arg) = args.next() {
+        let value = match arg.strip_prefix("--lang-ui") {
+            Some("") => args.next(),
+            Some(value) => value.strip_prefix('=').map(str::to_string),
+            None if arg == "--" => break,
+            None => None,
+        };
+        if let Some(locale) = value.and_then(|value| value.parse().ok()) {
+            from_args = Some(locale);
+        }
+    }
+    from_args
+        .or_else(|| settings::peek(|settings| settings.ui_lang))
+        .or_else(Locale::from_env)
+        .unwrap_or_default()
+}
+
+/// 旧版本的参数，保留为隐藏的兼容写法，使用时提示改用对应的子命令
+#[derive(clap::Args, Debug, Default, PartialEq)]
+#[command(group(ArgGroup::new("legacy_action").args([
+    "description", "interactive", "file", "all_styles", "list_profiles",
+    "history", "clear_history", "set_api_key", "clear_api_key",
+])))]
+#[command(group(ArgGroup::new("legacy_naming").multiple(true).args([
+    "style", "kind", "lang", "max_length", "abbreviation", "acronyms",
+    "context_file", "check_collisions", "force_rule", "output",
+])))]
+struct LegacyArgs {
+    /// 改用 `gen <描述>`
+    #[arg(short, long, hide = true)]
+    description: Option<String>,
+    
+    #[arg(short, long, hide = true)]
+    style: Option<NamingStyle>,
+    
+    #[arg(short, long, hide = true)]
+    kind: Option<IdentifierKind>,
+    
+    #[arg(short, long, hide = true)]
+    lang: Option<Language>,
+    
+    #[arg(long, hide = true)]
+    max_length: Option<usize>,
+    
+    #[arg(long, hide = true)]
+    abbreviation: Option<AbbreviationPreference>,
+    
+    #[arg(long, hide = true)]
+    acronyms: Option<AcronymPolicy>,
+    
+    /// 改用 `interactive`
+    #[arg(long, hide = true)]
+    interactive: bool,
+    
+    /// 改用 `batch <文件>`
+    #[arg(long, hide = true)]
+    file: Option<String>,
+    
+    #[arg(long, hide = true)]
+    context_file: Option<String>,
+    
+    #[arg(long, hide = true, value_name = "DIR")]
+    check_collisions: Option<String>,
+    
+    #[arg(long, hide = true, requires = "file")]
+    output: Option<String>,
+    
+    #[arg(long, hide = true)]
+    json: bool,
+    
+    /// 改用 `styles`
+    #[arg(long, hide = true, conflicts_with = "legacy_naming")]
+    all_styles: bool,
+    
+    /// 改用 `profiles`
+    #[arg(long, hide = true, conflicts_with = "legacy_naming")]
+    list_profiles: bool,
+    
+    /// 改用 `history`
+    #[arg(long, hide = true, conflicts_with = "legacy_naming")]
+    history: bool,
+    
+    /// 改用 `history clear`
+    #[arg(long, hide = true, conflicts_with = "legacy_naming")]
+    clear_history: bool,
+    
+    #[arg(long, hide = true)]
+    force_rule: bool,
+    
+    /// 改用 `provider set-key`
+    #[arg(long, hide = true, conflicts_with = "legacy_naming")]
+    set_api_key: Option<String>,
+    
+    #[arg(long, hide = true, requires = "set_api_key")]
+    encrypt: bool,
+    
+    /// 改用 `provider clear-key`
+    #[arg(long, hide = true, conflicts_with = "legacy_naming")]
+    clear_api_key: bool,
+}
+
+impl LegacyArgs {
+    /// 转换为对应的子命令；没有任何操作参数时进入交互模式
+    fn into_command(self) -> Command {
+        let naming = NamingArgs {
+            style: self.style,
+            kind: self.kind,
+            lang: self.lang,
+            max_length: self.max_length,
+            abbreviation: self.abbreviation,
+            acronyms: self.acronyms,
+            force_rule: self.force_rule,
+        };
+        let generate = GenerateArgs {
+            naming,
+            context_file: self.context_file,
+            check_collisions: self.check_collisions,
+            json: self.json,
+        };
+        
+        let (flag, replacement, command) = if self.all_styles {
+            ("--all-styles", "var-gen styles", Command::Styles)
+        } else if self.list_profiles {
+            ("--list-profiles", "var-gen profiles", Command::Profiles)
+        } else if self.history {
+            let history = HistoryArgs { action: None, limit: HISTORY_LIMIT, json: self.json };
+            ("--history", "var-gen history", Command::History(history))
+        } else if self.clear_history {
+            let history = HistoryArgs { action: Some(HistoryAction::Clear { yes: false }), limit: HISTORY_LIMIT, json: false };
+            ("--clear-history", "var-gen history clear", Command::History(history))
+        } else if let Some(key) = self.set_api_key {
+            let action = ProviderAction::SetKey { key: Some(key), encrypt: self.encrypt };
+            ("--set-api-key", "var-gen provider set-key", Command::Provider(ProviderArgs { action }))
+        } else if self.clear_api_key {
+            let action = ProviderAction::ClearKey;
+            ("--clear-api-key", "var-gen provider clear-key", Command::Provider(ProviderArgs { action }))
+        } else if let Some(file) = self.file {
+            let batch = BatchArgs { file, output: self.output, generate };
+            ("--file", t!("var-gen batch <文件>"), Command::Batch(batch))
+        } else if self.interactive {
+            ("--interactive", "var-gen interactive", Command::Interactive(generate.naming))
+        } else if let Some(description) = self.description {
+            ("--description", t!("var-gen gen <描述>"), Command::Gen(GenArgs { description, generate }))
+        } else {
+            // 不带参数运行时进入交互模式，不是弃用的写法
+            return Command::Interactive(generate.naming);
+        };
+        
+        eprintln!("{} {}", t!("警告:").yellow(), t!("{flag} 已弃用，请改用 `{replacement}`", flag = flag, replacement = replacement));
+        command
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// 根据描述生成变量名
+    Gen(GenArgs),
+    
+    /// 批量生成：逐行读取文件中的描述
+    Batch(BatchArgs),
+    
+    /// 交互式生成变量名（不带子命令运行时的默认模式）
+    Interactive(NamingArgs),
+    
+    /// 全屏终端界面：实时预览候选名称在各命名规范下的写法（输出不是终端时改用交互式模式）
+    Tui(NamingArgs),
+    
+    /// 查看、清除、导入或导出历史记录
+    History(HistoryArgs),
+    
+    /// 在命名规范之间转换已有标识符（不翻译、不做词形处理）
+    Convert(ConvertArgs),
+    
+    /// 检查源代码中的标识符命名（命名规范、拼音、中文、缩写一致性、保留字）
+    #[command(after_help = "退出码：0 未发现问题，1 发现命名问题，6 无法读取文件")]
+    Lint(LintArgs),
+    
+    /// 为命名不佳的已有标识符（如 yhxx、data2、tmp）推荐新名称
+    SuggestRename(SuggestRenameArgs),
+    
+    /// 以 LSP 服务运行（通过标准输入输出与编辑器通信）
+    Lsp(LspArgs),
+    
+    /// 启动本地 HTTP/JSON 接口服务
+    Serve(ServeArgs),
+    
+    /// 查看和修改配置
+    Config(ConfigArgs),
+    
+    /// 管理大模型服务商和 API 密钥
+    Provider(ProviderArgs),
+    
+    /// 管理自定义词汇映射文件
+    Mapping(MappingArgs),
+    
+    /// 列出支持的命名规范
+    Styles,
+    
+    /// 列出可用的领域配置档
+    Profiles,
+    
+    /// 查看或清除大模型结果缓存
+    Cache(CacheArgs),
+    
+    /// 输出 shell 补全脚本
+    #[command(after_help = "bash: var-gen completions bash > ~/.local/share/bash-completion/completions/var-gen
+zsh:  var-gen completions zsh > ~/.zfunc/_var-gen（~/.zfunc 需在 fpath 中）
+fish: var-gen completions fish > ~/.config/fish/completions/var-gen.fish")]
+    Completions(CompletionsArgs),
+    
+    /// 生成 roff 格式的 man 手册
+    Man(ManArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CompletionsArgs {
+    shell: Shell,
+    
+    /// 生成不回调 var-gen 的静态脚本（不能补全配置档、配置项和映射词）
+    #[arg(long = "static")]
+    static_script: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ManArgs {
+    /// 为主命令和每个子命令各生成一页，写入该目录（如 /usr/local/share/man/man1）；未指定时输出主页面
+    #[arg(short, long, value_hint = ValueHint::DirPath)]
+    output: Option<PathBuf>,
+}
+
+/// 影响命名结果的参数，gen、batch 和 interactive 共用
+#[derive(clap::Args, Debug)]
+pub struct NamingArgs {
+    /// 命名规范风格（未指定时依次取环境变量、项目配置、用户配置、配置档、语言惯例，否则为 snake）
+    #[arg(short, long)]
+    style: Option<NamingStyle>,
+    
+    /// 标识符类型（变量、函数、类型等）
+    #[arg(short, long)]
+    kind: Option<IdentifierKind>,
+    
+    /// 目标编程语言
+    #[arg(short, long)]
+    lang: Option<Language>,
+    
+    /// 标识符最大长度目标，超出时按缩写词典缩短
+    #[arg(long)]
+    max_length: Option<usize>,
+    
+    /// 缩写偏好：full（完整单词）、abbreviated（尽量缩写）、auto（超长时缩写）
+    #[arg(long)]
+    abbreviation: Option<AbbreviationPreference>,
+    
+    /// 缩略词大小写：preserve（userID、HTTPClient）或 capitalize（userId、HttpClient）
+    #[arg(long)]
+    acronyms: Option<AcronymPolicy>,
+    
+    /// 强制使用规则引擎（不调用大模型）
+    #[arg(long)]
+    force_rule: bool,
+}
+
+/// gen 和 batch 共用的参数
+#[derive(clap::Args, Debug)]
+pub struct GenerateArgs {
+    #[command(flatten)]
+    naming: NamingArgs,
+    
+    /// 代码上下文：path[:行范围]（如 src/main.rs:10-40），`-` 表示从标准输入读取
+    #[arg(long)]
+    context_file: Option<String>,
+    
+    /// 检查候选名称是否与项目目录中已声明的标识符重名
+    #[arg(long, value_name = "DIR")]
+    check_collisions: Option<String>,
+    
+    /// 以JSON格式输出
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct GenArgs {
     /// 变量描述文本
+    #[arg(add = ArgValueCompleter::new(complete::mapping_words))]
+    description: String,
+    
+    #[command(flatten)]
+    generate: GenerateArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct BatchArgs {
+    /// 描述文件路径，每行一条描述
+    #[arg(value_hint = ValueHint::FilePath)]
+    file: String,
+    
+    /// 输出文件路径（默认输出到终端）
     #[arg(short, long)]
-    description: Option<String>,
+    output: Option<String>,
     
-    /// 命名规范风格
-    #[arg(short, long, default_value = "snake")]
-    style: NamingStyle,
+    #[command(flatten)]
+    generate: GenerateArgs,
+}
+
+/// 默认显示的历史记录条数
+const HISTORY_LIMIT: usize = 20;
+
+#[derive(clap::Args, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct HistoryArgs {
+    #[command(subcommand)]
+    action: Option<HistoryAction>,
     
-    /// 强制使用交互式模式
+    /// 显示最近的条数
+    #[arg(short = 'n', long, default_value_t = HISTORY_LIMIT)]
+    limit: usize,
+    
+    /// 以JSON格式输出
     #[arg(long)]
-    interactive: bool,
+    json: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum HistoryAction {
+    /// 清除所有历史记录
+    Clear {
+        /// 不询问，直接清除
+        #[arg(short, long)]
+        yes: bool,
+    },
+    
+    /// 把全部历史记录导出为 JSON 文件
+    Export {
+        path: PathBuf,
+    },
+    
+    /// 从 JSON 文件导入历史记录（追加到现有记录之后）
+    Import {
+        path: PathBuf,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+pub struct MappingArgs {
+    #[command(subcommand)]
+    action: MappingAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum MappingAction {
+    /// 显示当前使用的词汇映射文件及其来源
+    Path,
+    
+    /// 生成一份可编辑的词汇映射文件（扩展名为 .json 时使用 JSON，否则使用 TOML）
+    Init {
+        /// 文件路径，默认为 ~/.var-gen/custom-mapping.json
+        path: Option<PathBuf>,
+        
+        /// 覆盖已存在的文件
+        #[arg(long)]
+        force: bool,
+    },
+    
+    /// 检查词汇映射文件能否解析（默认检查当前使用的文件）
+    Check {
+        path: Option<PathBuf>,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CacheArgs {
+    #[command(subcommand)]
+    action: CacheAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CacheAction {
+    /// 显示缓存是否开启及有效期
+    Status,
+    
+    /// 清除所有缓存的大模型结果
+    Clear {
+        /// 不询问，直接清除
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ProviderArgs {
+    #[command(subcommand)]
+    action: ProviderAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ProviderAction {
+    /// 列出各服务商的模型和密钥状态
+    List,
+    
+    /// 请求各服务商的接口，检查密钥是否可用
+    #[command(after_help = "退出码：0 全部可用，4 有服务商不可用")]
+    Test {
+        /// 只检查指定的服务商
+        provider: Option<Provider>,
+    },
+    
+    /// 切换默认服务商
+    Use {
+        provider: Provider,
+        
+        /// 同时设置该服务商使用的模型
+        #[arg(long)]
+        model: Option<String>,
+    },
+    
+    /// 保存当前服务商（或 --provider 指定的服务商）的 API 密钥
+    SetKey {
+        /// 密钥；省略或为 `-` 时从标准输入读取，避免留在命令历史中
+        key: Option<String>,
+        
+        /// 用口令加密保存（口令取自 VAR_GEN_KEY_PASSPHRASE 或交互输入）
+        #[arg(long)]
+        encrypt: bool,
+    },
+    
+    /// 删除当前服务商（或 --provider 指定的服务商）保存的 API 密钥
+    ClearKey,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    action: ConfigAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigAction {
+    /// 显示当前生效的配置（API 密钥只显示来源和指纹）
+    Show,
+    
+    /// 读取一个配置项
+    Get {
+        #[arg(add = ArgValueCandidates::new(complete::settings))]
+        key: String,
+    },
+    
+    /// 校验并保存一个配置项
+    Set {
+        #[arg(add = ArgValueCandidates::new(complete::settings))]
+        key: String,
+        value: String,
+    },
+    
+    /// 删除一个配置项，恢复默认值
+    Unset {
+        #[arg(add = ArgValueCandidates::new(complete::settings))]
+        key: String,
+    },
+    
+    /// 列出所有配置项及其来源
+    List,
+    
+    /// 重置所有配置并删除当前服务商的 API 密钥
+    Reset {
+        /// 不询问，直接重置
+        #[arg(short, long)]
+        yes: bool,
+    },
+    
+    /// 用 $VISUAL 或 $EDITOR 编辑用户配置文件，保存时校验全部配置项
+    Edit,
+    
+    /// 显示用户配置文件的路径
+    Path,
+    
+    /// 说明每个配置项的生效值来自哪里
+    Explain {
+        /// 按该语言的惯例确定默认命名规范
+        #[arg(short, long)]
+        lang: Option<Language>,
+        
+        /// 标识符类型，影响语言惯例
+        #[arg(short, long)]
+        kind: Option<IdentifierKind>,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ConvertArgs {
+    /// 要转换的标识符；未提供时从 --file 或标准输入读取（每行可包含多个）
+    identifiers: Vec<String>,
+    
+    /// 目标命名规范
+    #[arg(short, long)]
+    to: NamingStyle,
     
-    /// 批量处理文件路径
+    /// 从文件读取标识符
     #[arg(long)]
     file: Option<String>,
     
-    /// 输出文件路径
+    /// 缩略词大小写策略（默认沿用输入中的写法）
     #[arg(long)]
-    output: Option<String>,
+    acronyms: Option<AcronymPolicy>,
+    
+    /// 目标编程语言，决定默认的缩略词策略
+    #[arg(short, long)]
+    lang: Option<Language>,
     
-    /// 显示所有支持的命名规范
+    /// 以JSON格式输出
     #[arg(long)]
-    all_styles: bool,
+    json: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct LintArgs {
+    /// 要检查的文件或目录
+    #[arg(default_value = ".")]
+    path: String,
     
-    /// 显示历史记录
+    /// 输出格式
+    #[arg(long, value_enum, default_value_t = LintFormat::Text)]
+    format: LintFormat,
+    
+    /// 输出文件路径
     #[arg(long)]
-    history: bool,
+    output: Option<String>,
+    
+    /// 只检查指定语言的文件（单个文件无法识别扩展名时也用于指定语言）
+    #[arg(short, long)]
+    lang: Option<Language>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct SuggestRenameArgs {
+    /// 要重命名的标识符
+    identifier: String,
+    
+    /// 上下文（周围的代码片段或注释）
+    #[arg(short, long)]
+    context: Option<String>,
     
-    /// 清除历史记录
+    /// 从文件读取代码上下文：path[:行范围]，`-` 表示从标准输入读取
     #[arg(long)]
-    clear_history: bool,
+    context_file: Option<String>,
+    
+    /// 命名规范风格（未指定时沿用原标识符的风格）
+    #[arg(short, long)]
+    style: Option<NamingStyle>,
+    
+    /// 标识符类型
+    #[arg(short, long)]
+    kind: Option<IdentifierKind>,
+    
+    /// 目标编程语言
+    #[arg(short, long)]
+    lang: Option<Language>,
     
+    /// 强制使用规则引擎（只做拼音缩写解码，不调用大模型）
+    #[arg(long)]
+    force_rule: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct LspArgs {
     /// 强制使用规则引擎（不调用大模型）
     #[arg(long)]
     force_rule: bool,
+}
+
+#[derive(clap::Args, Debug)]
+#[cfg_attr(not(feature = "server"), allow(dead_code))]
+pub struct ServeArgs {
+    /// 监听地址
+    #[arg(long, default_value = "127.0.0.1:7878")]
+    bind: std::net::SocketAddr,
     
-    /// 设置API密钥
+    /// 访问令牌（也可通过环境变量 VAR_GEN_SERVER_TOKEN 设置），设置后请求需携带 `Authorization: Bearer <token>`
     #[arg(long)]
-    set_api_key: Option<String>,
+    token: Option<String>,
     
-    /// 清除API密钥
-    #[arg(long)]
-    clear_api_key: bool,
+    /// 请求体最大字节数
+    #[arg(long, default_value_t = 64 * 1024)]
+    max_body_bytes: usize,
+    
+    /// 批量生成、转换时单次请求的最大条数
+    #[arg(long, default_value_t = 100)]
+    max_batch: usize,
+    
+    /// 同时处理的最大请求数
+    #[arg(long, default_value_t = 16)]
+    concurrency: usize,
     
-    /// 词汇映射配置文件路径
+    /// 生成超时时间（秒），批量请求整批共用
+    #[arg(long, default_value_t = 30)]
+    timeout: u64,
+    
+    /// 强制使用规则引擎（不调用大模型）
     #[arg(long)]
-    mapping_config: Option<String>,
+    force_rule: bool,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug, Serialize, Deserialize)]
-pub enum NamingStyle {
-    #[clap(name = "camel")]
-    Camel,
-    #[clap(name = "pascal")]
-    Pascal,
-    #[clap(name = "snake")]
-    Snake,
-    #[clap(name = "kebab")]
-    Kebab,
-    #[clap(name = "upper_snake")]
-    UpperSnake,
-    #[clap(name = "lower_camel")]
-    LowerCamel,
+/// lint 报告格式
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum, Debug)]
+pub enum LintFormat {
+    #[clap(name = "text")]
+    Text,
+    #[clap(name = "sarif")]
+    Sarif,
+}
+
+
+/// 命令行中只对本次运行生效的配置档、服务商、模型和词汇映射文件
+struct Selection<'a> {
+    profile: Option<&'a str>,
+    provider: Option<Provider>,
+    model: Option<&'a str>,
+    mapping_config: Option<&'a str>,
+    lang_ui: Option<Locale>,
 }
 
-impl std::fmt::Display for NamingStyle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            NamingStyle::Camel => write!(f, "驼峰命名法 (camelCase)"),
-            NamingStyle::Pascal => write!(f, "帕斯卡命名法 (PascalCase)"),
-            NamingStyle::Snake => write!(f, "下划线命名法 (snake_case)"),
-            NamingStyle::Kebab => write!(f, "短横线命名法 (kebab-case)"),
-            NamingStyle::UpperSnake => write!(f, "大写下划线命名法 (UPPER_SNAKE_CASE)"),
-            NamingStyle::LowerCamel => write!(f, "小驼峰命名法 (lowerCamelCase)"),
+impl Selection<'_> {
+    /// 加载配置，再应用命令行的选择（优先于环境变量、项目和用户配置）
+    fn load_config(&self, storage: Arc<dyn Store>) -> Result<Config, Error> {
+        let mut config = Config::new(storage)?;
+        if let Some(profile) = self.profile {
+            config.use_profile(profile);
         }
+        if let Some(provider) = self.provider {
+            config.use_provider(provider)?;
+        }
+        if let Some(model) = self.model {
+            config.use_model(model)?;
+        }
+        if let Some(path) = self.mapping_config {
+            config.use_mapping_config_path(path);
+        }
+        if let Some(locale) = self.lang_ui {
+            config.use_ui_lang(locale);
+        }
+        Ok(config)
     }
 }
 
 pub async fn run(args: Args) -> Result<(), Error> {
-    // 初始化存储
-    let storage = Arc::new(Storage::new()?);
+    let selection = Selection {
+        profile: args.profile.as_deref(),
+        provider: args.provider,
+        model: args.model.as_deref(),
+        mapping_config: args.mapping_config.as_deref(),
+        lang_ui: args.lang_ui,
+    };
+    let command = match args.command {
+        Some(command) => command,
+        None => args.legacy.into_command(),
+    };
     
-    // 初始化配置
-    let mut config = Config::new(storage.clone())?;
+    // 每个子命令只打开自己需要的存储、配置和生成器
+    match command {
+        Command::Gen(gen_args) => {
+            run_generate(gen_args.generate, Input::Description(gen_args.description), &selection).await
+        }
+        Command::Batch(batch_args) => {
+            let input = Input::File { path: batch_args.file, output: batch_args.output };
+            run_generate(batch_args.generate, input, &selection).await
+        }
+        Command::Interactive(naming_args) => {
+            let storage = open_storage()?;
+            let config = Arc::new(selection.load_config(storage.clone())?);
+            let generator = Generator::new(config.clone())?;
+            run_interactive(storage, config, Arc::new(generator), &naming_args).await
+        }
+        Command::Tui(naming_args) => {
+            let storage = open_storage()?;
+            let config = Arc::new(selection.load_config(storage.clone())?);
+            let generator = Generator::new(config.clone())?;
+            run_tui(storage, config, Arc::new(generator), &naming_args).await
+        }
+        Command::History(history_args) => run_history(history_args),
+        // convert、lint、styles、completions 和 man 不依赖存储和生成器
+        Command::Convert(convert_args) => run_convert(convert_args),
+        Command::Lint(lint_args) => run_lint(lint_args),
+        Command::SuggestRename(rename_args) => run_suggest_rename(rename_args, &selection).await,
+        Command::Lsp(lsp_args) => {
+            let generator = open_generator(open_storage()?, &selection)?;
+            lsp::run(&generator, lsp_args.force_rule).await
+        }
+        Command::Serve(serve_args) => run_serve(serve_args, &selection).await,
+        Command::Config(config_args) => run_config(config_args, &selection),
+        Command::Provider(provider_args) => run_provider(provider_args, &selection).await,
+        Command::Mapping(mapping_args) => run_mapping(mapping_args, &selection),
+        Command::Styles => {
+            print_all_styles();
+            Ok(())
+        }
+        Command::Profiles => {
+            let config = selection.load_config(open_storage()?)?;
+            print_profiles(config.profile());
+            Ok(())
+        }
+        Command::Cache(cache_args) => run_cache(cache_args, &selection),
+        Command::Completions(completions_args) => {
+            complete::write_script(completions_args.shell, completions_args.static_script, &mut std::io::stdout())
+        }
+        Command::Man(man_args) => complete::write_man(man_args.output.as_deref()),
+    }
+}
+
+impl NamingArgs {
+    /// 命令行参数优先，其次沿用上下文中的命名风格和语言，最后按配置确定默认值
+    fn options(&self, generator: &Generator, context: Option<&CodeContext>) -> GenerateOptions {
+        let style = self
+            .style
+            .or_else(|| context.and_then(|c| c.dominant_style(self.kind)));
+        let language = self.lang.or_else(|| context.and_then(|c| c.language));
+        GenerateOptions {
+            max_length: self.max_length,
+            abbreviation: self.abbreviation,
+            acronyms: self.acronyms,
+            ..generator.resolve_options(style, self.kind, language)
+        }
+    }
+}
+
+/// 生成的输入：单条描述或描述文件
+enum Input {
+    Description(String),
+    File { path: String, output: Option<String> },
+}
+
+async fn run_generate(args: GenerateArgs, input: Input, selection: &Selection<'_>) -> Result<(), Error> {
+    let storage = open_storage()?;
+    let generator = open_generator(storage.clone(), selection)?;
+    
+    // 代码上下文
+    let context = args
+        .context_file
+        .as_deref()
+        .map(|spec| CodeContext::load(spec, args.naming.lang))
+        .transpose()?;
+    let options = args.naming.options(&generator, context.as_ref());
+    
+    // 项目标识符索引（按文件修改时间缓存在数据库中）
+    let index = args
+        .check_collisions
+        .as_deref()
+        .map(|dir| ProjectIndex::build(Path::new(dir), storage.as_ref()))
+        .transpose()?;
+    
+    let run = GenerateRun {
+        generator: &generator,
+        context: context.as_ref(),
+        index: index.as_ref(),
+        options: &options,
+        force_rule: args.naming.force_rule,
+        json: args.json,
+    };
     
-    // 如果指定了映射配置文件路径，更新配置
-    if let Some(mapping_path) = &args.mapping_config {
-        config.set_mapping_config_path(mapping_path)?;
-        println!("使用自定义词汇映射配置文件: {}", mapping_path);
+    match input {
+        Input::Description(description) => generate_single(&description, &run, storage).await,
+        Input::File { path, output } => process_file(&path, &output, &run).await,
+    }
+}
+
+fn run_history(args: HistoryArgs) -> Result<(), Error> {
+    let storage = open_storage()?;
+    match args.action {
+        None => print_history(storage, args.limit, args.json),
+        Some(HistoryAction::Clear { yes }) => clear_history(storage, yes),
+        Some(HistoryAction::Export { path }) => {
+            storage.export_history(&path)?;
+            println!("{}", t!("历史记录已导出到 {path}", path = path.display()));
+            Ok(())
+        }
+        Some(HistoryAction::Import { path }) => {
+            storage.import_history(&path)?;
+            println!("{}", t!("已从 {path} 导入历史记录", path = path.display()));
+            Ok(())
+        }
+    }
+}
+
+fn run_mapping(args: MappingArgs, selection: &Selection<'_>) -> Result<(), Error> {
+    match args.action {
+        MappingAction::Path => {
+            let config = selection.load_config(open_storage()?)?;
+            match config.mapping_config_path() {
+                Some(path) => println!("{}", t!("{value}（来自{source}）", value = path, source = config.source("mapping_config_path")?)),
+                None => println!("{}", t!("未设置，使用内置词表")),
+            }
+        }
+        MappingAction::Init { path, force } => {
+            let path = match path {
+                Some(path) => path,
+                None => dirs::home_dir()
+                    .ok_or_else(|| Error::ConfigError(t!("无法确定用户目录").to_string()))?
+                    .join(".var-gen")
+                    .join("custom-mapping.json"),
+            };
+            if path.exists() && !force {
+                return Err(Error::ConfigError(t!("{path} 已存在，使用 --force 覆盖", path = path.display())));
+            }
+            MappingConfig::default().save_to_file(&path)?;
+            println!("{}", t!("已生成词汇映射文件 {path}", path = path.display()));
+            println!("{}", t!("编辑后执行 `var-gen config set mapping_config_path {path}` 启用", path = path.display()));
+        }
+        MappingAction::Check { path } => {
+            let path = match path {
+                Some(path) => path,
+                None => {
+                    let config = selection.load_config(open_storage()?)?;
+                    let path = config.mapping_config_path().ok_or_else(|| {
+                        Error::ConfigError(t!("未设置词汇映射文件，请指定要检查的文件").to_string())
+                    })?;
+                    PathBuf::from(path)
+                }
+            };
+            let mapping = MappingConfig::from_file(&path)?;
+            println!(
+                "{}",
+                t!(
+                    "{path}: {mappings} 条映射，{stop_words} 个停用词",
+                    path = path.display(),
+                    mappings = mapping.mappings().len(),
+                    stop_words = mapping.stop_words().len()
+                )
+            );
+        }
+    }
+    Ok(())
+}
+
+fn run_cache(args: CacheArgs, selection: &Selection<'_>) -> Result<(), Error> {
+    let storage = open_storage()?;
+    match args.action {
+        CacheAction::Status => {
+            let config = selection.load_config(storage)?;
+            println!(
+                "{}",
+                t!("缓存: {state}（来自{source}）", state = on_off(config.cache_enabled()), source = config.source("cache_enabled")?)
+            );
+            println!(
+                "{}",
+                t!("有效期: {ttl}（来自{source}）", ttl = format_duration(config.cache_ttl()), source = config.source("cache_ttl")?)
+            );
+        }
+        CacheAction::Clear { yes } => {
+            if !yes && !confirm(t!("确定要清除大模型结果缓存吗？"))? {
+                println!("{}", t!("已取消"));
+                return Ok(());
+            }
+            storage.clear_cache()?;
+            println!("{}", t!("缓存已清除"));
+        }
     }
+    Ok(())
+}
+
+fn run_convert(args: ConvertArgs) -> Result<(), Error> {
+    // 收集待转换的标识符：命令行参数 > 文件 > 标准输入
+    let mut identifiers: Vec<String> = args
+        .identifiers
+        .iter()
+        .filter(|s| s.as_str() != "-")
+        .cloned()
+        .collect();
     
-    let config = Arc::new(config);
+    if identifiers.is_empty() {
+        let reader: Box<dyn BufRead> = match &args.file {
+            Some(path) => Box::new(BufReader::new(File::open(path)?)),
+            None => Box::new(BufReader::new(std::io::stdin())),
+        };
+        for line in reader.lines() {
+            identifiers.extend(line?.split_whitespace().map(|s| s.to_string()));
+        }
+    }
     
-    // 初始化生成器
-    let generator = Generator::new(config.clone(), storage.clone())?;
+    let conversions: Vec<Conversion> = identifiers
+        .iter()
+        .map(|identifier| convert_identifier(identifier, args.to, args.acronyms, args.lang))
+        .collect();
     
-    // 处理各种命令行参数
-    if args.all_styles {
-        print_all_styles();
-        return Ok(());
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&conversions)?);
+    } else {
+        for conversion in &conversions {
+            println!("{}", conversion.output);
+        }
     }
     
-    if args.history {
-        print_history(storage.clone())?;
-        return Ok(());
+    Ok(())
+}
+
+fn run_lint(args: LintArgs) -> Result<(), Error> {
+    let path = Path::new(&args.path);
+    
+    // 从被检查的目录向上查找项目配置
+    let root = std::fs::canonicalize(path)?;
+    let start = if root.is_dir() { root.as_path() } else { root.parent().unwrap_or(&root) };
+    let settings = match find_project_config(start) {
+        Some(config_path) => ProjectConfig::from_file(config_path)?.lint,
+        None => LintSettings::default(),
+    };
+    
+    let lint_report = Linter::new(settings, args.lang).lint_path(path)?;
+    let content = match args.format {
+        LintFormat::Text => report::to_text(&lint_report),
+        LintFormat::Sarif => report::to_sarif(&lint_report)? + "\n",
+    };
+    
+    match &args.output {
+        Some(output) => {
+            std::fs::write(output, content)?;
+            println!("{}", t!("结果已保存到 {path}", path = output));
+        }
+        None => print!("{}", content),
     }
     
-    if args.clear_history {
-        clear_history(storage.clone())?;
-        return Ok(());
+    if lint_report.issues.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::LintError(t!("发现 {count} 个命名问题", count = lint_report.issues.len())))
+    }
+}
+
+/// 保存 API 密钥，只输出来源和指纹；未提供或为 `-` 时从标准输入读取
+fn set_api_key(config: &mut Config, api_key: Option<&str>, encrypt: bool) -> Result<(), Error> {
+    let api_key = if api_key.is_none_or(|key| key == "-") {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+        line.trim().to_string()
+    } else {
+        api_key.unwrap_or_default().trim().to_string()
+    };
+    if api_key.is_empty() {
+        return Err(Error::ConfigError(t!("API密钥不能为空").to_string()));
     }
     
-    // 处理API密钥设置
-    if let Some(api_key) = args.set_api_key {
-        let mut config = Config::new(storage.clone())?;
+    if encrypt {
+        config.set_api_key_encrypted(&api_key, &read_passphrase()?)?;
+    } else {
         config.set_api_key(&api_key)?;
-        println!("API密钥已设置");
-        return Ok(());
     }
     
-    if args.clear_api_key {
-        let mut config = Config::new(storage.clone())?;
-        config.clear_api_key()?;
-        println!("API密钥已清除");
-        return Ok(());
+    if let Some(key) = config.api_key_info() {
+        println!(
+            "{}",
+            t!(
+                "{provider} 的{credential}已设置: {key}",
+                provider = config.provider().display_name(),
+                credential = config.provider().credential_name(),
+                key = key
+            )
+        );
+    }
+    let env_var = secret::env_var(config.provider().name());
+    if std::env::var(&env_var).is_ok_and(|v| !v.trim().is_empty()) {
+        println!("{}", t!("注意：环境变量 {name} 已设置，会优先于保存的密钥", name = env_var));
+    }
+    Ok(())
+}
+
+/// 加密存储的口令：环境变量 VAR_GEN_KEY_PASSPHRASE，未设置时交互输入
+fn read_passphrase() -> Result<String, Error> {
+    if let Some(passphrase) = std::env::var(secret::PASSPHRASE_ENV).ok().filter(|p| !p.is_empty()) {
+        return Ok(passphrase);
     }
     
-    // 处理批量文件
-    if let Some(file_path) = args.file {
-        process_file(&file_path, &args.output, &generator, args.style, args.force_rule).await?;
-        return Ok(());
+    #[cfg(feature = "interactive")]
+    return Ok(dialoguer::Password::new()
+        .with_prompt(t!("加密口令"))
+        .with_confirmation(t!("再次输入口令"), t!("两次输入的口令不一致"))
+        .interact()?);
+    
+    #[cfg(not(feature = "interactive"))]
+    Err(Error::ConfigError(t!("请通过环境变量 {name} 提供加密口令", name = secret::PASSPHRASE_ENV)))
+}
+
+fn run_config(args: ConfigArgs, selection: &Selection<'_>) -> Result<(), Error> {
+    // 配置文件损坏时也要能修复，这两个命令在加载配置之前处理
+    let path = settings::user_config_path()?;
+    match &args.action {
+        ConfigAction::Unset { key } => settings::discard_invalid(&path, key)?,
+        ConfigAction::Edit => return edit_config(&path),
+        ConfigAction::Path => {
+            println!("{}", path.display());
+            return Ok(());
+        }
+        _ => {}
     }
+    let mut config = selection.load_config(open_storage()?)?;
     
-    // 处理交互式模式或单变量生成
-    if args.interactive || args.description.is_none() {
-        run_interactive(storage.clone(), config.clone(), Arc::new(generator.clone_with_storage_config()?)).await?;
-    } else {
-        let description = args.description.unwrap();
-        generate_single(&description, args.style, &generator, storage.clone(), args.force_rule).await?;
+    match args.action {
+        ConfigAction::Show => print_config(&config)?,
+        ConfigAction::Get { key } => {
+            if let Some(value) = config.get(&key)? {
+                println!("{}", value);
+            }
+        }
+        ConfigAction::Set { key, value } => {
+            config.set(&key, &value)?;
+            if !warn_shadowed(&config, &key)? {
+                println!("{} = {}", key, config.get(&key)?.unwrap_or_default());
+            }
+        }
+        ConfigAction::Unset { key } => {
+            config.unset(&key)?;
+            if !warn_shadowed(&config, &key)? {
+                match config.get(&key)? {
+                    Some(value) => println!("{}", t!("{key} 已恢复默认值: {value}", key = key, value = value)),
+                    None => println!("{}", t!("{key} 已删除", key = key)),
+                }
+            }
+        }
+        ConfigAction::List => {
+            for (key, description) in config::SETTINGS {
+                let value = config.get(key)?.unwrap_or_default();
+                let note = t!("{description}（{source}）", description = i18n::translate(description), source = config.source(key)?);
+                println!("{:<20} {:<24} {}", key, value, note);
+            }
+        }
+        ConfigAction::Explain { lang, kind } => explain_config(&config, lang, kind)?,
+        ConfigAction::Reset { yes } => {
+            if !yes && !confirm(t!("重置所有配置并删除当前服务商的 API 密钥？"))? {
+                println!("{}", t!("已取消"));
+                return Ok(());
+            }
+            config.reset_to_defaults()?;
+            println!("{}", t!("配置已重置"));
+        }
+        ConfigAction::Edit | ConfigAction::Path => unreachable!("已在加载配置前处理"),
+    }
+    Ok(())
+}
+
+/// 修改的用户配置被命令行参数、环境变量或项目配置覆盖时提示，返回是否被覆盖
+fn warn_shadowed(config: &Config, key: &str) -> Result<bool, Error> {
+    let source = config.source(key)?;
+    let shadowed = matches!(source, Source::CommandLine | Source::Env | Source::Project);
+    if shadowed {
+        println!(
+            "{}",
+            t!(
+                "已保存到用户配置，但当前生效的 {key} = {value}，来自{source}",
+                key = key,
+                value = config.get(key)?.unwrap_or_default(),
+                source = source
+            )
+        );
+    }
+    Ok(shadowed)
+}
+
+/// 逐项显示生效值和来源，默认命名规范列出完整的优先级链
+fn explain_config(
+    config: &Config,
+    language: Option<Language>,
+    kind: Option<IdentifierKind>,
+) -> Result<(), Error> {
+    for (key, _) in config::SETTINGS {
+        let value = config.get(key)?.unwrap_or_else(|| t!("（未设置）").to_string());
+        println!("{:<20} {:<24} {}", key, value, t!("来自{source}", source = config.source(key)?));
+    }
+    let provider = config.provider();
+    println!("{:<20} {}", "api_key", describe_key(provider, config.api_key_info())?);
+    
+    let profile = config.profile().map(Profile::load).transpose()?;
+    let chain = config.style_chain(None, language, kind, profile.as_ref());
+    let (style, source) = config.resolve_style(None, language, kind, profile.as_ref());
+    // 生成时 --style 优先于下列各项
+    println!();
+    println!("{}", t!("生成时使用的命名规范: {style}（来自{source}）", style = style.name(), source = source));
+    for (level, style) in &chain {
+        let value = style.map_or("-", |style| style.name());
+        let marker = if *level == source { "*" } else { " " };
+        println!("{} {:<28} {}", marker, level.to_string(), value);
+    }
+    Ok(())
+}
+
+/// 用编辑器修改用户配置文件；校验通过后才替换原文件，文件中的注释原样保留
+fn edit_config(path: &Path) -> Result<(), Error> {
+    let original = settings::read_or_template(path)?;
+    let draft = std::env::temp_dir().join(format!("var-gen-config-{}.toml", std::process::id()));
+    std::fs::write(&draft, &original)?;
+    let edited = open_editor(&draft).and_then(|_| Ok(std::fs::read_to_string(&draft)?));
+    let edited = match edited {
+        Ok(edited) if edited == original => {
+            let _ = std::fs::remove_file(&draft);
+            println!("{}", t!("配置未修改"));
+            return Ok(());
+        }
+        Ok(edited) => edited,
+        Err(e) => {
+            let _ = std::fs::remove_file(&draft);
+            return Err(e);
+        }
+    };
+    
+    // 校验失败时保留修改后的内容，方便重新编辑
+    if let Err(e) = Settings::parse(&edited).and_then(|settings| settings.validate()) {
+        return Err(Error::ConfigError(t!("{error}；修改后的内容保留在 {path}", error = e, path = draft.display())));
+    }
+    settings::write_atomic(path, &edited)?;
+    let _ = std::fs::remove_file(&draft);
+    println!("{}", t!("配置已保存到 {path}", path = path.display()));
+    Ok(())
+}
+
+/// 打开 $VISUAL 或 $EDITOR（默认 vi）编辑文件，等待编辑器退出
+fn open_editor(path: &Path) -> Result<(), Error> {
+    let editor = ["VISUAL", "EDITOR"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.trim().is_empty()))
+        .unwrap_or_else(|| "vi".to_string());
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vi");
+    
+    let status = std::process::Command::new(program)
+        .args(words)
+        .arg(path)
+        .status()
+        .map_err(|e| Error::ConfigError(t!("无法启动编辑器 {editor}: {error}", editor = editor, error = e)))?;
+    if !status.success() {
+        return Err(Error::ConfigError(t!("编辑器 {editor} 异常退出，配置未修改", editor = editor)));
+    }
+    Ok(())
+}
+
+/// 显示当前配置；API 密钥只显示来源和指纹
+fn print_config(config: &Config) -> Result<(), Error> {
+    let provider = config.provider();
+    println!("{}", t!("服务商: {provider}（{name}）", provider = provider, name = provider.display_name()));
+    println!("{}", t!("模型: {model}", model = config.model()));
+    println!("{}: {}", provider.credential_name(), describe_key(provider, config.api_key_info())?);
+    println!(
+        "{}",
+        t!(
+            "默认命名规范: {style}",
+            style = config.default_style().map_or(t!("未设置（按语言惯例，否则为 snake）"), |style| style.name())
+        )
+    );
+    println!("{}", t!("配置档: {profile}", profile = config.profile().unwrap_or(t!("无"))));
+    println!("{}", t!("词汇映射文件: {path}", path = config.mapping_config_path().unwrap_or(t!("默认"))));
+    println!(
+        "{}",
+        t!("缓存: {state}（有效期 {ttl}）", state = on_off(config.cache_enabled()), ttl = format_duration(config.cache_ttl()))
+    );
+    println!("{}", t!("界面语言: {locale}", locale = config.ui_lang()));
+    Ok(())
+}
+
+/// 密钥的来源和指纹，未设置时给出设置方法
+fn describe_key(provider: Provider, key: Option<&ApiKey>) -> Result<String, Error> {
+    Ok(match key {
+        Some(key) => key.to_string(),
+        None if secret::has_encrypted(provider.name())? => {
+            t!("已加密保存，设置环境变量 {name} 后可用", name = secret::PASSPHRASE_ENV)
+        }
+        None => t!(
+            "未设置（可用 var-gen --provider {provider} provider set-key 或环境变量 {name} 设置）",
+            provider = provider,
+            name = secret::env_var(provider.name())
+        ),
+    })
+}
+
+async fn run_provider(args: ProviderArgs, selection: &Selection<'_>) -> Result<(), Error> {
+    let mut config = selection.load_config(open_storage()?)?;
+    
+    match args.action {
+        ProviderAction::List => {
+            for provider in Provider::ALL {
+                let marker = if provider == config.provider() { "*" } else { " " };
+                println!(
+                    "{} {:<8} {}  {}",
+                    marker,
+                    provider.name(),
+                    provider.display_name(),
+                    t!("模型: {model}", model = config.model_for(provider))
+                );
+                let key = config.api_key_for(provider)?;
+                println!("    {}: {}", provider.credential_name(), describe_key(provider, key.as_ref())?);
+                println!("    {}", t!("可选模型: {models}", models = provider.models().join(", ")));
+            }
+            Ok(())
+        }
+        ProviderAction::Test { provider } => test_providers(&config, provider).await,
+        ProviderAction::Use { provider, model } => {
+            config.set_provider(provider)?;
+            if let Some(model) = model {
+                if Provider::from_model(&model).is_some_and(|p| p != provider) {
+                    return Err(Error::ConfigError(t!("模型 {model} 不属于服务商 {provider}", model = model, provider = provider)));
+                }
+                config.set_model(&model)?;
+            }
+            println!(
+                "{}",
+                t!(
+                    "默认服务商已切换为 {provider}（{name}），模型: {model}",
+                    provider = provider,
+                    name = provider.display_name(),
+                    model = config.model()
+                )
+            );
+            Ok(())
+        }
+        ProviderAction::SetKey { key, encrypt } => set_api_key(&mut config, key.as_deref(), encrypt),
+        ProviderAction::ClearKey => {
+            config.clear_api_key()?;
+            println!("{}", t!("{provider} 的API密钥已清除", provider = config.provider().display_name()));
+            Ok(())
+        }
+    }
+}
+
+/// 检查已配置密钥的服务商；指定服务商时未配置密钥视为失败
+#[cfg(feature = "llm")]
+async fn test_providers(config: &Config, only: Option<Provider>) -> Result<(), Error> {
+    use crate::generator::llm::{test_provider, ProviderStatus};
+    
+    let providers = match only {
+        Some(provider) => vec![provider],
+        None => Provider::ALL.to_vec(),
+    };
+    
+    let mut failed = 0;
+    for provider in providers {
+        let model = config.model_for(provider);
+        let Some(key) = config.api_key_for(provider)? else {
+            println!(
+                "{} {}",
+                "-".yellow(),
+                t!("{provider}: 未配置{credential}，跳过", provider = provider, credential = provider.credential_name())
+            );
+            if only.is_some() {
+                failed += 1;
+            }
+            continue;
+        };
+        
+        match test_provider(provider, model, &key).await {
+            ProviderStatus::Ok(elapsed) => {
+                println!(
+                    "{} {}",
+                    "✓".green(),
+                    t!(
+                        "{provider}（{model}）: 正常，耗时 {elapsed} ms",
+                        provider = provider,
+                        model = model,
+                        elapsed = elapsed.as_millis()
+                    )
+                );
+            }
+            ProviderStatus::Unauthorized(status) => {
+                failed += 1;
+                println!(
+                    "{} {}",
+                    "✗".red(),
+                    t!(
+                        "{provider}（{model}）: 认证失败（HTTP {status}），请检查 {credential} {fingerprint}",
+                        provider = provider,
+                        model = model,
+                        status = status,
+                        credential = provider.credential_name(),
+                        fingerprint = key.fingerprint()
+                    )
+                );
+            }
+            ProviderStatus::Failed(reason) => {
+                failed += 1;
+                println!("{} {}", "✗".red(), t!("{provider}（{model}）: {reason}", provider = provider, model = model, reason = reason));
+            }
+        }
+    }
+    
+    if failed > 0 {
+        return Err(Error::LLMError(t!("{count} 个服务商不可用", count = failed)));
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "llm"))]
+async fn test_providers(_config: &Config, _only: Option<Provider>) -> Result<(), Error> {
+    Err(Error::FeatureUnavailable(t!("服务商检查"), "llm"))
+}
+
+/// 打开配置的存储后端（VAR_GEN_STORAGE 或项目配置中的 storage）
+fn open_storage() -> Result<Arc<dyn Store>, Error> {
+    StorageBackend::configured()?.open()
+}
+
+/// 为子命令创建生成器（使用命令行指定的配置档、服务商和模型）
+fn open_generator(storage: Arc<dyn Store>, selection: &Selection<'_>) -> Result<Generator, Error> {
+    Generator::new(Arc::new(selection.load_config(storage)?))
+}
+
+#[cfg(feature = "server")]
+async fn run_serve(args: ServeArgs, selection: &Selection<'_>) -> Result<(), Error> {
+    let storage = open_storage()?;
+    let state = AppState {
+        generator: open_generator(storage.clone(), selection)?,
+        storage,
+        token: args.token.or_else(|| std::env::var("VAR_GEN_SERVER_TOKEN").ok()).filter(|t| !t.is_empty()),
+        limits: Limits {
+            max_body_bytes: args.max_body_bytes,
+            max_batch: args.max_batch,
+            concurrency: args.concurrency.max(1),
+            timeout: std::time::Duration::from_secs(args.timeout),
+        },
+        force_rule: args.force_rule,
+    };
+    server::serve(args.bind, state).await
+}
+
+#[cfg(not(feature = "server"))]
+async fn run_serve(_args: ServeArgs, _selection: &Selection<'_>) -> Result<(), Error> {
+    Err(Error::FeatureUnavailable(t!("HTTP 接口服务"), "server"))
+}
+
+async fn run_suggest_rename(args: SuggestRenameArgs, selection: &Selection<'_>) -> Result<(), Error> {
+    let generator = open_generator(open_storage()?, selection)?;
+    
+    // 未指定命名规范时，候选名称沿用原标识符的风格
+    let style = args.style.or_else(|| detect_style(&args.identifier));
+    let options = generator.resolve_options(style, args.kind, args.lang);
+    
+    // 文字说明和代码片段一起作为上下文
+    let code = args
+        .context_file
+        .as_deref()
+        .map(|spec| CodeContext::load(spec, args.lang))
+        .transpose()?;
+    let context: Vec<&str> = args
+        .context
+        .as_deref()
+        .into_iter()
+        .chain(code.as_ref().map(|c| c.snippet.as_str()))
+        .collect();
+    let context = (!context.is_empty()).then(|| context.join("\n"));
+    
+    let candidates = generator
+        .suggest_rename(&args.identifier, context.as_deref(), &options, args.force_rule)
+        .await?;
+    
+    println!("{}", t!("{identifier} 的建议名称：", identifier = args.identifier));
+    for (i, name) in candidates.iter().enumerate() {
+        println!("  {}. {}", i + 1, name.green());
     }
     
     Ok(())
 }
 
 fn print_all_styles() {
-    println!("支持的命名规范：");
+    println!("{}", t!("支持的命名规范："));
     for style in NamingStyle::value_variants() {
         println!("  - {}", style);
     }
 }
 
-fn print_history(storage: Arc<Storage>) -> Result<(), Error> {
-    let history = storage.get_history(20)?;
+fn print_profiles(active: Option<&str>) {
+    println!("{}", t!("可用的领域配置档："));
+    for name in Profile::available() {
+        if Some(name.as_str()) == active {
+            println!("  - {} {}", name.green(), t!("(当前)").green());
+        } else {
+            println!("  - {}", name);
+        }
+    }
+}
+
+fn print_history(storage: Arc<dyn Store>, limit: usize, json: bool) -> Result<(), Error> {
+    let history = storage.get_history(limit)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&history)?);
+        return Ok(());
+    }
     if history.is_empty() {
-        println!("暂无历史记录");
+        println!("{}", t!("暂无历史记录"));
         return Ok(());
     }
     
-    println!("最近生成的变量名：");
+    println!("{}", t!("最近生成的变量名："));
     for entry in history {
         println!(
-            "  - {} (描述: \"{}\", 风格: {})",
-            entry.variable_name, entry.description, entry.style
+            "  - {} {}",
+            entry.variable_name,
+            t!("(描述: \"{description}\", 风格: {style})", description = entry.description, style = entry.style)
         );
     }
     
     Ok(())
 }
 
-fn clear_history(storage: Arc<Storage>) -> Result<(), Error> {
-    let confirm = dialoguer::Confirm::new()
-        .with_prompt("确定要清除所有历史记录吗？")
-        .default(false)
-        .interact()?;
-    
-    if confirm {
+fn clear_history(storage: Arc<dyn Store>, yes: bool) -> Result<(), Error> {
+    if yes || confirm(t!("确定要清除所有历史记录吗？"))? {
         storage.clear_history()?;
-        println!("历史记录已清除");
+        println!("{}", t!("历史记录已清除"));
     }
     
     Ok(())
 }
 
-async fn process_file(
-    file_path: &str,
-    output_path: &Option<String>,
-    generator: &Generator,
-    style: NamingStyle,
+fn on_off(enabled: bool) -> &'static str {
+    if enabled { t!("开启") } else { t!("关闭") }
+}
+
+/// 询问是否确认，默认为否；未启用 `interactive` 特性时从标准输入读取 y/N
+fn confirm(prompt: &str) -> Result<bool, Error> {
+    #[cfg(feature = "interactive")]
+    return Ok(dialoguer::Confirm::new().with_prompt(prompt).default(false).interact()?);
+    
+    #[cfg(not(feature = "interactive"))]
+    {
+        print!("{} [y/N] ", prompt);
+        std::io::stdout().flush()?;
+        let mut answer = String::new();
+        std::io::stdin().read_line(&mut answer)?;
+        Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+    }
+}
+
+/// 一次运行中各次生成共用的参数
+struct GenerateRun<'a> {
+    generator: &'a Generator,
+    context: Option<&'a CodeContext>,
+    index: Option<&'a ProjectIndex>,
+    options: &'a GenerateOptions,
     force_rule: bool,
-) -> Result<(), Error> {
+    json: bool,
+}
+
+impl GenerateRun<'_> {
+    /// 生成候选名称；与项目中已有标识符重名的候选排到后面
+    async fn names(&self, description: &str) -> Result<Vec<String>, Error> {
+        let names = self
+            .generator
+            .generate(description, self.context, self.options, self.force_rule)
+            .await?;
+        Ok(match self.index {
+            Some(index) => index.demote_existing(names),
+            None => names,
+        })
+    }
+}
+
+async fn process_file(file_path: &str, output_path: &Option<String>, run: &GenerateRun<'_>) -> Result<(), Error> {
     let file = File::open(file_path)?;
     let reader = BufReader::new(file);
     
@@ -215,24 +1587,40 @@ async fn process_file(
             continue;
         }
         
-        let variable_names: Vec<String> = generator.generate(&description, style, force_rule).await?;
+        let variable_names = run.names(&description).await?;
         results.push((description, variable_names));
     }
     
+    if run.json {
+        let generations: Vec<Generation> = results
+            .into_iter()
+            .map(|(description, names)| Generation { description, style: run.options.style.name(), names })
+            .collect();
+        let content = serde_json::to_string_pretty(&generations)?;
+        match output_path {
+            Some(path) => {
+                std::fs::write(path, content + "\n")?;
+                println!("{}", t!("结果已保存到 {path}", path = path));
+            }
+            None => println!("{}", content),
+        }
+        return Ok(());
+    }
+    
     match output_path {
         Some(path) => {
             let mut file = File::create(path)?;
             for (description, variable_names) in &results {
-                writeln!(file, "描述: {}", description)?;
-                writeln!(file, "变量名: {}", variable_names.join(", "))?;
+                writeln!(file, "{}", t!("描述: {description}", description = description))?;
+                writeln!(file, "{}", t!("变量名: {names}", names = variable_names.join(", ")))?;
                 writeln!(file)?;
             }
-            println!("结果已保存到 {}", path);
+            println!("{}", t!("结果已保存到 {path}", path = path));
         },
         None => {
             for (description, variable_names) in &results {
-                println!("描述: {}", description);
-                println!("变量名: {}", variable_names.join(", "));
+                println!("{}", t!("描述: {description}", description = description));
+                println!("{}", t!("变量名: {names}", names = variable_names.join(", ")));
                 println!();
             }
         },
@@ -241,131 +1629,112 @@ async fn process_file(
     Ok(())
 }
 
+#[cfg(not(feature = "interactive"))]
 async fn run_interactive(
-    storage: Arc<Storage>,
+    _storage: Arc<dyn Store>,
     _config: Arc<Config>,
-    generator: Arc<Generator>,
+    _generator: Arc<Generator>,
+    _naming: &NamingArgs,
 ) -> Result<(), Error> {
-    println!("=== var-gen 交互模式 ===");
-    println!("提示：输入变量描述生成变量名，输入空行退出。");
-    println!("支持的命名规范：snake, camel, pascal, kebab, upper_snake, lower_camel");
-    println!();
+    Err(Error::FeatureUnavailable(t!("交互模式"), "interactive"))
+}
+
+#[cfg(not(feature = "tui"))]
+async fn run_tui(
+    _storage: Arc<dyn Store>,
+    _config: Arc<Config>,
+    _generator: Arc<Generator>,
+    _naming: &NamingArgs,
+) -> Result<(), Error> {
+    Err(Error::FeatureUnavailable(t!("终端界面"), "tui"))
+}
+
+async fn generate_single(description: &str, run: &GenerateRun<'_>, storage: Arc<dyn Store>) -> Result<(), Error> {
+    let variable_names = run.names(description).await?;
+    let (options, index) = (run.options, run.index);
     
-    let stdin = std::io::stdin();
-    let mut input_buffer = String::new();
+    if let (true, Some(first)) = (run.json, variable_names.first()) {
+        storage.save_history(description, options.style, first)?;
+        let generation = Generation {
+            description: description.to_string(),
+            style: options.style.name(),
+            names: variable_names,
+        };
+        println!("{}", serde_json::to_string_pretty(&generation)?);
+        return Ok(());
+    }
     
-    loop {
-        // 清空缓冲区
-        input_buffer.clear();
-        
-        // 显示提示并刷新输出
-        print!("请输入变量描述（输入空行退出）：");
-        std::io::stdout().flush()?;
+    println!("{}", t!("生成的变量名："));
+    for (i, name) in variable_names.iter().enumerate() {
+        let locations = index.map_or(&[][..], |index| index.locations(name));
+        if locations.is_empty() {
+            println!("  {}. {}", i + 1, name.green());
+            continue;
+        }
         
-        // 读取输入
-        match stdin.read_line(&mut input_buffer) {
-            Ok(0) => {
-                // EOF，正常退出
-                println!("\n检测到输入结束，退出交互模式。");
-                break;
-            }
-            Ok(_) => {
-                let description = input_buffer.trim();
-                if description.is_empty() {
-                    println!("输入为空，退出交互模式。");
-                    break;
-                }
-                
-                println!("您输入的描述是：\"{}\"", description);
-                
-                // 使用箭头选择命名规范
-                let styles = vec![
-                    ("snake_case", "下划线命名法 (snake_case)"),
-                    ("camelCase", "驼峰命名法 (camelCase)"),
-                    ("PascalCase", "帕斯卡命名法 (PascalCase)"),
-                    ("kebab-case", "短横线命名法 (kebab-case)"),
-                    ("UPPER_SNAKE_CASE", "大写下划线命名法 (UPPER_SNAKE_CASE)"),
-                    ("lowerCamelCase", "小驼峰命名法 (lowerCamelCase)"),
-                ];
-                
-                let selection = dialoguer::Select::new()
-                    .with_prompt("请选择命名规范")
-                    .items(&styles.iter().map(|(name, desc)| format!("{} - {}", name, desc)).collect::<Vec<_>>())
-                    .default(0)
-                    .interact()?;
-                
-                let style = match selection {
-                    0 => NamingStyle::Snake,
-                    1 => NamingStyle::Camel,
-                    2 => NamingStyle::Pascal,
-                    3 => NamingStyle::Kebab,
-                    4 => NamingStyle::UpperSnake,
-                    5 => NamingStyle::LowerCamel,
-                    _ => NamingStyle::Snake, // 默认情况
-                };
-                
-                println!("正在生成变量名...");
-                
-                // 生成变量名
-                match generator.generate(&description, style, false).await {
-                    Ok(variable_names) => {
-                        // 显示结果
-                        println!("\n生成的变量名：");
-                        for (i, name) in variable_names.iter().enumerate() {
-                            println!("  {}. {}", i + 1, name.green());
-                        }
-                        
-                        // 询问是否保存到历史记录
-                        let save_to_history = dialoguer::Confirm::new()
-                            .with_prompt("是否保存到历史记录？")
-                            .default(false)
-                            .interact()?;
-                        
-                        if save_to_history && !variable_names.is_empty() {
-                            match storage.save_history(&description, style, &variable_names[0]) {
-                                Ok(_) => println!("已保存到历史记录。"),
-                                Err(e) => eprintln!("保存历史记录失败：{}", e),
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("生成变量名失败：{}", e);
-                    }
-                }
-                
-                println!("\n---");
-            }
-            Err(e) => {
-                eprintln!("读取输入失败：{}，退出交互模式。", e);
-                break;
+        let places: Vec<String> = locations.iter().take(3).map(|l| l.to_string()).collect();
+        println!("  {}. {} {}", i + 1, name.yellow(), t!("(已存在: {places})", places = places.join(", ")).yellow());
+        if let Some(index) = index {
+            let casing = run.generator.rule_generator.casing(options);
+            let alternatives = index.alternatives(name, options.style, &casing);
+            if !alternatives.is_empty() {
+                println!("     {}", t!("可改用: {names}", names = alternatives.join(", ")));
             }
         }
     }
     
-    println!("退出交互模式。");
+    // 自动保存到历史记录
+    if !variable_names.is_empty() {
+        storage.save_history(description, options.style, &variable_names[0])?;
+    }
+    
     Ok(())
 }
 
-async fn generate_single(
-    description: &str,
-    style: NamingStyle,
-    generator: &Generator,
-    storage: Arc<Storage>,
-    force_rule: bool,
-) -> Result<(), Error> {
-    let variable_names: Vec<String> = generator.generate(description, style, force_rule).await?;
+use std::sync::Arc;
+#[cfg(test)]
+mod tests {
+    use super::*;
     
-    println!("生成的变量名：");
-    for (i, name) in variable_names.iter().enumerate() {
-        println!("  {}. {}", i + 1, name.green());
+    #[test]
+    fn test_help_has_translation() {
+        fn check(command: &clap::Command, missing: &mut Vec<String>) {
+            let texts = command
+                .get_about()
+                .into_iter()
+                .chain(command.get_after_help())
+                .chain(command.get_arguments().filter(|arg| !arg.is_hide_set()).filter_map(|arg| arg.get_help()));
+            for text in texts.map(|text| text.to_string()) {
+                let chinese = text.chars().any(|c| ('\u{4e00}'..='\u{9fff}').contains(&c));
+                if chinese && i18n::lookup(Locale::EnUs, &text).is_none() {
+                    missing.push(text);
+                }
+            }
+            for subcommand in command.get_subcommands() {
+                check(subcommand, missing);
+            }
+        }
+        
+        let mut missing = Vec::new();
+        check(&Args::command(), &mut missing);
+        missing.sort();
+        missing.dedup();
+        assert!(missing.is_empty(), "缺少英文译文:\n{}", missing.join("\n"));
+        
+        for (_, description) in config::SETTINGS {
+            assert!(i18n::lookup(Locale::EnUs, description).is_some(), "缺少英文译文: {}", description);
+        }
     }
     
-    // 自动保存到历史记录
-    if !variable_names.is_empty() {
-        storage.save_history(description, style, &variable_names[0])?;
+    #[test]
+    fn test_lang_ui_argument() {
+        let args = |args: &[&str]| args.iter().map(std::ffi::OsString::from).collect::<Vec<_>>();
+        assert_eq!(ui_locale(args(&["var-gen", "--lang-ui", "en-US", "styles"])), Locale::EnUs);
+        assert_eq!(ui_locale(args(&["var-gen", "styles", "--lang-ui=zh"])), Locale::ZhCn);
+        // 描述文本中的 --lang-ui 不是参数
+        assert_eq!(
+            ui_locale(args(&["var-gen", "--lang-ui", "en", "gen", "--", "--lang-ui=zh"])),
+            Locale::EnUs
+        );
     }
-    
-    Ok(())
 }
-
-use std::sync::Arc;
\ No newline at end of file
@@ -0,0 +1,413 @@
+//! 交互模式：支持行编辑、历史描述回溯（↑↓，保存在 ~/.var-gen/repl_history）和以 `:` 开头的命令
+//!
+//! 命名规范、标识符类型和目标语言用 `:style`、`:kind`、`:lang` 设置后对之后的每条描述生效，
+//! 生成后用 `:pick <序号>` 选择候选名称并保存到历史记录。
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use clap::ValueEnum;
+use colored::Colorize;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::FileHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+
+use super::{print_history, NamingArgs};
+use crate::config::Config;
+use crate::generator::{GenerateOptions, Generator};
+use crate::naming::{IdentifierKind, Language, NamingStyle};
+use crate::storage::Store;
+use crate::utils::error::Error;
+
+/// 交互模式的命令，按 Tab 可补全
+const COMMANDS: [&str; 10] = [":style", ":kind", ":lang", ":regen", ":pick", ":copy", ":history", ":help", ":quit", ":exit"];
+
+/// 设置类命令的参数：省略时显示当前值，`-` 表示恢复默认
+#[derive(Debug, PartialEq)]
+enum Setting<T> {
+    Show,
+    Reset,
+    Set(T),
+}
+
+#[derive(Debug, PartialEq)]
+enum ReplCommand {
+    Style(Setting<NamingStyle>),
+    Kind(Setting<IdentifierKind>),
+    Lang(Setting<Language>),
+    Regen,
+    Pick(usize),
+    Copy(Option<PathBuf>),
+    History(usize),
+    Help,
+    Quit,
+}
+
+/// 解析以 `:` 开头的一行输入
+fn parse_command(line: &str) -> Result<ReplCommand, Error> {
+    let line = line.trim().trim_start_matches(':');
+    let (name, argument) = match line.split_once(char::is_whitespace) {
+        Some((name, argument)) => (name, Some(argument.trim()).filter(|a| !a.is_empty())),
+        None => (line, None),
+    };
+    let command = match name {
+        "style" | "s" => ReplCommand::Style(setting(argument, |value| value.parse())?),
+        "kind" | "k" => ReplCommand::Kind(setting(argument, parse_value)?),
+        "lang" | "l" => ReplCommand::Lang(setting(argument, parse_value)?),
+        "regen" | "r" => ReplCommand::Regen,
+        "pick" | "p" => ReplCommand::Pick(
+            argument
+                .and_then(|n| n.parse().ok())
+                .filter(|n| *n > 0)
+                .ok_or_else(|| Error::ConfigError(t!("用法: :pick <序号>").to_string()))?,
+        ),
+        "copy" | "c" => ReplCommand::Copy(argument.map(PathBuf::from)),
+        "history" | "h" => ReplCommand::History(match argument {
+            Some(n) => n
+                .parse()
+                .map_err(|_| Error::ConfigError(t!("无效的条数: {value}", value = n)))?,
+            None => 10,
+        }),
+        "help" | "?" => ReplCommand::Help,
+        "quit" | "q" | "exit" => ReplCommand::Quit,
+        _ => return Err(Error::ConfigError(t!("未知的命令: :{name}，输入 :help 查看可用命令", name = name))),
+    };
+    Ok(command)
+}
+
+fn setting<T>(argument: Option<&str>, parse: impl Fn(&str) -> Result<T, Error>) -> Result<Setting<T>, Error> {
+    Ok(match argument {
+        None => Setting::Show,
+        Some("-") => Setting::Reset,
+        Some(value) => Setting::Set(parse(value)?),
+    })
+}
+
+/// 按命令行参数的写法（含别名，如 fn、rs）解析取值
+fn parse_value<T: ValueEnum>(value: &str) -> Result<T, Error> {
+    T::from_str(value, true).map_err(|_| {
+        let names = value_names::<T>();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        Error::ConfigError(t!("无效的取值: {value}（可选 {names}）", value = value, names = crate::i18n::list(&names)))
+    })
+}
+
+fn value_names<T: ValueEnum>() -> Vec<String> {
+    T::value_variants()
+        .iter()
+        .filter_map(|v| v.to_possible_value().map(|p| p.get_name().to_string()))
+        .collect()
+}
+
+/// Tab 补全：命令名，以及 `:style`、`:kind`、`:lang` 的取值
+fn completions(line: &str) -> (usize, Vec<String>) {
+    if !line.starts_with(':') {
+        return (0, Vec::new());
+    }
+    let Some((name, argument)) = line.split_once(' ') else {
+        return (0, COMMANDS.iter().filter(|c| c.starts_with(line)).map(|c| c.to_string()).collect());
+    };
+    let values = match name {
+        ":style" | ":s" => NamingStyle::ALL.iter().map(|s| s.name().to_string()).collect(),
+        ":kind" | ":k" => value_names::<IdentifierKind>(),
+        ":lang" | ":l" => value_names::<Language>(),
+        _ => Vec::new(),
+    };
+    let argument = argument.trim_start();
+    let start = line.len() - argument.len();
+    (start, values.into_iter().filter(|v| v.starts_with(argument)).collect())
+}
+
+struct ReplHelper;
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(completions(&line[..pos]))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+/// 交互会话的状态：设置对之后的每条描述生效，候选名称保留到下一次生成
+struct Session {
+    storage: Arc<dyn Store>,
+    config: Arc<Config>,
+    generator: Arc<Generator>,
+    force_rule: bool,
+    /// 命令行中的 --max-length 等参数
+    defaults: GenerateOptions,
+    /// 显式设置的命名规范，未设置时按配置和语言惯例确定
+    style: Option<NamingStyle>,
+    kind: Option<IdentifierKind>,
+    language: Option<Language>,
+    description: Option<String>,
+    candidates: Vec<String>,
+    /// 生成候选名称时使用的命名规范，之后再改 `:style` 不影响已生成的候选
+    candidate_style: NamingStyle,
+    picked: Option<String>,
+    copy_path: Option<PathBuf>,
+}
+
+impl Session {
+    fn options(&self) -> GenerateOptions {
+        GenerateOptions {
+            max_length: self.defaults.max_length,
+            abbreviation: self.defaults.abbreviation,
+            acronyms: self.defaults.acronyms,
+            ..self.generator.resolve_options(self.style, self.kind, self.language)
+        }
+    }
+
+    /// 当前命名规范及其来源
+    fn print_style(&self) {
+        let (style, source) =
+            self.config
+                .resolve_style(self.style, self.language, self.kind, self.generator.profile.as_deref());
+        println!("{}", t!("命名规范: {style}（来自{source}）", style = style.name(), source = source));
+    }
+
+    fn print_settings(&self) {
+        self.print_style();
+        let unset = t!("未设置");
+        println!("{}", t!("标识符类型: {kind}", kind = self.kind.map_or(unset.to_string(), |k| k.to_string())));
+        println!("{}", t!("目标语言: {language}", language = self.language.map_or(unset.to_string(), |l| l.to_string())));
+        if !self.generator.llm_available() || self.force_rule {
+            println!("{}", t!("使用规则引擎（不调用大模型）"));
+        }
+    }
+
+    async fn generate(&mut self, description: &str) {
+        println!("{}", t!("正在生成变量名..."));
+        let options = self.options();
+        match self.generator.generate(description, None, &options, self.force_rule).await {
+            Ok(names) => {
+                println!("\n{}", t!("生成的变量名："));
+                for (i, name) in names.iter().enumerate() {
+                    println!("  {}. {}", i + 1, name.green());
+                }
+                println!("{}", t!("输入 :pick <序号> 选择并保存到历史记录"));
+                self.description = Some(description.to_string());
+                self.candidates = names;
+                self.candidate_style = options.style;
+                self.picked = None;
+            }
+            Err(e) => eprintln!("{}", t!("生成变量名失败：{error}", error = e)),
+        }
+    }
+
+    /// 执行命令，返回 false 表示退出
+    async fn run(&mut self, command: ReplCommand) -> Result<bool, Error> {
+        match command {
+            ReplCommand::Style(setting) => {
+                match setting {
+                    Setting::Show => {}
+                    Setting::Reset => self.style = None,
+                    Setting::Set(style) => self.style = Some(style),
+                }
+                self.print_style();
+            }
+            ReplCommand::Kind(setting) => {
+                match setting {
+                    Setting::Show => {}
+                    Setting::Reset => self.kind = None,
+                    Setting::Set(kind) => self.kind = Some(kind),
+                }
+                self.print_settings();
+            }
+            ReplCommand::Lang(setting) => {
+                match setting {
+                    Setting::Show => {}
+                    Setting::Reset => self.language = None,
+                    Setting::Set(language) => self.language = Some(language),
+                }
+                self.print_settings();
+            }
+            ReplCommand::Regen => {
+                let description = self
+                    .description
+                    .clone()
+                    .ok_or_else(|| Error::ConfigError(t!("还没有可以重新生成的描述").to_string()))?;
+                self.generate(&description).await;
+            }
+            ReplCommand::Pick(n) => {
+                let name = self.candidates.get(n - 1).cloned().ok_or_else(|| {
+                    Error::ConfigError(t!("没有第 {n} 个候选名称（共 {count} 个）", n = n, count = self.candidates.len()))
+                })?;
+                let description = self.description.as_deref().unwrap_or_default();
+                self.storage.save_history(description, self.candidate_style, &name)?;
+                println!("{}", t!("已选择 {name}，并保存到历史记录", name = name.green()));
+                self.picked = Some(name);
+            }
+            ReplCommand::Copy(path) => {
+                let name = self
+                    .picked
+                    .clone()
+                    .or_else(|| self.candidates.first().cloned())
+                    .ok_or_else(|| Error::ConfigError(t!("还没有生成任何名称").to_string()))?;
+                let path = path
+                    .or_else(|| self.copy_path.clone())
+                    .ok_or_else(|| Error::ConfigError(t!("用法: :copy <文件>（之后可省略文件）").to_string()))?;
+                append_line(&path, &name)?;
+                println!("{}", t!("已将 {name} 追加到 {path}", name = name, path = path.display()));
+                self.copy_path = Some(path);
+            }
+            ReplCommand::History(limit) => print_history(self.storage.clone(), limit, false)?,
+            ReplCommand::Help => print_help(),
+            ReplCommand::Quit => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+fn append_line(path: &Path, line: &str) -> Result<(), Error> {
+    use std::io::Write;
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)?;
+    Ok(())
+}
+
+fn print_help() {
+    println!("{}", t!("输入变量描述生成候选名称，或使用以下命令："));
+    for (usage, description) in [
+        (t!(":style <规范>"), t!("设置命名规范，- 恢复默认")),
+        (t!(":kind <类型>"), t!("设置标识符类型（如 fn、type），- 清除")),
+        (t!(":lang <语言>"), t!("设置目标语言（如 rust、java），- 清除")),
+        (":regen", t!("按当前设置重新生成上一条描述")),
+        (t!(":pick <序号>"), t!("选择候选名称并保存到历史记录")),
+        (t!(":copy [文件]"), t!("把选中的名称追加到文件")),
+        (t!(":history [条数]"), t!("显示最近的历史记录")),
+        (":quit", t!("退出（也可按 Ctrl-D）")),
+    ] {
+        println!("  {:<18}{}", usage, description);
+    }
+}
+
+/// 历史描述保存在 ~/.var-gen/repl_history
+fn history_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".var-gen").join("repl_history"))
+}
+
+pub(super) async fn run_interactive(
+    storage: Arc<dyn Store>,
+    config: Arc<Config>,
+    generator: Arc<Generator>,
+    naming: &NamingArgs,
+) -> Result<(), Error> {
+    let defaults = naming.options(&generator, None);
+    let mut session = Session {
+        storage,
+        config,
+        generator,
+        force_rule: naming.force_rule,
+        defaults,
+        style: naming.style,
+        kind: naming.kind,
+        language: naming.lang,
+        description: None,
+        candidates: Vec::new(),
+        candidate_style: defaults.style,
+        picked: None,
+        copy_path: None,
+    };
+
+    let mut editor: Editor<ReplHelper, FileHistory> = Editor::new()?;
+    editor.set_helper(Some(ReplHelper));
+    let history = history_path();
+    if let Some(path) = &history {
+        // 首次运行时文件不存在
+        let _ = editor.load_history(path);
+    }
+
+    println!("{}", t!("=== var-gen 交互模式 ==="));
+    println!("{}", t!("提示：输入变量描述生成变量名，↑↓ 回溯之前的描述，输入 :help 查看命令，Ctrl-D 退出。"));
+    session.print_settings();
+    println!();
+
+    loop {
+        let line = match editor.readline("> ") {
+            Ok(line) => line,
+            // Ctrl-C 放弃当前输入
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => {
+                eprintln!("{}", t!("读取输入失败：{error}，退出交互模式。", error = e));
+                break;
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with(':') {
+            match parse_command(line) {
+                Ok(command) => match session.run(command).await {
+                    Ok(true) => {}
+                    Ok(false) => break,
+                    Err(e) => eprintln!("{}", e),
+                },
+                Err(e) => eprintln!("{}", e),
+            }
+        } else {
+            editor.add_history_entry(line)?;
+            session.generate(line).await;
+        }
+        println!();
+    }
+
+    if let Some(path) = &history {
+        if let Some(dir) = path.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        if let Err(e) = editor.save_history(path) {
+            eprintln!("{}", t!("警告: 保存输入历史失败: {error}", error = e));
+        }
+    }
+    println!("{}", t!("退出交互模式。"));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command(":style camel").unwrap(), ReplCommand::Style(Setting::Set(NamingStyle::Camel)));
+        assert_eq!(parse_command(":kind fn").unwrap(), ReplCommand::Kind(Setting::Set(IdentifierKind::Function)));
+        assert_eq!(parse_command(":lang rs").unwrap(), ReplCommand::Lang(Setting::Set(Language::Rust)));
+        assert_eq!(parse_command(":lang -").unwrap(), ReplCommand::Lang(Setting::Reset));
+        assert_eq!(parse_command(":style").unwrap(), ReplCommand::Style(Setting::Show));
+        assert_eq!(parse_command(":pick 2").unwrap(), ReplCommand::Pick(2));
+        assert_eq!(parse_command(":copy names.txt").unwrap(), ReplCommand::Copy(Some(PathBuf::from("names.txt"))));
+        assert_eq!(parse_command(":history").unwrap(), ReplCommand::History(10));
+        assert_eq!(parse_command(":q").unwrap(), ReplCommand::Quit);
+
+        assert!(parse_command(":pick 0").is_err());
+        assert!(parse_command(":pick").is_err());
+        assert!(parse_command(":kind struct").is_err());
+        assert!(parse_command(":unknown").is_err());
+    }
+
+    #[test]
+    fn test_completions() {
+        assert_eq!(completions(":st"), (0, vec![":style".to_string()]));
+        assert_eq!(completions(":kind fu"), (6, vec!["function".to_string()]));
+        assert_eq!(completions(":style upper"), (7, vec!["upper_snake".to_string()]));
+        assert_eq!(completions("用户"), (0, Vec::new()));
+    }
+}
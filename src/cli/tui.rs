@@ -0,0 +1,426 @@
+//! 全屏终端界面：输入描述时实时预览候选名称在每种命名规范下的写法
+//!
+//! 输入时只用规则引擎预览，按 Enter 才调用完整的生成流程（可能请求大模型）。
+//! 标准输入或标准输出不是终端时回退到 [`repl`](super::repl) 的交互式模式。
+
+use std::io::IsTerminal;
+use std::sync::Arc;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Position, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+
+use super::NamingArgs;
+use crate::config::Config;
+use crate::generator::rule::casing::convert_identifier;
+use crate::generator::rule::{TokenMapping, TokenSource};
+use crate::generator::{GenerateOptions, Generator};
+use crate::naming::NamingStyle;
+use crate::storage::{HistoryEntry, Store};
+use crate::utils::error::Error;
+
+/// 历史记录侧栏显示的条数
+const HISTORY_LIMIT: usize = 50;
+
+/// 按键处理的结果
+#[derive(Debug, PartialEq)]
+enum Action {
+    None,
+    Regenerate,
+    Quit,
+}
+
+struct App {
+    storage: Arc<dyn Store>,
+    generator: Arc<Generator>,
+    force_rule: bool,
+    options: GenerateOptions,
+    input: String,
+    /// 当前候选名称，输入时来自规则引擎，按 Enter 后来自完整的生成流程；
+    /// 统一按 snake_case 生成以保留单词边界，显示和保存时再转换为选中的命名规范
+    candidates: Vec<String>,
+    candidate: usize,
+    /// 预览表中选中的命名规范
+    styles: TableState,
+    mappings: Vec<TokenMapping>,
+    history: Vec<HistoryEntry>,
+    status: String,
+    /// 本次保存的名称，退出后输出
+    saved: Vec<String>,
+}
+
+impl App {
+    fn new(storage: Arc<dyn Store>, generator: Arc<Generator>, options: GenerateOptions, force_rule: bool) -> Self {
+        let selected = NamingStyle::ALL.iter().position(|style| *style == options.style).unwrap_or(0);
+        let mut app = Self {
+            storage,
+            generator,
+            force_rule,
+            options,
+            input: String::new(),
+            candidates: Vec::new(),
+            candidate: 0,
+            styles: TableState::default().with_selected(Some(selected)),
+            mappings: Vec::new(),
+            history: Vec::new(),
+            status: String::new(),
+            saved: Vec::new(),
+        };
+        app.load_history();
+        app
+    }
+
+    fn load_history(&mut self) {
+        match self.storage.get_history(HISTORY_LIMIT) {
+            Ok(history) => self.history = history,
+            Err(e) => self.status = e.to_string(),
+        }
+    }
+
+    fn style(&self) -> NamingStyle {
+        NamingStyle::ALL[self.styles.selected().unwrap_or(0)]
+    }
+
+    /// 生成候选名称时的选项：flat 等命名规范会丢失单词边界，无法再转换为其他规范
+    fn generate_options(&self) -> GenerateOptions {
+        GenerateOptions { style: NamingStyle::Snake, ..self.options }
+    }
+
+    /// 候选名称在指定命名规范下的写法
+    fn render(&self, candidate: &str, style: NamingStyle) -> String {
+        convert_identifier(candidate, style, self.options.acronyms, self.options.language).output
+    }
+
+    /// 选中的候选名称在指定命名规范下的写法
+    fn preview(&self, style: NamingStyle) -> Option<String> {
+        let candidate = self.candidates.get(self.candidate)?;
+        Some(self.render(candidate, style))
+    }
+
+    /// 描述变化后用规则引擎刷新预览和映射
+    fn refresh(&mut self) {
+        self.candidate = 0;
+        if self.input.trim().is_empty() {
+            self.candidates.clear();
+            self.mappings.clear();
+            return;
+        }
+        let rule_generator = &self.generator.rule_generator;
+        self.mappings = rule_generator.explain(&self.input);
+        self.candidates = rule_generator.generate(&self.input, &self.generate_options()).unwrap_or_default();
+    }
+
+    /// 用完整的生成流程（可能调用大模型）重新生成候选名称
+    async fn regenerate(&mut self) {
+        if self.input.trim().is_empty() {
+            return;
+        }
+        match self.generator.generate(&self.input, None, &self.generate_options(), self.force_rule).await {
+            Ok(names) => {
+                self.status = t!("已生成 {count} 个候选名称", count = names.len());
+                self.candidates = names;
+                self.candidate = 0;
+            }
+            Err(e) => self.status = t!("生成变量名失败：{error}", error = e),
+        }
+    }
+
+    fn save(&mut self) {
+        let style = self.style();
+        let Some(name) = self.preview(style) else {
+            self.status = t!("还没有生成任何名称").to_string();
+            return;
+        };
+        match self.storage.save_history(&self.input, style, &name) {
+            Ok(()) => {
+                self.status = t!("已保存 {name}", name = name);
+                self.saved.push(name);
+                self.load_history();
+            }
+            Err(e) => self.status = t!("保存历史记录失败：{error}", error = e),
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Action {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return Action::Quit,
+            KeyCode::Char('c' | 'q') if ctrl => return Action::Quit,
+            KeyCode::Enter => return Action::Regenerate,
+            KeyCode::Char('r') if ctrl => return Action::Regenerate,
+            KeyCode::Char('s') if ctrl => self.save(),
+            KeyCode::Char('u') if ctrl => {
+                self.input.clear();
+                self.refresh();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.input.push(c);
+                self.refresh();
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+                self.refresh();
+            }
+            KeyCode::Up => self.styles.select(Some(self.styles.selected().unwrap_or(0).saturating_sub(1))),
+            KeyCode::Down => {
+                let next = (self.styles.selected().unwrap_or(0) + 1).min(NamingStyle::ALL.len() - 1);
+                self.styles.select(Some(next));
+            }
+            KeyCode::Tab if !self.candidates.is_empty() => {
+                self.candidate = (self.candidate + 1) % self.candidates.len();
+            }
+            KeyCode::BackTab if !self.candidates.is_empty() => {
+                self.candidate = (self.candidate + self.candidates.len() - 1) % self.candidates.len();
+            }
+            _ => {}
+        }
+        Action::None
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, footer] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [left, sidebar] = Layout::horizontal([Constraint::Min(40), Constraint::Length(36)]).areas(main);
+        let [input, candidates, preview, mappings] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(NamingStyle::ALL.len() as u16 + 2),
+            Constraint::Min(3),
+        ])
+        .areas(left);
+
+        self.draw_input(frame, input);
+        self.draw_candidates(frame, candidates);
+        self.draw_preview(frame, preview);
+        self.draw_mappings(frame, mappings);
+        self.draw_history(frame, sidebar);
+
+        let hints = t!("Enter 生成  Tab 切换候选  ↑↓ 选择命名规范  Ctrl-S 保存  Esc 退出");
+        let footer_line = Line::from(vec![Span::raw(hints).dark_gray(), Span::raw("  "), Span::raw(&self.status).yellow()]);
+        frame.render_widget(Paragraph::new(footer_line), footer);
+    }
+
+    fn draw_input(&self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(
+            Paragraph::new(self.input.as_str()).block(Block::bordered().title(t!(" 变量描述 "))),
+            area,
+        );
+        let width = Line::raw(self.input.as_str()).width() as u16;
+        frame.set_cursor_position(Position::new((area.x + 1 + width).min(area.right().saturating_sub(2)), area.y + 1));
+    }
+
+    fn draw_candidates(&self, frame: &mut Frame, area: Rect) {
+        let style = self.style();
+        let mut spans = Vec::new();
+        for (i, name) in self.candidates.iter().enumerate() {
+            let span = Span::raw(format!(" {}. {} ", i + 1, self.render(name, style)));
+            spans.push(if i == self.candidate { span.reversed() } else { span });
+        }
+        frame.render_widget(
+            Paragraph::new(Line::from(spans)).block(Block::bordered().title(t!(" 候选名称 "))),
+            area,
+        );
+    }
+
+    fn draw_preview(&mut self, frame: &mut Frame, area: Rect) {
+        let rows: Vec<Row> = NamingStyle::ALL
+            .iter()
+            .map(|style| {
+                let preview = self.preview(*style).unwrap_or_default();
+                Row::new(vec![style.name().to_string(), style.to_string(), preview])
+            })
+            .collect();
+        let table = Table::new(rows, [Constraint::Length(16), Constraint::Length(36), Constraint::Min(10)])
+            .block(Block::bordered().title(t!(" 各命名规范预览 ")))
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(table, area, &mut self.styles);
+    }
+
+    fn draw_mappings(&self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = self
+            .mappings
+            .iter()
+            .map(|mapping| {
+                let source = match mapping.source {
+                    TokenSource::Mapping => t!("词典映射"),
+                    TokenSource::PerChar => t!("逐字翻译"),
+                    TokenSource::Untranslated => t!("未找到映射"),
+                    TokenSource::English => t!("英文"),
+                };
+                let color = if mapping.source == TokenSource::Untranslated { Color::Red } else { Color::Green };
+                let mut spans = vec![
+                    Span::raw(format!("{} → ", mapping.token)),
+                    Span::raw(mapping.translation.as_str()).fg(color),
+                    Span::raw(format!("  ({})", source)).dark_gray(),
+                ];
+                if mapping.stop_word {
+                    spans.push(Span::raw(format!("  {}", t!("停用词，已忽略"))).dark_gray());
+                }
+                Line::from(spans)
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(t!(" 分词与映射 "))),
+            area,
+        );
+    }
+
+    fn draw_history(&self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .history
+            .iter()
+            .map(|entry| {
+                ListItem::new(vec![
+                    Line::raw(entry.variable_name.clone()).fg(Color::Green),
+                    Line::raw(format!("  {}", entry.description)).dark_gray(),
+                ])
+            })
+            .collect();
+        frame.render_widget(List::new(items).block(Block::bordered().title(t!(" 历史记录 "))), area);
+    }
+}
+
+async fn run_app(terminal: &mut DefaultTerminal, app: &mut App) -> Result<(), Error> {
+    loop {
+        terminal.draw(|frame| app.draw(frame))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match app.handle_key(key) {
+            Action::None => {}
+            Action::Regenerate => {
+                app.status = t!("正在生成变量名...").to_string();
+                terminal.draw(|frame| app.draw(frame))?;
+                app.regenerate().await;
+                // 生成过程中的警告会写到屏幕上，整屏重绘
+                terminal.clear()?;
+            }
+            Action::Quit => return Ok(()),
+        }
+    }
+}
+
+pub(super) async fn run_tui(
+    storage: Arc<dyn Store>,
+    config: Arc<Config>,
+    generator: Arc<Generator>,
+    naming: &NamingArgs,
+) -> Result<(), Error> {
+    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+        eprintln!("{}", t!("标准输入或输出不是终端，改用交互式模式"));
+        return super::repl::run_interactive(storage, config, generator, naming).await;
+    }
+
+    let options = naming.options(&generator, None);
+    let mut app = App::new(storage, generator, options, naming.force_rule);
+    let mut terminal = ratatui::try_init()?;
+    let result = run_app(&mut terminal, &mut app).await;
+    ratatui::try_restore()?;
+
+    for name in &app.saved {
+        println!("{}", name);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::mock_generator;
+    use crate::storage::mock_storage;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn app(style: NamingStyle) -> App {
+        let generator = Arc::new(mock_generator());
+        let options = generator.resolve_options(Some(style), None, None);
+        App::new(Arc::new(mock_storage().unwrap()), generator, options, true)
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+    }
+
+    fn screen(app: &mut App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(120, 32)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_live_preview_in_every_style() {
+        let mut app = app(NamingStyle::Snake);
+        type_text(&mut app, "用户信息");
+        assert_eq!(app.candidates, vec!["user_info"]);
+        assert_eq!(app.preview(NamingStyle::Camel).as_deref(), Some("userInfo"));
+        assert_eq!(app.preview(NamingStyle::UpperSnake).as_deref(), Some("USER_INFO"));
+        assert!(app.mappings.iter().any(|m| m.translation == "user" && m.source == TokenSource::Mapping));
+
+        let screen = screen(&mut app);
+        for expected in ["user_info", "userInfo", "UserInfo", "user-info", "USER_INFO", "user.info"] {
+            assert!(screen.contains(expected), "预览中缺少 {}:\n{}", expected, screen);
+        }
+
+        // 清空输入后预览也清空
+        app.handle_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        assert!(app.candidates.is_empty() && app.mappings.is_empty());
+    }
+
+    #[test]
+    fn test_select_style_and_save() {
+        let mut app = app(NamingStyle::Snake);
+        type_text(&mut app, "订单列表");
+        let snake = NamingStyle::ALL.iter().position(|s| *s == NamingStyle::Snake).unwrap();
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(app.styles.selected(), Some(snake + 1));
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        let expected = app.preview(app.style()).unwrap();
+        assert_eq!(app.saved, vec![expected.clone()]);
+        assert_eq!(app.history[0].variable_name, expected);
+        assert_eq!(app.history[0].style, app.style());
+
+        assert_eq!(app.handle_key(KeyEvent::from(KeyCode::Enter)), Action::Regenerate);
+        assert_eq!(app.handle_key(KeyEvent::from(KeyCode::Esc)), Action::Quit);
+    }
+
+    #[tokio::test]
+    async fn test_regenerate_uses_selected_style() {
+        let mut app = app(NamingStyle::Snake);
+        type_text(&mut app, "用户信息");
+        app.styles.select(NamingStyle::ALL.iter().position(|s| *s == NamingStyle::Pascal));
+        app.regenerate().await;
+        assert_eq!(app.preview(app.style()).as_deref(), Some("UserInfo"));
+        assert!(screen(&mut app).contains("1. UserInfo"));
+    }
+
+    #[tokio::test]
+    async fn test_flat_style_keeps_word_boundaries() {
+        let mut app = app(NamingStyle::Flat);
+        assert_eq!(app.style(), NamingStyle::Flat);
+        type_text(&mut app, "用户信息");
+        app.regenerate().await;
+        assert_eq!(app.candidates, vec!["user_info"]);
+
+        let screen = screen(&mut app);
+        for expected in ["1. userinfo", "userInfo", "UserInfo", "USER_INFO", "user.info"] {
+            assert!(screen.contains(expected), "预览中缺少 {}:\n{}", expected, screen);
+        }
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        assert_eq!(app.saved, vec!["userinfo"]);
+        assert_eq!(app.history[0].style, NamingStyle::Flat);
+    }
+}
@@ -136,31 +136,35 @@ impl Default for MappingConfig {
     }
 }
 
+/// 从字符串加载配置（支持JSON和TOML格式）
+impl std::str::FromStr for MappingConfig {
+    type Err = Error;
+    
+    fn from_str(content: &str) -> Result<Self, Error> {
+        // 首先尝试解析为JSON
+        if let Ok(config) = serde_json::from_str::<MappingConfig>(content) {
+            return Ok(config);
+        }
+        
+        // 如果JSON解析失败，尝试解析为TOML
+        toml::from_str(content)
+            .map_err(|e| Error::ConfigError(t!("解析映射配置文件失败: {error}", error = e)))
+    }
+}
+
 impl MappingConfig {
     /// 从文件加载配置
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
         let path = path.as_ref();
         
         if !path.exists() {
-            return Err(Error::ConfigError(format!("映射配置文件不存在: {}", path.display())));
+            return Err(Error::ConfigError(t!("映射配置文件不存在: {path}", path = path.display())));
         }
         
         let content = fs::read_to_string(path)
-            .map_err(|e| Error::ConfigError(format!("读取映射配置文件失败: {}", e)))?;
+            .map_err(|e| Error::ConfigError(t!("读取映射配置文件失败: {error}", error = e)))?;
         
-        Self::from_str(&content)
-    }
-    
-    /// 从字符串加载配置（支持JSON和TOML格式）
-    pub fn from_str(content: &str) -> Result<Self, Error> {
-        // 首先尝试解析为JSON
-        if let Ok(config) = serde_json::from_str::<MappingConfig>(content) {
-            return Ok(config);
-        }
-        
-        // 如果JSON解析失败，尝试解析为TOML
-        toml::from_str(content)
-            .map_err(|e| Error::ConfigError(format!("解析映射配置文件失败: {}", e)))
+        content.parse()
     }
     
     /// 保存配置到文件（使用TOML格式）
@@ -170,27 +174,26 @@ impl MappingConfig {
         // 确保父目录存在
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)
-                .map_err(|e| Error::ConfigError(format!("创建配置目录失败: {}", e)))?;
+                .map_err(|e| Error::ConfigError(t!("创建配置目录失败: {error}", error = e)))?;
         }
         
         // 根据文件扩展名决定格式
         let content = if path.extension().and_then(|s| s.to_str()) == Some("json") {
             serde_json::to_string_pretty(self)
-                .map_err(|e| Error::ConfigError(format!("序列化映射配置失败: {}", e)))?
+                .map_err(|e| Error::ConfigError(t!("序列化映射配置失败: {error}", error = e)))?
         } else {
             // 默认使用TOML格式
             toml::to_string_pretty(self)
-                .map_err(|e| Error::ConfigError(format!("序列化映射配置失败: {}", e)))?
+                .map_err(|e| Error::ConfigError(t!("序列化映射配置失败: {error}", error = e)))?
         };
         
         fs::write(path, content)
-            .map_err(|e| Error::ConfigError(format!("保存映射配置文件失败: {}", e)))?;
+            .map_err(|e| Error::ConfigError(t!("保存映射配置文件失败: {error}", error = e)))?;
         
         Ok(())
     }
     
     /// 合并另一个配置（用于扩展）
-    #[allow(dead_code)]
     pub fn merge(&mut self, other: MappingConfig) {
         // 合并词汇映射（后面的覆盖前面的）
         for (key, value) in other.mappings {
@@ -252,7 +255,7 @@ impl MappingConfigManager {
         
         // 验证配置文件路径有效（消除未使用字段警告）
         let _ = config_path.to_str().ok_or_else(|| {
-            Error::ConfigError("无效的配置文件路径".to_string())
+            Error::ConfigError(t!("无效的配置文件路径").to_string())
         })?;
         
         // 如果配置文件不存在，创建默认配置
@@ -320,7 +323,7 @@ mod tests {
     fn test_mapping_config_serialization() {
         let config = MappingConfig::default();
         let json = serde_json::to_string(&config).unwrap();
-        let loaded_config = MappingConfig::from_str(&json).unwrap();
+        let loaded_config: MappingConfig = json.parse().unwrap();
         
         assert_eq!(loaded_config.version, config.version);
         assert_eq!(loaded_config.mappings.len(), config.mappings.len());
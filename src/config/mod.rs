@@ -1,152 +1,590 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
-use crate::storage::Storage;
+use crate::i18n::Locale;
+use crate::naming::{IdentifierKind, Language, NamingStyle};
+use crate::storage::Store;
+use crate::utils::duration::{format_duration, parse_duration};
 use crate::utils::error::Error;
 
 pub mod mapping;
+pub mod profile;
+pub mod project;
+pub mod provider;
+pub mod secret;
+pub mod settings;
 
-#[derive(Debug)]
+use profile::Profile;
+use project::ProjectConfig;
+use provider::Provider;
+use secret::{ApiKey, KeySource};
+use settings::Settings;
+
+/// 默认的大模型结果缓存有效期：24 小时
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(86400);
+
+/// `config get/set/unset` 支持的配置项及说明（说明为中文原文，显示时用 `t!` 翻译）
+pub const SETTINGS: &[(&str, &str)] = &[
+    ("default_style", "默认命名规范（如 snake、camel）"),
+    ("provider", "默认大模型服务商（qwen、xinghuo）"),
+    ("model", "当前服务商使用的模型"),
+    ("cache_enabled", "是否缓存大模型结果（true/false）"),
+    ("cache_ttl", "缓存有效期（如 3600、30m、12h、1d）"),
+    ("mapping_config_path", "自定义词汇映射文件路径"),
+    ("profile", "默认领域配置档"),
+    ("ui_lang", "界面语言（zh-CN、en-US）"),
+];
+
+/// 按服务商查找 API 密钥（环境变量、密钥文件）
+type KeyResolver = fn(Provider) -> Result<Option<ApiKey>, Error>;
+
+/// 配置项生效值的来源
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// 本次运行的命令行参数
+    CommandLine,
+    /// `VAR_GEN_*` 环境变量
+    Env,
+    /// 项目目录中的 .var-gen.toml
+    Project,
+    /// 用户配置（`var-gen config set`）
+    User,
+    /// 领域配置档的默认值
+    Profile(String),
+    /// 目标语言的命名惯例
+    Language(Language),
+    Default,
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::CommandLine => f.write_str(t!("命令行参数")),
+            Source::Env => f.write_str(t!("环境变量")),
+            Source::Project => f.write_str(&t!("项目配置 {file}", file = project::PROJECT_CONFIG_FILE)),
+            Source::User => f.write_str(t!("用户配置")),
+            Source::Profile(name) => f.write_str(&t!("配置档 {name}", name = name)),
+            Source::Language(language) => f.write_str(&t!("{language} 的命名惯例", language = language)),
+            Source::Default => f.write_str(t!("默认值")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Config {
-    storage: Arc<Storage>,
-    default_style: String,
-    api_key: Option<String>,
-    model: String,
-    cache_enabled: bool,
-    cache_ttl: Duration,
-    mapping_config_path: Option<String>,
+    /// 历史记录和大模型缓存所在的存储
+    storage: Option<Arc<dyn Store>>,
+    /// 用户配置文件路径；未设置时修改只在当前进程内生效
+    path: Option<PathBuf>,
+    /// 命令行参数，只对本次运行生效
+    overrides: Settings,
+    /// `VAR_GEN_*` 环境变量
+    env: Settings,
+    /// 项目目录中的 .var-gen.toml
+    project: Settings,
+    /// 用户配置文件
+    user: Settings,
+    /// 当前服务商的 API 密钥
+    api_key: Option<ApiKey>,
+    /// 查找 API 密钥的方式
+    resolve_key: KeyResolver,
 }
 
 impl Config {
-    pub fn new(storage: Arc<Storage>) -> Result<Self, Error> {
-        // 从存储中加载配置
-        let default_style = storage
-            .get_config("default_style")?
-            .unwrap_or_else(|| "snake".to_string());
-            
-        let api_key = storage.get_config("api_key")?;
-        
-        let model = storage
-            .get_config("model")?
-            .unwrap_or_else(|| "qwen-tiny".to_string());
-            
-        let cache_enabled = storage
-            .get_config("cache_enabled")?
-            .map(|s| s.parse().unwrap_or(true))
-            .unwrap_or(true);
-            
-        let cache_ttl = storage
-            .get_config("cache_ttl")?
-            .map(|s| Duration::from_secs(s.parse().unwrap_or(86400)))
-            .unwrap_or(Duration::from_secs(86400)); // 默认 24 小时
-            
-        let mapping_config_path = storage.get_config("mapping_config_path")?;
-            
-        Ok(Self {
-            storage,
-            default_style,
-            api_key,
-            model,
-            cache_enabled,
-            cache_ttl,
-            mapping_config_path,
+    /// 加载用户配置文件，叠加项目配置和环境变量；存储只用于历史记录和缓存
+    pub fn new(storage: Arc<dyn Store>) -> Result<Self, Error> {
+        Self::open(storage, settings::user_config_path()?)
+    }
+    
+    /// 从指定的用户配置文件加载；文件不存在时先迁移旧版本保存在存储中的配置
+    pub fn open(storage: Arc<dyn Store>, path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let project = ProjectConfig::discover()?.map(|p| p.settings()).unwrap_or_default();
+        Self::open_with(storage, path.into(), Settings::from_env()?, project, secret::resolve_for)
+    }
+    
+    /// 加载用户配置文件，环境变量层、项目配置层和密钥查找方式由调用方给出；
+    /// 测试用它避开开发者本机的环境变量、项目配置和密钥文件
+    fn open_with(
+        storage: Arc<dyn Store>,
+        path: PathBuf,
+        env: Settings,
+        project: Settings,
+        resolve_key: KeyResolver,
+    ) -> Result<Self, Error> {
+        let user = match Settings::load(&path)? {
+            Some(user) => user,
+            None => settings::migrate(storage.as_ref(), &path)?,
+        };
+        let mut config = Self {
+            storage: Some(storage),
+            path: Some(path),
+            overrides: Settings::default(),
+            env,
+            project,
+            user,
+            api_key: None,
+            resolve_key,
+        };
+        config.api_key = resolve_key(config.provider())?;
+        Ok(config)
+    }
+    
+    /// 不依赖存储构建配置，供嵌入本库的程序使用
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+    
+    /// 历史记录和缓存所在的存储
+    pub fn store(&self) -> Option<&Arc<dyn Store>> {
+        self.storage.as_ref()
+    }
+    
+    /// 用户配置文件路径
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+    
+    /// 各层配置，按优先级从高到低排列
+    fn layers(&self) -> [(Source, &Settings); 4] {
+        [
+            (Source::CommandLine, &self.overrides),
+            (Source::Env, &self.env),
+            (Source::Project, &self.project),
+            (Source::User, &self.user),
+        ]
+    }
+    
+    /// 取优先级最高的一层中设置的值及其来源
+    fn lookup<'a, T>(&'a self, f: impl Fn(&'a Settings) -> Option<T>) -> Option<(T, Source)> {
+        self.layers()
+            .into_iter()
+            .find_map(|(source, settings)| Some((f(settings)?, source)))
+    }
+    
+    /// 修改用户配置并写回配置文件；服务商变化时重新查找密钥
+    fn update(&mut self, f: impl FnOnce(&mut Settings)) -> Result<(), Error> {
+        let provider = self.provider();
+        f(&mut self.user);
+        self.refresh_api_key(provider)?;
+        match &self.path {
+            Some(path) => self.user.save(path),
+            None => Ok(()),
+        }
+    }
+    
+    fn refresh_api_key(&mut self, previous: Provider) -> Result<(), Error> {
+        if self.provider() != previous {
+            self.api_key = (self.resolve_key)(self.provider())?;
+        }
+        Ok(())
+    }
+    
+    /// 读取配置项的当前值，未知的键返回错误
+    pub fn get(&self, key: &str) -> Result<Option<String>, Error> {
+        Ok(match key {
+            "default_style" => self.default_style().map(|style| style.name().to_string()),
+            "provider" => Some(self.provider().name().to_string()),
+            "model" => Some(self.model().to_string()),
+            "cache_enabled" => Some(self.cache_enabled().to_string()),
+            "cache_ttl" => Some(format_duration(self.cache_ttl())),
+            "mapping_config_path" => self.mapping_config_path().map(str::to_string),
+            "profile" => self.profile().map(str::to_string),
+            "ui_lang" => Some(self.ui_lang().name().to_string()),
+            _ => return Err(unknown_setting(key)),
         })
     }
     
+    /// 校验并保存配置项到用户配置文件
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        let value = value.trim();
+        let mut user = self.user.clone();
+        match key {
+            "default_style" => user.default_style = Some(value.parse()?),
+            "provider" => user.provider = Some(value.parse()?),
+            "model" => {
+                // 设置模型时切换到它所属的服务商
+                let provider = Provider::from_model(value).unwrap_or(self.provider());
+                user.models.insert(provider, value.to_string());
+                user.provider = Some(provider);
+            }
+            "cache_enabled" => user.cache_enabled = Some(settings::parse_bool(value)?),
+            "cache_ttl" => user.cache_ttl = Some(parse_duration(value)?),
+            "mapping_config_path" => user.mapping_config_path = Some(value.to_string()),
+            "profile" => user.profile = Some(value.to_string()),
+            "ui_lang" => user.ui_lang = Some(value.parse()?),
+            "api_key" => {
+                return Err(Error::ConfigError(
+                    t!("API 密钥不通过 config set 保存，请使用 var-gen provider set-key 或环境变量").to_string(),
+                ))
+            }
+            _ => return Err(unknown_setting(key)),
+        }
+        user.check(key)?;
+        self.update(|settings| *settings = user)
+    }
+    
+    /// 只校验配置项的值，不修改配置
+    pub fn validate(&self, key: &str, value: &str) -> Result<(), Error> {
+        let mut probe = Config { path: None, ..self.clone() };
+        probe.set(key, value)
+    }
+    
+    /// 从用户配置文件中删除配置项
+    pub fn unset(&mut self, key: &str) -> Result<(), Error> {
+        let provider = self.provider();
+        let mut user = self.user.clone();
+        match key {
+            "default_style" => user.default_style = None,
+            "provider" => user.provider = None,
+            "model" => {
+                user.models.remove(&provider);
+            }
+            "cache_enabled" => user.cache_enabled = None,
+            "cache_ttl" => user.cache_ttl = None,
+            "mapping_config_path" => user.mapping_config_path = None,
+            "profile" => user.profile = None,
+            "ui_lang" => user.ui_lang = None,
+            _ => return Err(unknown_setting(key)),
+        }
+        self.update(|settings| *settings = user)
+    }
+    
+    /// 配置项当前值的来源
+    pub fn source(&self, key: &str) -> Result<Source, Error> {
+        let provider = self.provider();
+        let source = match key {
+            "default_style" => self.lookup(|s| s.default_style).map(|(_, source)| source),
+            "provider" => self.lookup(|s| s.provider).map(|(_, source)| source),
+            "model" => self.lookup(|s| s.models.get(&provider)).map(|(_, source)| source),
+            "cache_enabled" => self.lookup(|s| s.cache_enabled).map(|(_, source)| source),
+            "cache_ttl" => self.lookup(|s| s.cache_ttl).map(|(_, source)| source),
+            "mapping_config_path" => self.lookup(|s| s.mapping_config_path.as_ref()).map(|(_, source)| source),
+            "profile" => self.lookup(|s| s.profile.as_ref()).map(|(_, source)| source),
+            "ui_lang" => self.lookup(|s| s.ui_lang).map(|(_, source)| source),
+            _ => return Err(unknown_setting(key)),
+        };
+        Ok(source.unwrap_or(Source::Default))
+    }
+    
+    /// 命名规范的各级候选，按优先级排列：命令行、环境变量、项目配置、用户配置、配置档、语言惯例、snake
+    pub fn style_chain(
+        &self,
+        style: Option<NamingStyle>,
+        language: Option<Language>,
+        kind: Option<IdentifierKind>,
+        profile: Option<&Profile>,
+    ) -> Vec<(Source, Option<NamingStyle>)> {
+        let mut chain = vec![
+            (Source::CommandLine, style),
+            (Source::Env, self.env.default_style),
+            (Source::Project, self.project.default_style),
+            (Source::User, self.user.default_style),
+        ];
+        if let Some(profile) = profile {
+            chain.push((Source::Profile(profile.name.clone()), profile.default_style));
+        }
+        if let Some(language) = language {
+            chain.push((Source::Language(language), Some(language.default_style(kind))));
+        }
+        chain.push((Source::Default, Some(NamingStyle::Snake)));
+        chain
+    }
+    
+    /// 取优先级最高的命名规范及其来源
+    pub fn resolve_style(
+        &self,
+        style: Option<NamingStyle>,
+        language: Option<Language>,
+        kind: Option<IdentifierKind>,
+        profile: Option<&Profile>,
+    ) -> (NamingStyle, Source) {
+        self.style_chain(style, language, kind, profile)
+            .into_iter()
+            .find_map(|(source, style)| Some((style?, source)))
+            .unwrap_or((NamingStyle::Snake, Source::Default))
+    }
+    
     // 配置访问方法
-    #[allow(dead_code)]
-    pub fn default_style(&self) -> &str {
-        &self.default_style
+    /// 环境变量、项目或用户配置的默认命名规范
+    pub fn default_style(&self) -> Option<NamingStyle> {
+        self.lookup(|s| s.default_style).map(|(style, _)| style)
+    }
+    
+    pub fn set_default_style(&mut self, style: NamingStyle) -> Result<(), Error> {
+        self.update(|settings| settings.default_style = Some(style))
     }
     
     pub fn mapping_config_path(&self) -> Option<&str> {
-        self.mapping_config_path.as_deref()
+        self.lookup(|s| s.mapping_config_path.as_deref()).map(|(path, _)| path)
+    }
+    
+    /// 仅对当前进程使用指定的映射文件（用于 --mapping-config，不写入配置文件）
+    pub fn use_mapping_config_path(&mut self, path: &str) {
+        self.overrides.mapping_config_path = Some(path.to_string());
     }
     
-    #[allow(dead_code)]
     pub fn set_mapping_config_path(&mut self, path: &str) -> Result<(), Error> {
-        self.mapping_config_path = Some(path.to_string());
-        self.storage.save_config("mapping_config_path", path)
+        self.update(|settings| settings.mapping_config_path = Some(path.to_string()))
     }
     
-    #[allow(dead_code)]
     pub fn clear_mapping_config_path(&mut self) -> Result<(), Error> {
-        self.mapping_config_path = None;
-        self.storage.save_config("mapping_config_path", "")
+        self.update(|settings| settings.mapping_config_path = None)
+    }
+    
+    /// 当前生效的领域配置档名称
+    pub fn profile(&self) -> Option<&str> {
+        self.lookup(|s| s.profile.as_deref()).map(|(profile, _)| profile)
     }
     
-    #[allow(dead_code)]
-    pub fn set_default_style(&mut self, style: &str) -> Result<(), Error> {
-        self.default_style = style.to_string();
-        self.storage.save_config("default_style", style)
+    /// 仅对当前进程切换配置档（用于 --profile，不写入配置文件）
+    pub fn use_profile(&mut self, profile: &str) {
+        self.overrides.profile = Some(profile.to_string());
     }
     
+    pub fn set_profile(&mut self, profile: &str) -> Result<(), Error> {
+        self.update(|settings| settings.profile = Some(profile.to_string()))
+    }
+    
+    /// 界面语言；未设置时按 `LC_ALL`/`LC_MESSAGES`/`LANG` 确定，默认为 zh-CN
+    pub fn ui_lang(&self) -> Locale {
+        self.lookup(|s| s.ui_lang)
+            .map(|(locale, _)| locale)
+            .or_else(Locale::from_env)
+            .unwrap_or_default()
+    }
+    
+    /// 仅对当前进程使用指定的界面语言（用于 --lang-ui，不写入配置文件）
+    pub fn use_ui_lang(&mut self, locale: Locale) {
+        self.overrides.ui_lang = Some(locale);
+    }
+    
+    /// 当前模型的 API 密钥明文，只用于发送请求
     pub fn api_key(&self) -> Option<&str> {
-        self.api_key.as_deref()
+        self.api_key.as_ref().map(ApiKey::expose)
+    }
+    
+    /// API 密钥及其来源，用于展示
+    pub fn api_key_info(&self) -> Option<&ApiKey> {
+        self.api_key.as_ref()
     }
     
-    #[allow(dead_code)]
+    /// 指定服务商的 API 密钥
+    pub fn api_key_for(&self, provider: Provider) -> Result<Option<ApiKey>, Error> {
+        if provider == self.provider() {
+            return Ok(self.api_key.clone());
+        }
+        (self.resolve_key)(provider)
+    }
+    
+    /// 当前使用的服务商
+    pub fn provider(&self) -> Provider {
+        self.lookup(|s| s.provider).map_or(Provider::Qwen, |(provider, _)| provider)
+    }
+    
+    /// 仅对当前进程切换服务商（用于 --provider，不写入配置文件）
+    pub fn use_provider(&mut self, provider: Provider) -> Result<(), Error> {
+        let previous = self.provider();
+        self.overrides.provider = Some(provider);
+        self.refresh_api_key(previous)
+    }
+    
+    /// 切换默认服务商
+    pub fn set_provider(&mut self, provider: Provider) -> Result<(), Error> {
+        self.update(|settings| settings.provider = Some(provider))
+    }
+    
+    /// 以 0600 权限写入当前服务商的密钥文件
     pub fn set_api_key(&mut self, api_key: &str) -> Result<(), Error> {
-        self.api_key = Some(api_key.to_string());
-        self.storage.save_config("api_key", api_key)
+        let provider = self.provider();
+        provider.validate_credential(api_key)?;
+        if self.path.is_none() {
+            self.api_key = Some(ApiKey::new(api_key, KeySource::Inline));
+            return Ok(());
+        }
+        let path = secret::write_key_file(provider.name(), api_key)?;
+        self.api_key = Some(ApiKey::new(api_key, KeySource::File(path)));
+        Ok(())
     }
     
-    #[allow(dead_code)]
+    /// 用口令加密保存当前服务商的密钥，读取时需要设置环境变量 VAR_GEN_KEY_PASSPHRASE
+    pub fn set_api_key_encrypted(&mut self, api_key: &str, passphrase: &str) -> Result<(), Error> {
+        let provider = self.provider();
+        provider.validate_credential(api_key)?;
+        if self.path.is_none() {
+            self.api_key = Some(ApiKey::new(api_key, KeySource::Inline));
+            return Ok(());
+        }
+        let path = secret::write_encrypted(provider.name(), api_key, passphrase)?;
+        // 密钥文件优先于加密存储，保留会遮住新密钥
+        let key_file = secret::key_file(provider.name())?;
+        if key_file.exists() {
+            std::fs::remove_file(key_file)?;
+        }
+        self.api_key = Some(ApiKey::new(api_key, KeySource::Encrypted(path)));
+        Ok(())
+    }
+    
+    /// 删除当前服务商的密钥文件和加密存储条目
     pub fn clear_api_key(&mut self) -> Result<(), Error> {
         self.api_key = None;
-        self.storage.save_config("api_key", "")
+        if self.path.is_some() {
+            secret::remove(self.provider().name())?;
+        }
+        Ok(())
     }
     
+    /// 当前服务商使用的模型
     pub fn model(&self) -> &str {
-        &self.model
+        self.model_for(self.provider())
+    }
+    
+    pub fn model_for(&self, provider: Provider) -> &str {
+        self.lookup(|s| s.models.get(&provider))
+            .map_or(provider.default_model(), |(model, _)| model.as_str())
+    }
+    
+    /// 仅对当前进程切换模型（用于 --model），能从模型名推断服务商时一并切换
+    pub fn use_model(&mut self, model: &str) -> Result<(), Error> {
+        let previous = self.provider();
+        if let Some(provider) = Provider::from_model(model) {
+            self.overrides.provider = Some(provider);
+        }
+        let provider = self.provider();
+        self.overrides.models.insert(provider, model.to_string());
+        self.refresh_api_key(previous)
     }
     
-    #[allow(dead_code)]
+    /// 设置模型并切换到它所属的服务商
     pub fn set_model(&mut self, model: &str) -> Result<(), Error> {
-        self.model = model.to_string();
-        self.storage.save_config("model", model)
+        self.set("model", model)
     }
     
-    #[allow(dead_code)]
     pub fn cache_enabled(&self) -> bool {
-        self.cache_enabled
+        self.lookup(|s| s.cache_enabled).is_none_or(|(enabled, _)| enabled)
     }
     
-    #[allow(dead_code)]
     pub fn set_cache_enabled(&mut self, enabled: bool) -> Result<(), Error> {
-        self.cache_enabled = enabled;
-        self.storage.save_config("cache_enabled", &enabled.to_string())
+        self.update(|settings| settings.cache_enabled = Some(enabled))
     }
     
-    #[allow(dead_code)]
     pub fn cache_ttl(&self) -> Duration {
-        self.cache_ttl
+        self.lookup(|s| s.cache_ttl).map_or(DEFAULT_CACHE_TTL, |(ttl, _)| ttl)
     }
     
-    #[allow(dead_code)]
     pub fn set_cache_ttl(&mut self, ttl: Duration) -> Result<(), Error> {
-        self.cache_ttl = ttl;
-        self.storage.save_config("cache_ttl", &ttl.as_secs().to_string())
+        self.update(|settings| settings.cache_ttl = Some(ttl))
     }
     
-    // 重置所有配置到默认值
-    #[allow(dead_code)]
+    /// 清空用户配置文件，并删除当前服务商的 API 密钥
     pub fn reset_to_defaults(&mut self) -> Result<(), Error> {
-        self.default_style = "snake".to_string();
-        self.api_key = None;
-        self.model = "qwen-tiny".to_string();
-        self.cache_enabled = true;
-        self.cache_ttl = Duration::from_secs(86400);
-        self.mapping_config_path = None;
-        
-        self.storage.save_config("default_style", "snake")?;
-        self.storage.save_config("api_key", "")?;
-        self.storage.save_config("model", "qwen-tiny")?;
-        self.storage.save_config("cache_enabled", "true")?;
-        self.storage.save_config("cache_ttl", "86400")?;
-        self.storage.save_config("mapping_config_path", "")?;
-        
-        Ok(())
+        self.clear_api_key()?;
+        self.update(|settings| *settings = Settings::default())
+    }
+}
+
+fn unknown_setting(key: &str) -> Error {
+    let keys: Vec<&str> = SETTINGS.iter().map(|(key, _)| *key).collect();
+    Error::ConfigError(t!("未知的配置项: {key}（可选 {keys}）", key = key, keys = keys.join("、")))
+}
+
+/// 配置构建器，未设置的项使用默认值
+///
+/// ```
+/// use var_gen::Config;
+///
+/// let config = Config::builder().profile("commerce").model("qwen-tiny").build();
+/// assert_eq!(config.profile(), Some("commerce"));
+/// ```
+#[derive(Debug, Default)]
+pub struct ConfigBuilder {
+    storage: Option<Arc<dyn Store>>,
+    default_style: Option<NamingStyle>,
+    api_key: Option<String>,
+    provider: Option<Provider>,
+    model: Option<String>,
+    cache_enabled: Option<bool>,
+    cache_ttl: Option<Duration>,
+    mapping_config_path: Option<String>,
+    profile: Option<String>,
+}
+
+impl ConfigBuilder {
+    /// 缓存大模型结果的存储
+    pub fn store(mut self, storage: Arc<dyn Store>) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+    
+    pub fn default_style(mut self, style: NamingStyle) -> Self {
+        self.default_style = Some(style);
+        self
+    }
+    
+    pub fn api_key(mut self, api_key: &str) -> Self {
+        self.api_key = Some(api_key.to_string());
+        self
+    }
+    
+    /// 服务商；未设置时由模型推断，默认为通义千问
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+    
+    pub fn model(mut self, model: &str) -> Self {
+        self.model = Some(model.to_string());
+        self
+    }
+    
+    pub fn cache_enabled(mut self, enabled: bool) -> Self {
+        self.cache_enabled = Some(enabled);
+        self
+    }
+    
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+    
+    /// 自定义词汇映射文件路径
+    pub fn mapping_config_path(mut self, path: &str) -> Self {
+        self.mapping_config_path = Some(path.to_string());
+        self
+    }
+    
+    /// 领域配置档名称
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+    
+    pub fn build(self) -> Config {
+        let provider = self
+            .provider
+            .or_else(|| self.model.as_deref().and_then(Provider::from_model))
+            .unwrap_or(Provider::Qwen);
+        let user = Settings {
+            default_style: self.default_style,
+            provider: Some(provider),
+            cache_enabled: self.cache_enabled,
+            cache_ttl: self.cache_ttl,
+            mapping_config_path: self.mapping_config_path,
+            profile: self.profile,
+            ui_lang: None,
+            models: self.model.map(|model| BTreeMap::from([(provider, model)])).unwrap_or_default(),
+        };
+        Config {
+            storage: self.storage,
+            path: None,
+            overrides: Settings::default(),
+            env: Settings::default(),
+            project: Settings::default(),
+            user,
+            api_key: self.api_key.map(|key| ApiKey::new(&key, KeySource::Inline)),
+            resolve_key: secret::resolve_for,
+        }
     }
 }
 
@@ -154,26 +592,14 @@ impl Config {
 #[cfg(test)]
 #[allow(dead_code)]
 pub fn mock_config() -> Config {
-    use crate::storage::mock_storage;
-    
-    let storage = Arc::new(mock_storage().unwrap());
-    
-    Config {
-        storage,
-        default_style: "snake".to_string(),
-        api_key: None,
-        model: "qwen-tiny".to_string(),
-        cache_enabled: true,
-        cache_ttl: Duration::from_secs(86400),
-        mapping_config_path: None,
-    }
+    Config::builder().build()
 }
 
 #[cfg(test)]
 #[allow(dead_code)]
 pub fn mock_config_with_api_key(api_key: &str) -> Config {
     let mut config = mock_config();
-    config.api_key = Some(api_key.to_string());
+    config.api_key = Some(ApiKey::new(api_key, KeySource::Inline));
     config
 }
 
@@ -181,6 +607,119 @@ pub fn mock_config_with_api_key(api_key: &str) -> Config {
 #[allow(dead_code)]
 pub fn mock_config_with_mapping_path(path: &str) -> Config {
     let mut config = mock_config();
-    config.mapping_config_path = Some(path.to_string());
+    config.user.mapping_config_path = Some(path.to_string());
     config
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStore;
+
+    /// 只加载临时目录中的用户配置文件，不受本机环境变量、项目配置和密钥文件影响
+    fn open(dir: &tempfile::TempDir) -> Result<Config, Error> {
+        let path = dir.path().join("config.toml");
+        Config::open_with(Arc::new(MemoryStore::new()), path, Settings::default(), Settings::default(), |_| Ok(None))
+    }
+
+    #[test]
+    fn test_set_validates_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = open(&dir).unwrap();
+
+        config.set("default_style", "camel").unwrap();
+        config.set("cache_ttl", "12h").unwrap();
+        config.set("cache_enabled", "off").unwrap();
+        assert!(config.set("default_style", "foo").is_err());
+        assert!(config.set("cache_ttl", "12x").is_err());
+        assert!(config.set("cache_ttl", "0").is_err());
+        assert!(config.set("cache_enabled", "maybe").is_err());
+        assert!(config.set("api_key", "sk-abc").is_err());
+        assert!(config.set("unknown", "1").is_err());
+
+        let config = open(&dir).unwrap();
+        assert_eq!(config.default_style(), Some(NamingStyle::Camel));
+        assert_eq!(config.cache_ttl(), Duration::from_secs(12 * 3600));
+        assert_eq!(config.get("cache_ttl").unwrap().as_deref(), Some("12h"));
+        assert!(!config.cache_enabled());
+    }
+
+    #[test]
+    fn test_invalid_config_file_is_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "cache_ttl = \"abc\"\ndefault_style = \"camel\"\n").unwrap();
+        let err = open(&dir).unwrap_err().to_string();
+        assert!(err.contains("cache_ttl"), "{}", err);
+
+        // 删除出错的项后其他配置保留
+        settings::discard_invalid(&path, "cache_ttl").unwrap();
+        let config = open(&dir).unwrap();
+        assert_eq!(config.cache_ttl(), DEFAULT_CACHE_TTL);
+        assert_eq!(config.default_style(), Some(NamingStyle::Camel));
+    }
+
+    #[test]
+    fn test_command_line_overrides_user_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = open(&dir).unwrap();
+        config.set("profile", "commerce").unwrap();
+        config.set("model", "qwen-max").unwrap();
+
+        config.use_profile("support");
+        config.use_model("generalv3.5").unwrap();
+        assert_eq!(config.profile(), Some("support"));
+        assert_eq!(config.provider(), Provider::Xinghuo);
+        assert_eq!(config.source("model").unwrap(), Source::CommandLine);
+        assert_eq!(config.model_for(Provider::Qwen), "qwen-max");
+
+        // 命令行参数不写入配置文件
+        let config = open(&dir).unwrap();
+        assert_eq!(config.profile(), Some("commerce"));
+        assert_eq!(config.provider(), Provider::Qwen);
+        assert_eq!(config.source("profile").unwrap(), Source::User);
+    }
+
+    #[test]
+    fn test_resolve_style_precedence() {
+        let mut config = Config::builder().build();
+        assert_eq!(config.resolve_style(None, None, None, None), (NamingStyle::Snake, Source::Default));
+        assert_eq!(
+            config.resolve_style(None, Some(Language::Java), None, None),
+            (NamingStyle::Camel, Source::Language(Language::Java))
+        );
+        assert_eq!(
+            config.resolve_style(None, Some(Language::Java), Some(IdentifierKind::Type), None),
+            (NamingStyle::Pascal, Source::Language(Language::Java))
+        );
+
+        config.set_default_style(NamingStyle::Kebab).unwrap();
+        assert_eq!(config.resolve_style(None, Some(Language::Java), None, None), (NamingStyle::Kebab, Source::User));
+
+        config.project.default_style = Some(NamingStyle::Pascal);
+        assert_eq!(config.resolve_style(None, None, None, None), (NamingStyle::Pascal, Source::Project));
+        config.env.default_style = Some(NamingStyle::Dot);
+        assert_eq!(config.resolve_style(None, None, None, None), (NamingStyle::Dot, Source::Env));
+        assert_eq!(
+            config.resolve_style(Some(NamingStyle::Flat), None, None, None),
+            (NamingStyle::Flat, Source::CommandLine)
+        );
+    }
+
+    #[test]
+    fn test_unset_restores_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = open(&dir).unwrap();
+        config.set("provider", "xinghuo").unwrap();
+        config.set("model", "generalv3.5").unwrap();
+        assert_eq!(config.source("model").unwrap(), Source::User);
+
+        config.unset("model").unwrap();
+        assert_eq!(config.model(), "xinghuo-lite");
+        config.unset("provider").unwrap();
+        assert_eq!(config.source("provider").unwrap(), Source::Default);
+
+        let config = open(&dir).unwrap();
+        assert_eq!(config.provider(), Provider::Qwen);
+        assert_eq!(config.model_for(Provider::Xinghuo), "xinghuo-lite");
+    }
+}
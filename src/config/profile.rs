@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::naming::{IdentifierKind, Language, NamingStyle};
+use crate::config::mapping::MappingConfig;
+use crate::generator::rule::abbreviation::AbbreviationPreference;
+use crate::generator::rule::casing::AcronymPolicy;
+use crate::utils::error::Error;
+
+/// 领域配置档（profile）
+///
+/// 不同领域的团队对同一个词有不同的译法（如“单”在电商中是 order，在客服中是 ticket），
+/// profile 把一层词汇映射、停用词、偏好缩写、默认命名参数和大模型提示打包在一起。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    /// 配置档名称
+    pub name: String,
+    /// 配置档描述
+    pub description: Option<String>,
+    /// 叠加在基础映射之上的词汇映射
+    pub mappings: HashMap<String, String>,
+    /// 额外的停用词
+    pub stop_words: Vec<String>,
+    /// 偏好缩写（完整词 -> 缩写）
+    pub abbreviations: HashMap<String, String>,
+    /// 标识符最大长度目标
+    pub max_length: Option<usize>,
+    /// 完整/缩写偏好
+    pub abbreviation_preference: Option<AbbreviationPreference>,
+    /// 额外的缩略词（如 sku、erp）
+    pub acronyms: Vec<String>,
+    /// 缩略词大小写策略
+    pub acronym_policy: Option<AcronymPolicy>,
+    /// 默认命名规范
+    pub default_style: Option<NamingStyle>,
+    /// 默认标识符类型
+    pub default_kind: Option<IdentifierKind>,
+    /// 默认目标编程语言
+    pub default_language: Option<Language>,
+    /// 传递给大模型的领域提示
+    pub prompt_hints: Vec<String>,
+}
+
+impl Profile {
+    /// 按名称加载配置档：优先用户目录下的文件，其次内置配置档
+    pub fn load(name: &str) -> Result<Self, Error> {
+        if let Some(dir) = profiles_dir() {
+            for ext in ["toml", "json"] {
+                let path = dir.join(format!("{}.{}", name, ext));
+                if path.exists() {
+                    return Self::from_file(&path, name);
+                }
+            }
+        }
+
+        Self::builtin(name)
+            .ok_or_else(|| Error::ConfigError(t!("未找到配置档: {name}", name = name)))
+    }
+
+    /// 从文件加载配置档（支持JSON和TOML格式）
+    pub fn from_file<P: AsRef<Path>>(path: P, name: &str) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| Error::ConfigError(t!("读取配置档失败: {error}", error = e)))?;
+
+        let mut profile = match serde_json::from_str::<Profile>(&content) {
+            Ok(profile) => profile,
+            Err(_) => toml::from_str(&content)
+                .map_err(|e| Error::ConfigError(t!("解析配置档失败: {error}", error = e)))?,
+        };
+
+        if profile.name.is_empty() {
+            profile.name = name.to_string();
+        }
+
+        Ok(profile)
+    }
+
+    /// 将配置档中的映射和停用词转换为映射配置层
+    pub fn mapping_layer(&self) -> MappingConfig {
+        MappingConfig {
+            mappings: self.mappings.clone(),
+            stop_words: self.stop_words.clone(),
+            version: "1.0".to_string(),
+            description: self.description.clone(),
+        }
+    }
+
+    /// 所有可用的配置档名称（内置 + 用户目录）
+    pub fn available() -> Vec<String> {
+        let mut names: Vec<String> = BUILTIN_PROFILES.iter().map(|s| s.to_string()).collect();
+
+        if let Some(dir) = profiles_dir() {
+            if let Ok(entries) = fs::read_dir(dir) {
+                for entry in entries.flatten() {
+                    let path = entry.path();
+                    let is_profile = matches!(
+                        path.extension().and_then(|s| s.to_str()),
+                        Some("toml") | Some("json")
+                    );
+                    if let (true, Some(stem)) = (is_profile, path.file_stem().and_then(|s| s.to_str())) {
+                        if !names.iter().any(|n| n == stem) {
+                            names.push(stem.to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        names
+    }
+
+    /// 内置配置档
+    pub fn builtin(name: &str) -> Option<Self> {
+        let profile = match name {
+            "commerce" => Profile {
+                name: name.to_string(),
+                description: Some("电商领域".to_string()),
+                mappings: to_map(&[
+                    ("单", "order"),
+                    ("订单", "order"),
+                    ("购物车", "cart"),
+                    ("商品", "product"),
+                    ("库存", "inventory"),
+                    ("支付", "payment"),
+                    ("退款", "refund"),
+                    ("优惠券", "coupon"),
+                    ("客户", "customer"),
+                    ("流", "flow"),
+                ]),
+                abbreviations: to_map(&[("quantity", "qty"), ("identifier", "id")]),
+                acronyms: vec!["sku".to_string(), "spu".to_string()],
+                prompt_hints: vec![
+                    "业务领域是电商，“单”通常指订单 (order)".to_string(),
+                ],
+                ..Default::default()
+            },
+            "support" => Profile {
+                name: name.to_string(),
+                description: Some("客服/工单领域".to_string()),
+                mappings: to_map(&[
+                    ("单", "ticket"),
+                    ("工单", "ticket"),
+                    ("客户", "customer"),
+                    ("客服", "agent"),
+                    ("回复", "reply"),
+                    ("升级", "escalate"),
+                    ("流", "flow"),
+                ]),
+                prompt_hints: vec![
+                    "业务领域是客服系统，“单”通常指工单 (ticket)".to_string(),
+                ],
+                ..Default::default()
+            },
+            "streaming" => Profile {
+                name: name.to_string(),
+                description: Some("流式数据/音视频领域".to_string()),
+                mappings: to_map(&[
+                    ("流", "stream"),
+                    ("帧", "frame"),
+                    ("缓冲", "buffer"),
+                    ("分片", "chunk"),
+                ]),
+                abbreviations: to_map(&[("buffer", "buf")]),
+                prompt_hints: vec![
+                    "业务领域是流式数据处理，“流”指数据流 (stream)".to_string(),
+                ],
+                ..Default::default()
+            },
+            _ => return None,
+        };
+
+        Some(profile)
+    }
+}
+
+/// 内置配置档名称
+pub const BUILTIN_PROFILES: &[&str] = &["commerce", "support", "streaming"];
+
+/// 用户配置档目录：~/.var-gen/profiles
+pub fn profiles_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".var-gen").join("profiles"))
+}
+
+fn to_map(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_profiles_translate_differently() {
+        let commerce = Profile::load("commerce").unwrap();
+        let support = Profile::load("support").unwrap();
+
+        assert_eq!(commerce.mappings.get("单").unwrap(), "order");
+        assert_eq!(support.mappings.get("单").unwrap(), "ticket");
+    }
+
+    #[test]
+    fn test_unknown_profile_is_error() {
+        assert!(Profile::load("no-such-profile").is_err());
+    }
+
+    #[test]
+    fn test_profile_from_toml_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("team.toml");
+        fs::write(
+            &path,
+            "default_style = \"camel\"\nprompt_hints = [\"金融\"]\n[mappings]\n\"单\" = \"bill\"\n",
+        )
+        .unwrap();
+
+        let profile = Profile::from_file(&path, "team").unwrap();
+        assert_eq!(profile.name, "team");
+        assert_eq!(profile.default_style, Some(NamingStyle::Camel));
+        assert_eq!(profile.mapping_layer().get_mapping("单").unwrap(), "bill");
+    }
+}
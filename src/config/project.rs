@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::settings::Settings;
+use crate::naming::{IdentifierKind, NamingStyle};
+use crate::storage::StorageBackend;
+use crate::utils::error::Error;
+
+/// 项目级配置文件名
+pub const PROJECT_CONFIG_FILE: &str = ".var-gen.toml";
+
+/// 项目级配置（位于项目目录中的 .var-gen.toml）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectConfig {
+    /// 项目默认的命名规范，优先于用户配置
+    pub default_style: Option<NamingStyle>,
+    
+    /// 项目使用的领域配置档
+    pub profile: Option<String>,
+    
+    /// 存储后端（sled、json 或 memory），环境变量 VAR_GEN_STORAGE 优先
+    pub storage: Option<StorageBackend>,
+    
+    /// lint 命令的检查设置
+    pub lint: LintSettings,
+}
+
+/// lint 命令的检查设置（.var-gen.toml 中的 [lint] 部分）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LintSettings {
+    /// 各类标识符要求的命名规范，覆盖语言默认值
+    pub styles: HashMap<IdentifierKind, NamingStyle>,
+    /// 忽略的路径（相对于扫描目录的前缀，或文件/目录名）
+    pub ignore: Vec<String>,
+    /// 禁用的规则（如 pinyin-identifier）
+    pub disable: Vec<String>,
+}
+
+impl ProjectConfig {
+    /// 参与配置合并的设置
+    pub fn settings(&self) -> Settings {
+        Settings {
+            default_style: self.default_style,
+            profile: self.profile.clone(),
+            ..Settings::default()
+        }
+    }
+    
+    /// 从当前目录向上查找并加载项目配置
+    pub fn discover() -> Result<Option<Self>, Error> {
+        let cwd = std::env::current_dir()?;
+        match find_project_config(&cwd) {
+            Some(path) => Self::from_file(&path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// 从文件加载项目配置
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| Error::ConfigError(t!("读取项目配置失败: {error}", error = e)))?;
+
+        toml::from_str(&content).map_err(|e| {
+            Error::ConfigError(t!("解析项目配置失败 {path}: {error}", path = path.display(), error = e))
+        })
+    }
+}
+
+/// 从指定目录开始逐级向上查找项目配置文件
+pub fn find_project_config(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(PROJECT_CONFIG_FILE))
+        .find(|path| path.is_file())
+}
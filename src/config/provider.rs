@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::error::Error;
+
+/// 大模型服务商，每家分别保存密钥和模型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum Provider {
+    /// 阿里云 DashScope 通义千问
+    #[cfg_attr(feature = "cli", clap(name = "qwen", help = t!("阿里云 DashScope 通义千问")))]
+    Qwen,
+    /// 讯飞星火
+    #[cfg_attr(feature = "cli", clap(name = "xinghuo", alias = "spark", help = t!("讯飞星火")))]
+    Xinghuo,
+}
+
+impl Provider {
+    pub const ALL: [Provider; 2] = [Provider::Qwen, Provider::Xinghuo];
+
+    /// 命令行、环境变量和密钥文件中使用的名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Provider::Qwen => "qwen",
+            Provider::Xinghuo => "xinghuo",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Provider::Qwen => t!("通义千问"),
+            Provider::Xinghuo => t!("讯飞星火"),
+        }
+    }
+
+    /// 未单独设置模型时使用的模型
+    pub fn default_model(&self) -> &'static str {
+        match self {
+            Provider::Qwen => "qwen-tiny",
+            Provider::Xinghuo => "xinghuo-lite",
+        }
+    }
+
+    /// 常用模型，用于提示；其他模型名原样传给接口
+    pub fn models(&self) -> &'static [&'static str] {
+        match self {
+            Provider::Qwen => &["qwen-tiny", "qwen-turbo", "qwen-plus", "qwen-max"],
+            Provider::Xinghuo => &["xinghuo-lite", "generalv3", "pro-128k", "generalv3.5", "max-32k", "4.0Ultra"],
+        }
+    }
+
+    /// 根据模型名推断服务商
+    pub fn from_model(model: &str) -> Option<Provider> {
+        let model = model.trim();
+        if model.starts_with("qwen") {
+            return Some(Provider::Qwen);
+        }
+        if model.starts_with("xinghuo") || model.starts_with("spark") || Provider::Xinghuo.models().contains(&model) {
+            return Some(Provider::Xinghuo);
+        }
+        None
+    }
+
+    /// 发给接口的模型名（兼容旧版本的模型别名）
+    pub fn api_model<'a>(&self, model: &'a str) -> &'a str {
+        match (self, model) {
+            (Provider::Qwen, "qwen-tiny") => "qwen-plus",
+            (Provider::Xinghuo, "xinghuo-lite") => "spark-lite",
+            _ => model,
+        }
+    }
+
+    /// OpenAI 兼容的对话接口地址
+    pub fn endpoint(&self) -> &'static str {
+        match self {
+            Provider::Qwen => "https://dashscope.aliyuncs.com/compatible-mode/v1/chat/completions",
+            Provider::Xinghuo => "https://spark-api-open.xf-yun.com/v1/chat/completions",
+        }
+    }
+
+    /// 控制台中凭证的名称
+    pub fn credential_name(&self) -> &'static str {
+        match self {
+            Provider::Qwen => "API Key",
+            Provider::Xinghuo => "APIPassword",
+        }
+    }
+
+    /// 检查凭证格式：通义千问为 `sk-` 开头的 API Key，星火为 APIPassword 或 `APIKey:APISecret`
+    pub fn validate_credential(&self, credential: &str) -> Result<(), Error> {
+        let credential = credential.trim();
+        if credential.is_empty() || credential.chars().any(char::is_whitespace) {
+            return Err(Error::ConfigError(t!("{credential} 不能为空或包含空白字符", credential = self.credential_name())));
+        }
+
+        match self {
+            Provider::Qwen if !credential.starts_with("sk-") => Err(Error::ConfigError(
+                t!("通义千问的 API Key 应以 sk- 开头，请在 DashScope 控制台复制").to_string(),
+            )),
+            Provider::Xinghuo if credential.matches(':').count() > 1 || credential.starts_with(':') || credential.ends_with(':') => {
+                Err(Error::ConfigError(
+                    t!("讯飞星火的凭证应为控制台中的 APIPassword，或 APIKey:APISecret").to_string(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Provider {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "qwen" | "dashscope" | "tongyi" => Ok(Provider::Qwen),
+            "xinghuo" | "spark" | "xfyun" => Ok(Provider::Xinghuo),
+            other => Err(Error::ConfigError(t!(
+                "未知的服务商: {name}（可选 qwen、xinghuo）",
+                name = other
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_from_model_and_name() {
+        assert_eq!(Provider::from_model("qwen-max"), Some(Provider::Qwen));
+        assert_eq!(Provider::from_model("xinghuo-lite"), Some(Provider::Xinghuo));
+        assert_eq!(Provider::from_model("4.0Ultra"), Some(Provider::Xinghuo));
+        assert_eq!(Provider::from_model("gpt-4"), None);
+        assert_eq!("Spark".parse::<Provider>().unwrap(), Provider::Xinghuo);
+        assert_eq!(Provider::Qwen.api_model("qwen-tiny"), "qwen-plus");
+        assert_eq!(Provider::Qwen.api_model("qwen-max"), "qwen-max");
+    }
+
+    #[test]
+    fn test_validate_credential() {
+        assert!(Provider::Qwen.validate_credential("sk-abc").is_ok());
+        assert!(Provider::Qwen.validate_credential("abc").is_err());
+        assert!(Provider::Xinghuo.validate_credential("abcdef").is_ok());
+        assert!(Provider::Xinghuo.validate_credential("key:secret").is_ok());
+        assert!(Provider::Xinghuo.validate_credential("key:").is_err());
+        assert!(Provider::Xinghuo.validate_credential("a b").is_err());
+    }
+}
@@ -0,0 +1,347 @@
+//! API 密钥的读取和保存
+//!
+//! 按优先级依次查找：
+//! 1. 环境变量 `VAR_GEN_<PROVIDER>_API_KEY`（如 `VAR_GEN_QWEN_API_KEY`）
+//! 2. 密钥文件 `~/.var-gen/keys/<provider>.key`，权限必须为 0600
+//! 3. 加密存储 `~/.var-gen/keys/encrypted.json`，口令来自环境变量 `VAR_GEN_KEY_PASSPHRASE`
+//!
+//! 密钥不会出现在日志、错误信息和 `Debug` 输出中，只展示来源和指纹。
+
+use argon2::Argon2;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::provider::Provider;
+
+use crate::utils::error::Error;
+
+/// 解锁加密存储的口令所在的环境变量
+pub const PASSPHRASE_ENV: &str = "VAR_GEN_KEY_PASSPHRASE";
+
+/// 加密存储的文件名（位于密钥目录下）
+const ENCRYPTED_FILE: &str = "encrypted.json";
+
+/// 密钥的来源
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySource {
+    /// 环境变量
+    Env(String),
+    /// 密钥文件
+    File(PathBuf),
+    /// 口令加密的本地存储
+    Encrypted(PathBuf),
+    /// 嵌入本库时通过 `Config::builder()` 直接传入
+    Inline,
+}
+
+impl fmt::Display for KeySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeySource::Env(name) => f.write_str(&t!("环境变量 {name}", name = name)),
+            KeySource::File(path) => f.write_str(&t!("密钥文件 {path}", path = path.display())),
+            KeySource::Encrypted(path) => f.write_str(&t!("加密存储 {path}", path = path.display())),
+            KeySource::Inline => f.write_str(t!("程序内配置")),
+        }
+    }
+}
+
+/// API 密钥；`Debug` 和 `Display` 只输出来源和指纹
+#[derive(Clone, PartialEq, Eq)]
+pub struct ApiKey {
+    value: String,
+    source: KeySource,
+}
+
+impl ApiKey {
+    pub fn new(value: &str, source: KeySource) -> Self {
+        Self {
+            value: value.trim().to_string(),
+            source,
+        }
+    }
+
+    /// 密钥明文，只用于发送请求
+    pub fn expose(&self) -> &str {
+        &self.value
+    }
+
+    pub fn source(&self) -> &KeySource {
+        &self.source
+    }
+
+    /// SHA-256 的前 12 位十六进制，用于核对密钥而不泄露内容
+    pub fn fingerprint(&self) -> String {
+        let digest = Sha256::digest(self.value.as_bytes());
+        let hex: String = digest.iter().take(6).map(|b| format!("{:02x}", b)).collect();
+        format!("SHA256:{}", hex)
+    }
+
+    /// 把文本中出现的密钥替换为 `***`
+    pub fn redact(&self, text: &str) -> String {
+        if self.value.is_empty() {
+            return text.to_string();
+        }
+        text.replace(&self.value, "***")
+    }
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ApiKey(***, {}, {})", self.fingerprint(), self.source)
+    }
+}
+
+impl fmt::Display for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}（{}）", self.fingerprint(), self.source)
+    }
+}
+
+/// 服务商对应的环境变量名
+pub fn env_var(provider: &str) -> String {
+    format!("VAR_GEN_{}_API_KEY", provider.to_uppercase().replace('-', "_"))
+}
+
+/// 密钥目录 `~/.var-gen/keys`
+pub fn keys_dir() -> Result<PathBuf, Error> {
+    let home_dir = dirs::home_dir().ok_or_else(|| Error::ConfigError(t!("无法确定用户目录").to_string()))?;
+    Ok(home_dir.join(".var-gen").join("keys"))
+}
+
+/// 服务商的密钥文件路径
+pub fn key_file(provider: &str) -> Result<PathBuf, Error> {
+    Ok(keys_dir()?.join(format!("{}.key", provider)))
+}
+
+/// 按优先级查找服务商的密钥；密钥文件权限过宽或口令错误时给出警告并跳过
+pub fn resolve(provider: &str, passphrase: Option<&str>) -> Result<Option<ApiKey>, Error> {
+    let var = env_var(provider);
+    if let Some(value) = std::env::var(&var).ok().filter(|v| !v.trim().is_empty()) {
+        return Ok(Some(ApiKey::new(&value, KeySource::Env(var))));
+    }
+
+    let path = key_file(provider)?;
+    if path.is_file() {
+        match read_key_file(&path) {
+            Ok(value) if !value.is_empty() => return Ok(Some(ApiKey::new(&value, KeySource::File(path)))),
+            Ok(_) => {}
+            Err(e) => eprintln!("{}", t!("警告: {message}", message = e)),
+        }
+    }
+
+    let path = keys_dir()?.join(ENCRYPTED_FILE);
+    if let Some(passphrase) = passphrase {
+        match read_encrypted(&path, provider, passphrase) {
+            Ok(Some(value)) => return Ok(Some(ApiKey::new(&value, KeySource::Encrypted(path)))),
+            Ok(None) => {}
+            Err(e) => eprintln!("{}", t!("警告: {message}", message = e)),
+        }
+    }
+
+    Ok(None)
+}
+
+/// 查找服务商的密钥，加密存储的口令取自环境变量 VAR_GEN_KEY_PASSPHRASE
+pub fn resolve_for(provider: Provider) -> Result<Option<ApiKey>, Error> {
+    let passphrase = std::env::var(PASSPHRASE_ENV).ok();
+    resolve(provider.name(), passphrase.as_deref())
+}
+
+/// 加密存储中是否保存了服务商的密钥（不需要口令）
+pub fn has_encrypted(provider: &str) -> Result<bool, Error> {
+    Ok(load_vault(&keys_dir()?.join(ENCRYPTED_FILE))?.contains_key(provider))
+}
+
+/// 读取密钥文件，要求只有所有者可读写
+fn read_key_file(path: &Path) -> Result<String, Error> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(path)?.permissions().mode();
+        if mode & 0o077 != 0 {
+            return Err(Error::ConfigError(t!(
+                "密钥文件 {path} 的权限为 {mode}，其他用户可读，已忽略；请执行 chmod 600",
+                path = path.display(),
+                mode = format!("{:o}", mode & 0o777)
+            )));
+        }
+    }
+
+    Ok(std::fs::read_to_string(path)?.trim().to_string())
+}
+
+/// 以 0600 权限写入服务商的密钥文件
+pub fn write_key_file(provider: &str, value: &str) -> Result<PathBuf, Error> {
+    let path = key_file(provider)?;
+    write_private(&path, value.trim().as_bytes())?;
+    Ok(path)
+}
+
+/// 创建只有所有者可读写的文件
+fn write_private(path: &Path, content: &[u8]) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // 文件已存在时 mode 不生效，单独收紧权限
+        if path.exists() {
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    use std::io::Write;
+    options.open(path)?.write_all(content)?;
+    Ok(())
+}
+
+/// 加密存储中的一条密钥
+#[derive(Debug, Serialize, Deserialize)]
+struct Sealed {
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+fn load_vault(path: &Path) -> Result<BTreeMap<String, Sealed>, Error> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(serde_json::from_str(&content)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// 用 Argon2id 从口令和盐派生加密密钥
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Key, Error> {
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| Error::ConfigError(t!("派生加密密钥失败: {error}", error = e)))?;
+    Ok(key)
+}
+
+fn decode(field: &str) -> Result<Vec<u8>, Error> {
+    BASE64
+        .decode(field)
+        .map_err(|e| Error::ConfigError(t!("加密存储格式错误: {error}", error = e)))
+}
+
+fn read_encrypted(path: &Path, provider: &str, passphrase: &str) -> Result<Option<String>, Error> {
+    let vault = load_vault(path)?;
+    let Some(sealed) = vault.get(provider) else {
+        return Ok(None);
+    };
+
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &decode(&sealed.salt)?)?);
+    let nonce = decode(&sealed.nonce)?;
+    if nonce.len() != 12 {
+        return Err(Error::ConfigError(t!("加密存储格式错误: nonce 长度不正确").to_string()));
+    }
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(&nonce), decode(&sealed.ciphertext)?.as_slice())
+        .map_err(|_| Error::ConfigError(t!("无法解密 {provider} 的密钥：口令错误或文件已损坏", provider = provider)))?;
+
+    Ok(Some(String::from_utf8(plaintext)?))
+}
+
+/// 用口令加密保存服务商的密钥
+pub fn write_encrypted(provider: &str, value: &str, passphrase: &str) -> Result<PathBuf, Error> {
+    if passphrase.is_empty() {
+        return Err(Error::ConfigError(t!("口令不能为空").to_string()));
+    }
+
+    let path = keys_dir()?.join(ENCRYPTED_FILE);
+    write_encrypted_at(&path, provider, value, passphrase)?;
+    Ok(path)
+}
+
+fn write_encrypted_at(path: &Path, provider: &str, value: &str, passphrase: &str) -> Result<(), Error> {
+    let mut vault = load_vault(path)?;
+
+    let mut salt = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt)?);
+    let ciphertext = cipher
+        .encrypt(&nonce, value.trim().as_bytes())
+        .map_err(|_| Error::ConfigError(t!("加密密钥失败").to_string()))?;
+
+    vault.insert(
+        provider.to_string(),
+        Sealed {
+            salt: BASE64.encode(salt),
+            nonce: BASE64.encode(nonce),
+            ciphertext: BASE64.encode(ciphertext),
+        },
+    );
+    write_private(path, &serde_json::to_vec_pretty(&vault)?)
+}
+
+/// 删除服务商的密钥文件和加密存储中的条目
+pub fn remove(provider: &str) -> Result<(), Error> {
+    let path = key_file(provider)?;
+    if path.exists() {
+        std::fs::remove_file(&path)?;
+    }
+
+    let path = keys_dir()?.join(ENCRYPTED_FILE);
+    let mut vault = load_vault(&path)?;
+    if vault.remove(provider).is_some() {
+        write_private(&path, &serde_json::to_vec_pretty(&vault)?)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_and_errors_never_show_key() {
+        let key = ApiKey::new("sk-short", KeySource::Inline);
+        let debug = format!("{:?}", key);
+        assert!(!debug.contains("sk-short"));
+        assert!(debug.contains(&key.fingerprint()));
+        assert_eq!(key.redact("Invalid key sk-short"), "Invalid key ***");
+    }
+
+    #[test]
+    fn test_encrypted_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(ENCRYPTED_FILE);
+        write_encrypted_at(&path, "qwen", "sk-123", "secret").unwrap();
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("sk-123"));
+
+        assert_eq!(read_encrypted(&path, "qwen", "secret").unwrap().as_deref(), Some("sk-123"));
+        assert!(read_encrypted(&path, "qwen", "wrong").is_err());
+        assert_eq!(read_encrypted(&path, "xinghuo", "secret").unwrap(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_key_file_must_be_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("qwen.key");
+        write_private(&path, b"sk-123\n").unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        assert_eq!(read_key_file(&path).unwrap(), "sk-123");
+
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        assert!(read_key_file(&path).is_err());
+    }
+}
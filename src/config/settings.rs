@@ -0,0 +1,393 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::mapping::MappingConfig;
+use super::profile::Profile;
+use super::provider::Provider;
+use super::secret;
+use crate::i18n::Locale;
+use crate::naming::NamingStyle;
+use crate::storage::Store;
+use crate::utils::duration::{format_duration, parse_duration};
+use crate::utils::error::Error;
+
+/// 指定用户配置文件路径的环境变量
+pub const CONFIG_ENV: &str = "VAR_GEN_CONFIG";
+
+/// 用户配置文件不存在时 `config edit` 使用的模板
+const TEMPLATE: &str = r#"# var-gen 用户配置，删除某一行即恢复该项的默认值
+# default_style = "snake"
+# provider = "qwen"
+# cache_enabled = true
+# cache_ttl = "1d"
+# mapping_config_path = "/path/to/custom-mapping.json"
+# profile = "commerce"
+# ui_lang = "en-US"
+#
+# [models]
+# qwen = "qwen-max"
+# xinghuo = "generalv3.5"
+"#;
+
+/// 一层配置：用户配置文件、项目配置、环境变量和命令行参数各有一份，按优先级合并
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    #[serde(with = "style_name", skip_serializing_if = "Option::is_none")]
+    pub default_style: Option<NamingStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<Provider>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_enabled: Option<bool>,
+    /// 缓存有效期，可写秒数或 "12h" 这样的时长
+    #[serde(with = "ttl", skip_serializing_if = "Option::is_none")]
+    pub cache_ttl: Option<Duration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mapping_config_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// 界面语言（zh-CN、en-US）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_lang: Option<Locale>,
+    /// 各服务商使用的模型（`[models]` 表）
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub models: BTreeMap<Provider, String>,
+}
+
+impl Settings {
+    /// 读取配置文件，文件不存在时返回 None
+    pub fn load(path: &Path) -> Result<Option<Self>, Error> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        Self::parse(&content).map(Some).map_err(|e| {
+            Error::ConfigError(t!(
+                "配置文件 {path} 无效: {error}\n可执行 `var-gen config edit` 修改，或 `var-gen config unset <配置项>` 恢复默认值",
+                path = path.display(),
+                error = message(e)
+            ))
+        })
+    }
+
+    pub fn parse(content: &str) -> Result<Self, Error> {
+        toml::from_str(content).map_err(|e| Error::ConfigError(e.to_string().trim_end().to_string()))
+    }
+
+    /// 写回配置文件：先写临时文件再改名
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| Error::ConfigError(t!("序列化配置失败: {error}", error = e)))?;
+        write_atomic(path, &format!("{}\n\n{}", t!("# var-gen 用户配置，可直接编辑；`var-gen config list` 列出所有配置项"), content))
+    }
+
+    /// 从 `VAR_GEN_*` 环境变量读取设置，值无效时报错
+    pub fn from_env() -> Result<Self, Error> {
+        fn var<T>(name: &str, parse: impl FnOnce(&str) -> Result<T, Error>) -> Result<Option<T>, Error> {
+            match std::env::var(name).ok().filter(|v| !v.trim().is_empty()) {
+                Some(value) => parse(value.trim())
+                    .map(Some)
+                    .map_err(|e| Error::ConfigError(t!("环境变量 {name} 无效: {error}", name = name, error = message(e)))),
+                None => Ok(None),
+            }
+        }
+        let text = |value: &str| Ok(value.to_string());
+
+        let mut settings = Settings {
+            default_style: var("VAR_GEN_DEFAULT_STYLE", NamingStyle::from_str)?,
+            provider: var("VAR_GEN_PROVIDER", Provider::from_str)?,
+            cache_enabled: var("VAR_GEN_CACHE_ENABLED", parse_bool)?,
+            cache_ttl: var("VAR_GEN_CACHE_TTL", parse_duration)?,
+            mapping_config_path: var("VAR_GEN_MAPPING_CONFIG_PATH", text)?,
+            profile: var("VAR_GEN_PROFILE", text)?,
+            ui_lang: var("VAR_GEN_UI_LANG", Locale::from_str)?,
+            models: BTreeMap::new(),
+        };
+        // 与 --model 一样，能从模型名推断服务商时一并切换
+        if let Some(model) = var("VAR_GEN_MODEL", text)? {
+            let provider = settings.provider.or(Provider::from_model(&model)).unwrap_or(Provider::Qwen);
+            settings.provider.get_or_insert(provider);
+            settings.models.insert(provider, model);
+        }
+        Ok(settings)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Settings::default()
+    }
+
+    /// 检查单个配置项：有效期大于 0，模型名非空，映射文件和配置档可以加载
+    pub fn check(&self, key: &str) -> Result<(), Error> {
+        match key {
+            "cache_ttl" if self.cache_ttl.is_some_and(|ttl| ttl.is_zero()) => {
+                Err(Error::ConfigError(t!("缓存有效期必须大于 0").to_string()))
+            }
+            "models" | "model" if self.models.values().any(|m| m.trim().is_empty()) => {
+                Err(Error::ConfigError(t!("模型名称不能为空").to_string()))
+            }
+            "mapping_config_path" => match &self.mapping_config_path {
+                Some(path) => {
+                    let content = std::fs::read_to_string(path)
+                        .map_err(|e| Error::ConfigError(t!("无法读取词汇映射文件 {path}: {error}", path = path, error = e)))?;
+                    MappingConfig::from_str(&content).map(|_| ())
+                }
+                None => Ok(()),
+            },
+            "profile" => match &self.profile {
+                Some(profile) => Profile::load(profile).map(|_| ()),
+                None => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// 检查所有配置项
+    pub fn validate(&self) -> Result<(), Error> {
+        for key in ["cache_ttl", "models", "mapping_config_path", "profile"] {
+            self.check(key)?;
+        }
+        Ok(())
+    }
+}
+
+/// 用户配置文件路径：环境变量 VAR_GEN_CONFIG，否则为 `<配置目录>/var-gen/config.toml`
+/// （Linux 上遵循 XDG_CONFIG_HOME，默认 `~/.config/var-gen/config.toml`）
+pub fn user_config_path() -> Result<PathBuf, Error> {
+    if let Some(path) = std::env::var_os(CONFIG_ENV).filter(|p| !p.is_empty()) {
+        return Ok(PathBuf::from(path));
+    }
+    let config_dir = dirs::config_dir().ok_or_else(|| Error::ConfigError(t!("无法确定配置目录").to_string()))?;
+    Ok(config_dir.join("var-gen").join("config.toml"))
+}
+
+/// 不打开存储，只从环境变量和用户配置文件中读取一项设置（用于补全和确定界面语言）；
+/// 环境变量或配置文件无效时忽略
+pub fn peek<T>(f: impl Fn(Settings) -> Option<T>) -> Option<T> {
+    let from_file = || Settings::load(&user_config_path().ok()?).ok()?.and_then(&f);
+    Settings::from_env().ok().and_then(&f).or_else(from_file)
+}
+
+/// 用户配置文件的内容；不存在时返回带注释的模板
+pub fn read_or_template(path: &Path) -> Result<String, Error> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(TEMPLATE.to_string()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// 先写临时文件再改名，避免中途退出留下半个文件
+pub fn write_atomic(path: &Path, content: &str) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension("toml.tmp");
+    std::fs::write(&tmp_path, content)?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// 配置文件无法解析时直接删除其中的某一项，使 `config unset` 能修复损坏的配置
+pub fn discard_invalid(path: &Path, key: &str) -> Result<(), Error> {
+    if Settings::load(path).is_ok() {
+        return Ok(());
+    }
+    let mut table: toml::Table = std::fs::read_to_string(path)?
+        .parse()
+        .map_err(|e| Error::ConfigError(t!("配置文件 {path} 不是有效的 TOML: {error}", path = path.display(), error = e)))?;
+    let key = if key == "model" { "models" } else { key };
+    if table.remove(key).is_some() {
+        let content = toml::to_string_pretty(&table)
+            .map_err(|e| Error::ConfigError(t!("序列化配置失败: {error}", error = e)))?;
+        write_atomic(path, &content)?;
+    }
+    Ok(())
+}
+
+/// 旧版本保存在存储后端中的配置项
+const STORED_KEYS: &[&str] = &[
+    "default_style",
+    "provider",
+    "model",
+    "provider.qwen.model",
+    "provider.xinghuo.model",
+    "cache_enabled",
+    "cache_ttl",
+    "mapping_config_path",
+    "profile",
+    "api_key",
+];
+
+/// 一次性迁移：把旧版本保存在存储后端中的配置写入用户配置文件，明文密钥写入密钥文件，
+/// 然后从存储中删除，存储后端只保留历史记录和缓存；无效的值给出警告并跳过
+pub fn migrate(storage: &dyn Store, path: &Path) -> Result<Settings, Error> {
+    let mut stored = BTreeMap::new();
+    for key in STORED_KEYS {
+        if let Some(value) = storage.get_config(key)?.filter(|v| !v.trim().is_empty()) {
+            stored.insert(*key, value.trim().to_string());
+        }
+    }
+    if stored.is_empty() {
+        return Ok(Settings::default());
+    }
+
+    fn valid<T>(key: &str, result: Result<T, Error>) -> Option<T> {
+        result
+            .map_err(|e| eprintln!("{}", t!("警告: 存储中的配置项 {key} 无效，未迁移: {error}", key = key, error = message(e))))
+            .ok()
+    }
+
+    let mut settings = Settings::default();
+    for (key, value) in &stored {
+        match *key {
+            "default_style" => settings.default_style = valid(key, value.parse()),
+            "provider" => settings.provider = valid(key, value.parse()),
+            "cache_enabled" => settings.cache_enabled = valid(key, parse_bool(value)),
+            "cache_ttl" => settings.cache_ttl = valid(key, parse_duration(value)),
+            "mapping_config_path" => settings.mapping_config_path = Some(value.clone()),
+            "profile" => settings.profile = Some(value.clone()),
+            _ => {}
+        }
+    }
+    for provider in Provider::ALL {
+        if let Some(model) = stored.get(format!("provider.{}.model", provider.name()).as_str()) {
+            settings.models.insert(provider, model.clone());
+        }
+    }
+
+    // 旧版本只保存一个模型，由它推断服务商，明文密钥也属于这个服务商
+    let legacy_provider = stored
+        .get("model")
+        .and_then(|model| Provider::from_model(model))
+        .unwrap_or(Provider::Qwen);
+    if let Some(model) = stored.get("model") {
+        settings.models.entry(legacy_provider).or_insert_with(|| model.clone());
+        settings.provider.get_or_insert(legacy_provider);
+    }
+    if let Some(api_key) = stored.get("api_key") {
+        if !secret::key_file(legacy_provider.name())?.exists() {
+            secret::write_key_file(legacy_provider.name(), api_key)?;
+        }
+    }
+
+    if !settings.is_empty() {
+        settings.save(path)?;
+        eprintln!("{}", t!("已将配置从存储迁移到 {path}", path = path.display()));
+    }
+    for key in stored.keys() {
+        storage.save_config(key, "")?;
+    }
+    Ok(settings)
+}
+
+/// 解析布尔值，接受 true/false、yes/no、on/off、1/0
+pub fn parse_bool(value: &str) -> Result<bool, Error> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Ok(true),
+        "false" | "no" | "off" | "0" => Ok(false),
+        _ => Err(Error::ConfigError(t!("无效的布尔值: \"{value}\"（可选 true、false）", value = value.trim()))),
+    }
+}
+
+/// 配置错误的说明文字，去掉 "Config error:" 前缀以便嵌入其他错误信息
+fn message(e: Error) -> String {
+    match e {
+        Error::ConfigError(message) => message,
+        other => other.to_string(),
+    }
+}
+
+/// 命名规范在配置文件中写作 `name()`，如 "upper_snake"
+mod style_name {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(style: &Option<NamingStyle>, serializer: S) -> Result<S::Ok, S::Error> {
+        match style {
+            Some(style) => serializer.serialize_str(style.name()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<NamingStyle>, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map(Some).map_err(|e| serde::de::Error::custom(message(e)))
+    }
+}
+
+/// 有效期写作秒数或时长字符串，保存时统一为时长字符串
+mod ttl {
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Secs(u64),
+        Text(String),
+    }
+
+    pub fn serialize<S: Serializer>(ttl: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+        match ttl {
+            Some(ttl) => serializer.serialize_str(&format_duration(*ttl)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Duration>, D::Error> {
+        match Raw::deserialize(deserializer)? {
+            Raw::Secs(secs) => Ok(Some(Duration::from_secs(secs))),
+            Raw::Text(text) => parse_duration(&text).map(Some).map_err(|e| serde::de::Error::custom(message(e))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStore;
+
+    #[test]
+    fn test_parse_and_save_round_trip() {
+        let settings = Settings::parse("default_style = \"upper_snake\"\ncache_ttl = 3600\n[models]\nqwen = \"qwen-max\"\n").unwrap();
+        assert_eq!(settings.default_style, Some(NamingStyle::UpperSnake));
+        assert_eq!(settings.cache_ttl, Some(Duration::from_secs(3600)));
+        assert_eq!(settings.models.get(&Provider::Qwen).map(String::as_str), Some("qwen-max"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        settings.save(&path).unwrap();
+        assert!(std::fs::read_to_string(&path).unwrap().contains("cache_ttl = \"1h\""));
+        assert_eq!(Settings::load(&path).unwrap(), Some(settings));
+
+        // 拼错的键和无效的值都报错
+        assert!(Settings::parse("default_styel = \"camel\"").is_err());
+        assert!(Settings::parse("default_style = \"foo\"").is_err());
+        assert!(Settings::parse("cache_ttl = \"12x\"").is_err());
+    }
+
+    #[test]
+    fn test_migrate_from_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let storage = MemoryStore::new();
+        storage.save_config("default_style", "camel").unwrap();
+        storage.save_config("model", "generalv3.5").unwrap();
+        storage.save_config("cache_ttl", "7200").unwrap();
+        storage.save_config("cache_enabled", "perhaps").unwrap();
+
+        let settings = migrate(&storage, &path).unwrap();
+        assert_eq!(settings.default_style, Some(NamingStyle::Camel));
+        assert_eq!(settings.provider, Some(Provider::Xinghuo));
+        assert_eq!(settings.models.get(&Provider::Xinghuo).map(String::as_str), Some("generalv3.5"));
+        assert_eq!(settings.cache_ttl, Some(Duration::from_secs(7200)));
+        assert_eq!(settings.cache_enabled, None);
+
+        // 迁移后存储中不再保存配置，再次迁移不会覆盖配置文件
+        assert_eq!(storage.get_config("default_style").unwrap().filter(|v| !v.is_empty()), None);
+        assert_eq!(Settings::load(&path).unwrap(), Some(settings));
+        assert!(migrate(&storage, &path).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, MAIN_SEPARATOR};
+use std::time::UNIX_EPOCH;
+
+use crate::naming::{IdentifierKind, Language, NamingStyle};
+use crate::generator::rule::casing::{split_identifier, Casing};
+use crate::lint::scanner::{language_for_path, Scanner};
+use crate::lint::source_files;
+use crate::storage::{FileIndex, IndexedIdentifier, Store};
+use crate::utils::error::Error;
+
+/// 消歧时尝试添加的前缀词
+const QUALIFIERS: &[&str] = &["new", "current", "local", "other"];
+
+/// 最多提供的替代名称数
+const MAX_ALTERNATIVES: usize = 3;
+
+/// 标识符在项目中的声明位置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// 相对于项目目录的路径
+    pub path: String,
+    pub line: usize,
+    pub kind: IdentifierKind,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.path, self.line)
+    }
+}
+
+/// 项目中已声明的标识符索引
+#[derive(Debug, Clone, Default)]
+pub struct ProjectIndex {
+    identifiers: HashMap<String, Vec<Location>>,
+}
+
+impl ProjectIndex {
+    /// 扫描项目目录；按文件修改时间复用存储中缓存的索引，只重新扫描有变化的文件
+    pub fn build(root: &Path, storage: &dyn Store) -> Result<Self, Error> {
+        let root = fs::canonicalize(root)?;
+        if !root.is_dir() {
+            return Err(Error::ConfigError(t!("{path} 不是目录", path = root.display())));
+        }
+
+        let mut scanners: HashMap<Language, Scanner> = HashMap::new();
+        let mut index = Self::default();
+        let mut indexed_paths = Vec::new();
+
+        for file in source_files(&root, &[])? {
+            let Some(language) = language_for_path(&file) else {
+                continue;
+            };
+            let key = file.to_string_lossy().to_string();
+            let mtime = modified_millis(&file)?;
+
+            let file_index = match storage.get_file_index(&key)? {
+                Some(cached) if cached.mtime == mtime => cached,
+                _ => {
+                    // 跳过非 UTF-8 文件
+                    let Ok(source) = String::from_utf8(fs::read(&file)?) else {
+                        continue;
+                    };
+                    let scanner = match scanners.entry(language) {
+                        std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                        std::collections::hash_map::Entry::Vacant(entry) => entry.insert(Scanner::new(language)?),
+                    };
+                    let file_index = FileIndex {
+                        mtime,
+                        identifiers: scanner
+                            .scan(&source)
+                            .into_iter()
+                            .map(|d| IndexedIdentifier { name: d.name, kind: d.kind, line: d.line })
+                            .collect(),
+                    };
+                    storage.save_file_index(&key, &file_index)?;
+                    file_index
+                }
+            };
+
+            let relative = relative_path(&root, &file);
+            for identifier in file_index.identifiers {
+                index.identifiers.entry(identifier.name).or_default().push(Location {
+                    path: relative.clone(),
+                    line: identifier.line,
+                    kind: identifier.kind,
+                });
+            }
+            indexed_paths.push(key);
+        }
+
+        // 清理已删除文件的缓存
+        let prefix = format!("{}{}", root.to_string_lossy().trim_end_matches(MAIN_SEPARATOR), MAIN_SEPARATOR);
+        storage.prune_file_index(&prefix, &indexed_paths)?;
+
+        Ok(index)
+    }
+
+    /// 已存在的候选排到后面，保持其余顺序不变
+    pub fn demote_existing(&self, names: Vec<String>) -> Vec<String> {
+        let (existing, fresh): (Vec<String>, Vec<String>) =
+            names.into_iter().partition(|name| self.contains(name));
+        fresh.into_iter().chain(existing).collect()
+    }
+
+    /// 名称在项目中的声明位置
+    pub fn locations(&self, name: &str) -> &[Location] {
+        self.identifiers.get(name).map_or(&[], |l| l.as_slice())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.identifiers.contains_key(name)
+    }
+
+    /// 为重名的候选生成不冲突的替代名称（加 new、current 等限定前缀）
+    pub fn alternatives(&self, name: &str, style: NamingStyle, casing: &Casing) -> Vec<String> {
+        let words = split_identifier(name);
+        if words.is_empty() {
+            return Vec::new();
+        }
+
+        let mut alternatives = Vec::new();
+        for qualifier in QUALIFIERS {
+            if words.first().is_some_and(|w| w == qualifier) {
+                continue;
+            }
+            let qualified: Vec<String> = std::iter::once(qualifier.to_string())
+                .chain(words.iter().cloned())
+                .collect();
+            let candidate = casing.convert(&qualified, style);
+            if !self.contains(&candidate) && !alternatives.contains(&candidate) {
+                alternatives.push(candidate);
+            }
+            if alternatives.len() == MAX_ALTERNATIVES {
+                break;
+            }
+        }
+
+        alternatives
+    }
+}
+
+/// 文件修改时间（毫秒时间戳）
+fn modified_millis(path: &Path) -> Result<u64, Error> {
+    let modified = fs::metadata(path)?.modified()?;
+    Ok(modified
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default())
+}
+
+fn relative_path(root: &Path, file: &Path) -> String {
+    file.strip_prefix(root)
+        .unwrap_or(file)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::rule::casing::AcronymPolicy;
+    use crate::storage::mock_storage;
+
+    #[test]
+    fn test_index_and_alternatives() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("user.rs");
+        fs::write(&file, "fn load_user() {}\nlet new_user_name = 1;\n").unwrap();
+        let storage = mock_storage().unwrap();
+
+        let index = ProjectIndex::build(dir.path(), &storage).unwrap();
+        assert_eq!(index.locations("load_user")[0].to_string(), "user.rs:1");
+        assert!(!index.contains("user_name"));
+
+        let casing = Casing::new(AcronymPolicy::Capitalize);
+        assert_eq!(
+            index.alternatives("user_name", NamingStyle::Snake, &casing),
+            vec!["current_user_name", "local_user_name", "other_user_name"]
+        );
+
+        // 文件未变化时使用缓存，删除后清理缓存
+        let key = fs::canonicalize(&file).unwrap().to_string_lossy().to_string();
+        assert!(storage.get_file_index(&key).unwrap().is_some());
+        fs::remove_file(&file).unwrap();
+        let index = ProjectIndex::build(dir.path(), &storage).unwrap();
+        assert!(!index.contains("load_user"));
+        assert!(storage.get_file_index(&key).unwrap().is_none());
+    }
+}
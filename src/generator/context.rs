@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use crate::naming::{IdentifierKind, Language, NamingStyle};
+use crate::generator::rule::casing::detect_style;
+use crate::lint::scanner::{language_for_path, Declaration, Scanner};
+use crate::utils::error::Error;
+
+/// 嵌入提示词的代码片段的 token 预算
+pub const CONTEXT_TOKEN_BUDGET: usize = 1500;
+
+/// 只指定一行时，向前后各取的行数
+const LINE_WINDOW: usize = 20;
+
+/// 从 0 开始的起始行和不含在内的结束行（None 表示到文件末尾）
+type LineRange = (usize, Option<usize>);
+
+/// 命名时参考的代码上下文
+#[derive(Debug, Clone, Default)]
+pub struct CodeContext {
+    /// 截断后的代码片段
+    pub snippet: String,
+    /// 代码语言（--lang 优先，未指定时由文件扩展名决定）
+    pub language: Option<Language>,
+    /// 片段中已声明的标识符
+    pub declarations: Vec<Declaration>,
+}
+
+impl CodeContext {
+    /// 按 `path[:line-range]` 加载上下文，`-` 表示从标准输入读取
+    ///
+    /// 行范围支持 `10-40`、`10-`（到文件末尾）和 `25`（前后各取 20 行）
+    pub fn load(spec: &str, language: Option<Language>) -> Result<Self, Error> {
+        let (path, range) = parse_spec(spec)?;
+
+        let source = if path == "-" {
+            let mut source = String::new();
+            std::io::stdin().read_to_string(&mut source)?;
+            source
+        } else {
+            fs::read_to_string(path)
+                .map_err(|e| Error::ConfigError(t!("读取上下文文件失败 {path}: {error}", path = path, error = e)))?
+        };
+
+        let lines: Vec<&str> = source.lines().collect();
+        let (start, end) = match range {
+            Some((start, end)) => (start.min(lines.len()), end.unwrap_or(lines.len()).min(lines.len())),
+            None => (0, lines.len()),
+        };
+        let snippet = lines[start..end.max(start)].join("\n");
+
+        let language = language.or_else(|| language_for_path(Path::new(path)));
+        Self::from_snippet(&snippet, language)
+    }
+
+    /// 从代码文本构建上下文：提取已声明的名称，并按 token 预算截断
+    pub fn from_snippet(snippet: &str, language: Option<Language>) -> Result<Self, Error> {
+        let declarations = match language {
+            Some(language) => Scanner::new(language)?.scan(snippet),
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            snippet: truncate_to_budget(snippet, CONTEXT_TOKEN_BUDGET),
+            language,
+            declarations,
+        })
+    }
+
+    /// 片段中已使用的名称（去重，保持出现顺序）
+    pub fn used_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for declaration in &self.declarations {
+            if !names.contains(&declaration.name.as_str()) {
+                names.push(&declaration.name);
+            }
+        }
+        names
+    }
+
+    /// 名称是否已在片段中声明
+    pub fn is_used(&self, name: &str) -> bool {
+        self.declarations.iter().any(|d| d.name == name)
+    }
+
+    /// 片段中同类标识符最常用的命名规范；没有同类声明时参考全部声明
+    pub fn dominant_style(&self, kind: Option<IdentifierKind>) -> Option<NamingStyle> {
+        let same_kind: Vec<&Declaration> = self
+            .declarations
+            .iter()
+            .filter(|d| kind.is_none_or(|k| d.kind == k))
+            .collect();
+        let pool = if same_kind.is_empty() {
+            self.declarations.iter().collect()
+        } else {
+            same_kind
+        };
+
+        let mut counts: BTreeMap<NamingStyle, usize> = BTreeMap::new();
+        for declaration in pool {
+            if let Some(style) = detect_style(declaration.name.trim_matches('_')) {
+                *counts.entry(style).or_default() += 1;
+            }
+        }
+        // 次数相同时取排序靠前的规范，保证结果稳定
+        counts
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+            .map(|(style, _)| style)
+    }
+}
+
+/// 解析 `path[:line-range]`，返回路径和从 0 开始的行范围
+fn parse_spec(spec: &str) -> Result<(&str, Option<LineRange>), Error> {
+    let Some((path, range)) = spec.rsplit_once(':') else {
+        return Ok((spec, None));
+    };
+    // Windows 盘符（C:\...）或不是行范围时，整体视为路径
+    if path.is_empty() || range.is_empty() || !range.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return Ok((spec, None));
+    }
+
+    let invalid = || Error::ConfigError(t!("无效的行范围: {range}", range = range));
+    let parse = |s: &str| s.parse::<usize>().ok().filter(|&n| n > 0).ok_or_else(invalid);
+
+    let range = match range.split_once('-') {
+        Some((start, "")) => (parse(start)? - 1, None),
+        Some((start, end)) => {
+            let (start, end) = (parse(start)?, parse(end)?);
+            if end < start {
+                return Err(invalid());
+            }
+            (start - 1, Some(end))
+        }
+        None => {
+            let line = parse(range)?;
+            (line.saturating_sub(LINE_WINDOW + 1), Some(line + LINE_WINDOW))
+        }
+    };
+
+    Ok((path, Some(range)))
+}
+
+/// 粗略估算 token 数：中日韩字符各算一个，其余字符按 4 个一个
+pub fn estimate_tokens(text: &str) -> usize {
+    let wide = text.chars().filter(|c| !c.is_ascii()).count();
+    let narrow = text.chars().filter(|c| c.is_ascii()).count();
+    wide + narrow.div_ceil(4)
+}
+
+/// 超出预算时保留末尾的行（通常离待命名的位置最近），并注明省略的行数
+fn truncate_to_budget(snippet: &str, budget: usize) -> String {
+    if estimate_tokens(snippet) <= budget {
+        return snippet.to_string();
+    }
+
+    let lines: Vec<&str> = snippet.lines().collect();
+    let mut used = 0;
+    let mut keep = 0;
+    for line in lines.iter().rev() {
+        let cost = estimate_tokens(line) + 1;
+        if used + cost > budget {
+            break;
+        }
+        used += cost;
+        keep += 1;
+    }
+
+    // 最后一行本身就超出预算时，按字符保留该行的末尾
+    if keep == 0 {
+        let last = lines.last().copied().unwrap_or_default();
+        let mut start = last.len();
+        for (i, _) in last.char_indices().rev() {
+            if estimate_tokens(&last[i..]) > budget {
+                break;
+            }
+            start = i;
+        }
+        return format!("...（省略前 {} 行）\n...{}", lines.len() - 1, &last[start..]);
+    }
+
+    let omitted = lines.len() - keep;
+    format!("...（省略前 {} 行）\n{}", omitted, lines[omitted..].join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_spec() {
+        assert_eq!(parse_spec("src/main.rs").unwrap(), ("src/main.rs", None));
+        assert_eq!(parse_spec("src/main.rs:10-40").unwrap(), ("src/main.rs", Some((9, Some(40)))));
+        assert_eq!(parse_spec("src/main.rs:10-").unwrap(), ("src/main.rs", Some((9, None))));
+        assert_eq!(parse_spec("src/main.rs:5").unwrap(), ("src/main.rs", Some((0, Some(25)))));
+        assert_eq!(parse_spec(r"C:\code\main.rs").unwrap(), (r"C:\code\main.rs", None));
+        assert!(parse_spec("src/main.rs:40-10").is_err());
+    }
+
+    #[test]
+    fn test_context_names_and_style() {
+        let snippet = "fn load(path: &str) {\n    let userName = read(path);\n    let orderCount = 0;\n    let total = 1;\n}\n";
+        let context = CodeContext::from_snippet(snippet, Some(Language::Rust)).unwrap();
+        assert_eq!(context.used_names(), vec!["load", "userName", "orderCount", "total"]);
+        assert!(context.is_used("total"));
+        assert_eq!(context.dominant_style(Some(IdentifierKind::Variable)), Some(NamingStyle::Camel));
+    }
+
+    #[test]
+    fn test_explicit_language_overrides_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("order.js");
+        fs::write(&path, "const itemCount = 0;\n").unwrap();
+        let spec = path.to_str().unwrap();
+        assert_eq!(CodeContext::load(spec, Some(Language::TypeScript)).unwrap().language, Some(Language::TypeScript));
+        assert_eq!(CodeContext::load(spec, None).unwrap().language, Some(Language::JavaScript));
+    }
+
+    #[test]
+    fn test_truncate_keeps_tail() {
+        let snippet: Vec<String> = (1..=100).map(|i| format!("let value_{} = {};", i, i)).collect();
+        let truncated = truncate_to_budget(&snippet.join("\n"), 50);
+        assert!(truncated.starts_with("...（省略前"));
+        assert!(truncated.ends_with("let value_100 = 100;"));
+        assert!(estimate_tokens(&truncated) <= 60);
+    }
+
+    #[test]
+    fn test_truncate_keeps_part_of_oversized_last_line() {
+        let last = format!("let total = {}value;", "value + ".repeat(100));
+        let truncated = truncate_to_budget(&format!("let a = 1;\n{}", last), 20);
+        assert!(truncated.starts_with("...（省略前 1 行）\n..."));
+        assert!(truncated.ends_with("value;"));
+        assert!(truncated.lines().nth(1).is_some_and(|line| estimate_tokens(line) <= 21));
+    }
+}
@@ -5,51 +5,73 @@ use std::time::Duration;
 use reqwest::Client;
 
 
+use crate::config::profile::Profile;
+use crate::config::provider::Provider;
+use crate::config::secret::ApiKey;
 use crate::config::Config;
+use crate::generator::rule::abbreviation::AbbreviationPreference;
+use crate::generator::rule::casing::AcronymPolicy;
+use crate::generator::context::CodeContext;
+use crate::generator::GenerateOptions;
 use crate::utils::error::Error;
 
-use crate::cli::NamingStyle;
+use crate::naming::NamingStyle;
 
 #[derive(Debug, Clone)]
 pub struct LLMGenerator {
     client: Client,
-    api_key: Option<String>,
+    api_key: Option<ApiKey>,
+    provider: Provider,
     model: String,
+    profile: Option<Arc<Profile>>,
     cache: Arc<RwLock<HashMap<String, Vec<String>>>>,
 }
 
 impl LLMGenerator {
-    pub fn new(config: Arc<Config>) -> Result<Self, Error> {
-        let api_key = config.api_key().map(|s| s.to_string());
+    pub fn new(config: Arc<Config>, profile: Option<Arc<Profile>>) -> Result<Self, Error> {
+        let api_key = config.api_key_info().cloned();
         
         // 如果没有配置 API 密钥，返回错误
         if api_key.is_none() {
-            return Err(Error::LLMError("API key not configured".to_string()));
+            return Err(Error::LLMError(t!("未配置 API 密钥").to_string()));
         }
         
         let client = Client::builder()
             .timeout(Duration::from_secs(30))
             .build()?;
         
+        let provider = config.provider();
         let model = config.model().to_string();
         
         Ok(Self {
             client,
             api_key,
+            provider,
             model,
+            profile,
             cache: Arc::new(RwLock::new(HashMap::new())),
         })
     }
     
-    pub async fn generate(&self, description: &str, style: NamingStyle) -> Result<Vec<String>, Error> {
+    pub async fn generate(
+        &self,
+        description: &str,
+        context: Option<&CodeContext>,
+        options: &GenerateOptions,
+    ) -> Result<Vec<String>, Error> {
         // 检查缓存
-        let cache_key = format!("{}:{}", description, style);
+        let cache_key = format!(
+            "{}:{:?}:{}",
+            description,
+            options,
+            context.map_or("", |c| c.snippet.as_str())
+        );
         if let Some(result) = self.cache.read().unwrap().get(&cache_key) {
             return Ok(result.clone());
         }
         
         // 构建提示词
-        let prompt: String = self.build_prompt(description, style);
+        let prompt: String = self.build_prompt(description, context, options);
         
         // 调用大模型 API
         let response: String = self.call_api(&prompt).await?;
@@ -63,132 +85,189 @@ impl LLMGenerator {
         Ok(variable_names)
     }
     
-    fn build_prompt(&self, description: &str, style: NamingStyle) -> String {
-        let style_desc = match style {
-            NamingStyle::Camel => "camelCase (e.g., userName)",
-            NamingStyle::Pascal => "PascalCase (e.g., UserName)",
-            NamingStyle::Snake => "snake_case (e.g., user_name)",
-            NamingStyle::Kebab => "kebab-case (e.g., user-name)",
-            NamingStyle::UpperSnake => "UPPER_SNAKE_CASE (e.g., USER_NAME)",
-            NamingStyle::LowerCamel => "lowerCamelCase (e.g., userName)",
-        };
-        
-        format!(
+    /// 为已有标识符推荐更好的名称，`context` 为周围的代码或注释
+    pub async fn suggest_rename(
+        &self,
+        identifier: &str,
+        context: Option<&str>,
+        options: &GenerateOptions,
+    ) -> Result<Vec<String>, Error> {
+        let cache_key = format!("rename:{}:{:?}:{:?}", identifier, context, options);
+        if let Some(result) = self.cache.read().unwrap().get(&cache_key) {
+            return Ok(result.clone());
+        }
+        
+        let prompt = self.build_rename_prompt(identifier, context, options);
+        let response = self.call_api(&prompt).await?;
+        
+        // 模型可能逐行列出候选
+        let candidates: Vec<String> = response
+            .split([',', '，', '\n'])
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        if candidates.is_empty() {
+            return Err(Error::LLMError(t!("响应中没有有效的变量名").to_string()));
+        }
+        
+        self.cache.write().unwrap().insert(cache_key, candidates.clone());
+        
+        Ok(candidates)
+    }
+    
+    fn build_prompt(&self, description: &str, context: Option<&CodeContext>, options: &GenerateOptions) -> String {
+        let mut prompt = format!(
             "请根据以下描述生成符合{}格式的变量名，仅输出变量名，多个候选用逗号分隔，尽量用英文变量名，无需额外解释：\n{}",
-            style_desc,
+            style_description(options.style),
             description
-        )
+        );
+        
+        // 代码上下文：名称要与已有代码保持一致，且不能遮蔽已有变量
+        if let Some(context) = context.filter(|c| !c.snippet.trim().is_empty()) {
+            prompt.push_str(&format!("\n变量将用于以下代码中：\n```\n{}\n```", context.snippet));
+            let used_names = context.used_names();
+            if !used_names.is_empty() {
+                prompt.push_str(&format!(
+                    "\n代码中已使用的名称：{}。新名称的用词和风格应与之保持一致，且不能与这些名称重复",
+                    used_names.join("、")
+                ));
+            }
+        }
+        
+        self.append_constraints(&mut prompt, options);
+        prompt
     }
     
-    async fn call_api(&self, prompt: &str) -> Result<String, Error> {
-        // 根据模型选择不同的 API
-        match self.model.as_str() {
-            "qwen-tiny" => self.call_qwen_api(prompt).await,
-            "xinghuo-lite" => self.call_xinghuo_api(prompt).await,
-            _ => Err(Error::LLMError(format!("Unsupported model: {}", self.model))),
+    fn build_rename_prompt(&self, identifier: &str, context: Option<&str>, options: &GenerateOptions) -> String {
+        let mut prompt = format!(
+            "以下标识符命名不佳（可能是拼音、拼音首字母缩写、无意义的缩写或过于笼统的名称，如 yhxx 表示“用户信息”），\
+            请根据其含义和上下文推荐3个更清晰的英文名称，格式为{}，仅输出名称，多个候选用逗号分隔，无需额外解释：\n原名称：{}",
+            style_description(options.style),
+            identifier
+        );
+        if let Some(context) = context.map(str::trim).filter(|c| !c.is_empty()) {
+            prompt.push_str(&format!("\n上下文：\n{}", context));
         }
+        self.append_constraints(&mut prompt, options);
+        prompt
     }
     
-    async fn call_qwen_api(&self, prompt: &str) -> Result<String, Error> {
-        let api_key = self.api_key.as_ref().ok_or_else(|| Error::LLMError("API key not configured".to_string()))?;
-        
-        // 使用兼容模式的API端点
-        let url = "https://dashscope.aliyuncs.com/compatible-mode/v1/chat/completions";
+    /// 标识符类型、语言、长度、缩写和配置档等约束
+    fn append_constraints(&self, prompt: &mut String, options: &GenerateOptions) {
+        // 标识符类型与目标语言
+        if let Some(kind) = options.kind {
+            prompt.push_str(&format!("\n标识符类型：{}", kind));
+        }
+        if let Some(language) = options.language {
+            prompt.push_str(&format!("\n目标语言：{}", language));
+        }
         
-        let request_body = serde_json::json!({
-            "model": "qwen-plus",
-            "messages": [
-                {
-                    "role": "system",
-                    "content": "You are a helpful assistant."
-                },
-                {
-                    "role": "user",
-                    "content": prompt
-                }
-            ],
-            "temperature": 0.1,
-            "max_tokens": 200
-        });
-
-        eprintln!("🔍 调用通义千问API:");
-        eprintln!("   模型: qwen-plus");
+        // 长度与缩写偏好
+        let max_length = options
+            .max_length
+            .or_else(|| self.profile.as_ref().and_then(|p| p.max_length));
+        if let Some(max_length) = max_length {
+            prompt.push_str(&format!("\n变量名长度尽量不超过{}个字符", max_length));
+        }
+        let preference = options
+            .abbreviation
+            .or_else(|| self.profile.as_ref().and_then(|p| p.abbreviation_preference));
+        match preference {
+            Some(AbbreviationPreference::Full) => prompt.push_str("\n使用完整单词，不要缩写（id、info、max、min 等通用缩写可以保留）"),
+            Some(AbbreviationPreference::Abbreviated) => {
+                prompt.push_str("\n尽量使用常见缩写（如 cfg、info、num、db）")
+            }
+            _ => {}
+        }
         
-        let response = self.client
-            .post(url)
-            .header("Authorization", format!("Bearer {}", api_key))
-            .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
-            .await?;
+        // 缩略词大小写：显式指定或按目标语言的风格指南
+        let acronym_policy = options
+            .acronyms
+            .or_else(|| self.profile.as_ref().and_then(|p| p.acronym_policy))
+            .or_else(|| options.language.map(|l| AcronymPolicy::for_language(Some(l))));
+        match acronym_policy {
+            Some(AcronymPolicy::Preserve) => {
+                prompt.push_str("\n缩略词保持全大写（如 userID、HTTPClient）")
+            }
+            Some(AcronymPolicy::Capitalize) => {
+                prompt.push_str("\n缩略词按普通单词首字母大写（如 userId、HttpClient）")
+            }
+            None => {}
+        }
         
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-            eprintln!("❌ 通义千问API请求失败详细信息:");
-            eprintln!("   状态码: {}", status);
-            eprintln!("   错误响应: {}", error_text);
+        // 领域配置档提示
+        if let Some(profile) = &self.profile {
+            for hint in &profile.prompt_hints {
+                prompt.push_str(&format!("\n领域提示：{}", hint));
+            }
             
-            return Err(Error::LLMError(format!(
-                "Qwen API request failed with status: {}. Error: {}",
-                status, error_text
-            )));
+            if !profile.mappings.is_empty() {
+                let mut vocabulary: Vec<String> = profile
+                    .mappings
+                    .iter()
+                    .map(|(chinese, english)| format!("{}={}", chinese, english))
+                    .collect();
+                vocabulary.sort();
+                prompt.push_str(&format!("\n优先使用以下词汇译法：{}", vocabulary.join("，")));
+            }
+            
+            if !profile.abbreviations.is_empty() {
+                let mut abbreviations: Vec<String> = profile
+                    .abbreviations
+                    .iter()
+                    .map(|(full, short)| format!("{}->{}", full, short))
+                    .collect();
+                abbreviations.sort();
+                prompt.push_str(&format!("\n优先使用以下缩写：{}", abbreviations.join("，")));
+            }
         }
-        
-        let response_json: serde_json::Value = response.json().await?;
-        
-        // 使用兼容模式的响应格式
-        let text = response_json["choices"][0]["message"]["content"]
-            .as_str()
-            .ok_or_else(|| Error::LLMError("Invalid response format from Qwen API".to_string()))?;
-        
-        Ok(text.to_string())
     }
     
-    async fn call_xinghuo_api(&self, prompt: &str) -> Result<String, Error> {
-        let api_key = self.api_key.as_ref().ok_or_else(|| Error::LLMError("API key not configured".to_string()))?;
+    async fn call_api(&self, prompt: &str) -> Result<String, Error> {
+        let api_key = self.api_key.as_ref().ok_or_else(|| Error::LLMError(t!("未配置 API 密钥").to_string()))?;
+        let model = self.provider.api_model(&self.model);
         
-        let url = "https://spark-api-open.xf-yun.com/v1/chat/completions";
+        let mut messages = Vec::new();
+        if self.provider == Provider::Qwen {
+            messages.push(serde_json::json!({"role": "system", "content": "You are a helpful assistant."}));
+        }
+        messages.push(serde_json::json!({"role": "user", "content": prompt}));
         
         let request_body = serde_json::json!({
-            "model": "spark-lite",
-            "messages": [
-                {
-                    "role": "user",
-                    "content": prompt
-                }
-            ],
+            "model": model,
+            "messages": messages,
             "temperature": 0.1,
             "max_tokens": 200
         });
+
+        eprintln!("🔍 {}", t!("调用{provider}API:", provider = self.provider.display_name()));
+        eprintln!("   {}", t!("模型: {model}", model = model));
         
-        let response = self.client
-            .post(url)
-            .header("Authorization", format!("Bearer {}", api_key))
-            .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
-            .await?;
+        let response = post_chat(&self.client, self.provider, api_key, &request_body).await?;
         
         if !response.status().is_success() {
             let status = response.status();
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-            eprintln!("❌ 星火API请求失败详细信息:");
-            eprintln!("   状态码: {}", status);
-            eprintln!("   错误响应: {}", error_text);
-            eprintln!("   使用的API密钥: {}", &api_key[..10]); // 只显示前10位
+            // 部分服务端会在错误信息中回显密钥
+            let error_text = api_key.redact(&response.text().await.unwrap_or_else(|_| t!("未知错误").to_string()));
+            eprintln!("❌ {}", t!("{provider}API请求失败详细信息:", provider = self.provider.display_name()));
+            eprintln!("   {}", t!("状态码: {status}", status = status));
+            eprintln!("   {}", t!("错误响应: {error}", error = error_text));
+            eprintln!("   {}", t!("使用的{credential}: {key}", credential = self.provider.credential_name(), key = api_key));
             
-            return Err(Error::LLMError(format!(
-                "Xinghuo API request failed with status: {}. Error: {}",
-                status, error_text
+            return Err(Error::LLMError(t!(
+                "{provider} 接口请求失败，状态码: {status}，错误: {error}",
+                provider = self.provider,
+                status = status,
+                error = error_text
             )));
         }
         
         let response_json: serde_json::Value = response.json().await?;
         
+        // OpenAI 兼容模式的响应格式
         let text = response_json["choices"][0]["message"]["content"]
             .as_str()
-            .ok_or_else(|| Error::LLMError("Invalid response format".to_string()))?;
+            .ok_or_else(|| Error::LLMError(t!("{provider} 接口返回的格式无效", provider = self.provider)))?;
         
         Ok(text.to_string())
     }
@@ -205,18 +284,93 @@ impl LLMGenerator {
             .collect();
         
         if variable_names.is_empty() {
-            return Err(Error::LLMError("No valid variable names found in response".to_string()));
+            return Err(Error::LLMError(t!("响应中没有有效的变量名").to_string()));
         }
         
         Ok(variable_names)
     }
 }
 
+/// 提示词中的命名规范说明
+fn style_description(style: NamingStyle) -> &'static str {
+    match style {
+        NamingStyle::Camel => "camelCase (e.g., userName)",
+        NamingStyle::Pascal => "PascalCase (e.g., UserName)",
+        NamingStyle::Snake => "snake_case (e.g., user_name)",
+        NamingStyle::Kebab => "kebab-case (e.g., user-name)",
+        NamingStyle::UpperSnake => "UPPER_SNAKE_CASE (e.g., USER_NAME)",
+        NamingStyle::LowerCamel => "lowerCamelCase (e.g., userName)",
+        NamingStyle::Dot => "dot.case, lowercase words joined by dots, as used for config keys (e.g., user.name)",
+        NamingStyle::Train => "Train-Case, capitalized words joined by hyphens, as used for HTTP headers (e.g., User-Name)",
+        NamingStyle::Flat => "flatcase, all lowercase with no separators (e.g., username)",
+        NamingStyle::ScreamingKebab => "SCREAMING-KEBAB-CASE, also known as COBOL-CASE (e.g., USER-NAME)",
+        NamingStyle::Ada => "Ada_Case, capitalized words joined by underscores (e.g., User_Name)",
+        NamingStyle::Path => "path/case, lowercase words joined by slashes (e.g., user/name)",
+        NamingStyle::Namespace => "namespace::case, lowercase words joined by double colons (e.g., user::name)",
+    }
+}
+
+/// 向服务商的对话接口发送请求
+async fn post_chat(
+    client: &Client,
+    provider: Provider,
+    api_key: &ApiKey,
+    body: &serde_json::Value,
+) -> Result<reqwest::Response, reqwest::Error> {
+    // 星火的 APIPassword 和 APIKey:APISecret 都以 Bearer 方式传递
+    client
+        .post(provider.endpoint())
+        .header("Authorization", format!("Bearer {}", api_key.expose()))
+        .header("Content-Type", "application/json")
+        .json(body)
+        .send()
+        .await
+}
+
+/// `provider test` 的检查结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProviderStatus {
+    /// 接口可用，附带响应耗时
+    Ok(Duration),
+    /// 凭证无效或无权限（HTTP 401/403）
+    Unauthorized(u16),
+    /// 网络错误或其他接口错误
+    Failed(String),
+}
+
+/// 用最小的请求检查服务商的接口和凭证是否可用
+pub async fn test_provider(provider: Provider, model: &str, api_key: &ApiKey) -> ProviderStatus {
+    let client = match Client::builder().timeout(Duration::from_secs(15)).build() {
+        Ok(client) => client,
+        Err(e) => return ProviderStatus::Failed(e.to_string()),
+    };
+    let request_body = serde_json::json!({
+        "model": provider.api_model(model),
+        "messages": [{"role": "user", "content": "ping"}],
+        "max_tokens": 1
+    });
+    
+    let started = std::time::Instant::now();
+    match post_chat(&client, provider, api_key, &request_body).await {
+        Ok(response) if response.status().is_success() => ProviderStatus::Ok(started.elapsed()),
+        Ok(response) if matches!(response.status().as_u16(), 401 | 403) => {
+            ProviderStatus::Unauthorized(response.status().as_u16())
+        }
+        Ok(response) => {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            let text: String = api_key.redact(&text).chars().take(200).collect();
+            ProviderStatus::Failed(format!("HTTP {}: {}", status, text))
+        }
+        Err(e) => ProviderStatus::Failed(e.to_string()),
+    }
+}
+
 // 用于测试的辅助函数
 #[cfg(test)]
 #[allow(dead_code)]
 pub fn mock_llm_generator() -> LLMGenerator {
     let config = Arc::new(crate::config::mock_config_with_api_key("mock-api-key"));
     
-    LLMGenerator::new(config).unwrap()
+    LLMGenerator::new(config, None).unwrap()
 }
\ No newline at end of file
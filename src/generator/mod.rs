@@ -1,79 +1,175 @@
+use serde::Serialize;
 use std::sync::Arc;
 
+use crate::config::profile::Profile;
 use crate::config::Config;
-use crate::storage::Storage;
 use crate::utils::error::Error;
 
+pub mod collision;
+pub mod context;
+#[cfg(feature = "llm")]
 pub mod llm;
 pub mod rule;
 
+use context::CodeContext;
+#[cfg(feature = "llm")]
 use llm::LLMGenerator;
+use rule::abbreviation::AbbreviationPreference;
+use rule::casing::{split_identifier, AcronymPolicy};
 use rule::RuleGenerator;
-use crate::cli::NamingStyle;
+use crate::naming::{IdentifierKind, Language, NamingStyle};
 
 
+/// 一条描述的生成结果（命令行 `--json` 输出和 HTTP 接口共用）
+#[derive(Debug, Clone, Serialize)]
+pub struct Generation {
+    pub description: String,
+    pub style: &'static str,
+    pub names: Vec<String>,
+}
+
+/// 单次生成的命名参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenerateOptions {
+    pub style: NamingStyle,
+    pub kind: Option<IdentifierKind>,
+    pub language: Option<Language>,
+    pub max_length: Option<usize>,
+    pub abbreviation: Option<AbbreviationPreference>,
+    pub acronyms: Option<AcronymPolicy>,
+}
+
+impl GenerateOptions {
+    /// 用配置档的默认值补全未指定的参数，命名规范最后按语言惯例确定
+    pub fn resolve(
+        style: Option<NamingStyle>,
+        kind: Option<IdentifierKind>,
+        language: Option<Language>,
+        profile: Option<&Profile>,
+    ) -> Self {
+        let kind = kind.or_else(|| profile.and_then(|p| p.default_kind));
+        let language = language.or_else(|| profile.and_then(|p| p.default_language));
+        Self {
+            style: style
+                .or_else(|| profile.and_then(|p| p.default_style))
+                .or_else(|| language.map(|l| l.default_style(kind)))
+                .unwrap_or(NamingStyle::Snake),
+            kind,
+            language,
+            max_length: None,
+            abbreviation: None,
+            acronyms: None,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Generator {
+    #[cfg(feature = "llm")]
     pub llm_generator: Option<LLMGenerator>,
     pub rule_generator: RuleGenerator,
-    pub storage: Arc<Storage>,
     pub config: Arc<Config>,
+    pub profile: Option<Arc<Profile>>,
 }
 
 impl Generator {
     pub fn clone_with_storage_config(&self) -> Result<Self, Error> {
         // 使用配置中的映射文件路径创建新的规则生成器
-        let mapping_config_path = self.config.mapping_config_path();
-        let rule_generator = if let Some(path) = mapping_config_path {
-            RuleGenerator::new_with_config(Some(path))?
-        } else {
-            RuleGenerator::new()?
-        };
+        let rule_generator = RuleGenerator::new_with_config(
+            self.config.mapping_config_path(),
+            self.profile.as_deref(),
+        )?;
         
         Ok(Generator {
+            #[cfg(feature = "llm")]
             llm_generator: self.llm_generator.clone(),
             rule_generator,
-            storage: self.storage.clone(),
             config: self.config.clone(),
+            profile: self.profile.clone(),
         })
     }
 }
 
 impl Generator {
-    pub fn new(config: Arc<Config>, storage: Arc<Storage>) -> Result<Self, Error> {
+    /// 按配置创建生成器：加载配置档和词汇映射，配置了 API 密钥时启用大模型
+    pub fn new(config: Arc<Config>) -> Result<Self, Error> {
+        // 加载当前生效的领域配置档
+        let profile = config
+            .profile()
+            .map(Profile::load)
+            .transpose()?
+            .map(Arc::new);
+        
         // 尝试初始化大模型生成器
-        let llm_generator = match LLMGenerator::new(config.clone()) {
+        #[cfg(feature = "llm")]
+        let llm_generator = match LLMGenerator::new(config.clone(), profile.clone()) {
             Ok(generator) => Some(generator),
             Err(e) => {
                 // 如果大模型生成器初始化失败，记录错误但继续使用规则引擎
-                eprintln!("Warning: Failed to initialize LLM generator: {}", e);
+                eprintln!("{}", t!("警告: 大模型生成器初始化失败: {error}", error = e));
                 None
             }
         };
-        
-        // 初始化规则引擎生成器，使用配置中的映射文件路径
-        let mapping_config_path = config.mapping_config_path();
-        let rule_generator = if let Some(path) = mapping_config_path {
-            RuleGenerator::new_with_config(Some(path))?
-        } else {
-            RuleGenerator::new()?
-        };
+
+        // 离线构建无法调用大模型，配置了密钥时提示一次
+        #[cfg(not(feature = "llm"))]
+        if config.api_key().is_some_and(|key| !key.is_empty()) {
+            eprintln!("{}", t!("警告: 已配置 API 密钥，但编译时未启用 `llm` 特性，将只使用规则引擎"));
+        }
+
+        // 初始化规则引擎生成器，使用配置中的映射文件路径和配置档
+        let rule_generator =
+            RuleGenerator::new_with_config(config.mapping_config_path(), profile.as_deref())?;
         
         Ok(Self {
+            #[cfg(feature = "llm")]
             llm_generator,
             rule_generator,
-            storage,
             config,
+            profile,
         })
     }
     
+    /// 按配置补全命名参数：命名规范依次取参数、项目配置、用户配置、配置档和语言惯例
+    pub fn resolve_options(
+        &self,
+        style: Option<NamingStyle>,
+        kind: Option<IdentifierKind>,
+        language: Option<Language>,
+    ) -> GenerateOptions {
+        let options = GenerateOptions::resolve(style, kind, language, self.profile.as_deref());
+        let (style, _) = self.config.resolve_style(style, options.language, options.kind, self.profile.as_deref());
+        GenerateOptions { style, ..options }
+    }
+    
+    /// 是否可以调用大模型
+    pub fn llm_available(&self) -> bool {
+        #[cfg(feature = "llm")]
+        return self.llm_generator.is_some();
+        #[cfg(not(feature = "llm"))]
+        return false;
+    }
+    
+    /// 根据描述生成候选名称：优先使用大模型，不可用或失败时回退到规则引擎
+    #[cfg_attr(not(feature = "llm"), allow(unused_variables))]
     pub async fn generate(
         &self,
         description: &str,
-        style: NamingStyle,
+        context: Option<&CodeContext>,
+        options: &GenerateOptions,
         force_rule: bool,
     ) -> Result<Vec<String>, Error> {
+        // 大模型结果按描述和命名参数缓存（带代码上下文时不缓存）
+        #[cfg(feature = "llm")]
+        let cache_key = (self.llm_generator.is_some() && !force_rule && context.is_none())
+            .then(|| self.cache_key(description, options));
+        #[cfg(feature = "llm")]
+        if let Some(names) = cache_key.as_deref().and_then(|key| self.cached_names(key)) {
+            return Ok(names);
+        }
+        
         // 首先检查是否有网络连接
+        #[cfg(feature = "llm")]
         let has_network = if !force_rule {
             self.check_network().await
         } else {
@@ -81,33 +177,156 @@ impl Generator {
         };
         
         // 如果有网络连接且未强制使用规则引擎，尝试使用大模型生成器
+        #[cfg(feature = "llm")]
         if has_network && self.llm_generator.is_some() && !force_rule {
             if let Some(llm_generator) = &self.llm_generator {
-                match llm_generator.generate(description, style).await {
+                match llm_generator.generate(description, context, options).await {
                     Ok(variable_names) => {
-                        return Ok(variable_names);
+                        if let Some(key) = &cache_key {
+                            self.cache_names(key, &variable_names);
+                        }
+                        return Ok(avoid_used_names(variable_names, context));
                     }
                     Err(e) => {
                         // 如果大模型生成失败，回退到规则引擎
-                        eprintln!("Warning: LLM generation failed: {}", e);
+                        eprintln!("{}", t!("警告: 大模型生成失败: {error}", error = e));
                     }
                 }
             }
         }
         
         // 使用规则引擎生成变量名
-        let variable_names = self.rule_generator.generate(description, style)?;
+        let variable_names = self.rule_generator.generate(description, options)?;
         
-        Ok(variable_names)
+        Ok(avoid_used_names(variable_names, context))
     }
     
+    /// 为已有标识符推荐新名称：优先使用大模型，不可用或失败时离线解码拼音缩写
+    #[cfg_attr(not(feature = "llm"), allow(unused_variables))]
+    pub async fn suggest_rename(
+        &self,
+        identifier: &str,
+        context: Option<&str>,
+        options: &GenerateOptions,
+        force_rule: bool,
+    ) -> Result<Vec<String>, Error> {
+        #[cfg(feature = "llm")]
+        let has_network = if !force_rule {
+            self.check_network().await
+        } else {
+            false
+        };
+        
+        #[cfg(feature = "llm")]
+        if has_network {
+            if let Some(llm_generator) = &self.llm_generator {
+                match llm_generator.suggest_rename(identifier, context, options).await {
+                    Ok(candidates) => {
+                        let candidates = self.validate_candidates(identifier, &candidates, options);
+                        if !candidates.is_empty() {
+                            return Ok(candidates);
+                        }
+                        eprintln!("{}", t!("警告: 大模型没有返回有效的名称"));
+                    }
+                    Err(e) => {
+                        eprintln!("{}", t!("警告: 大模型推荐名称失败: {error}", error = e));
+                    }
+                }
+            }
+        }
+        
+        let candidates = self.rule_generator.suggest_rename(identifier, options)?;
+        Ok(self.validate_candidates(identifier, &candidates, options))
+    }
+    
+    /// 把候选名称统一为目标命名规范，去掉非 ASCII、与原名相同和重复的结果
+    fn validate_candidates(&self, identifier: &str, candidates: &[String], options: &GenerateOptions) -> Vec<String> {
+        let casing = self.rule_generator.casing(options);
+        let mut validated = Vec::new();
+        
+        for candidate in candidates {
+            // 去掉模型输出中的序号、列表符号和引号
+            let candidate = candidate
+                .trim_start_matches(|c: char| c.is_ascii_digit() || matches!(c, '.' | ')' | '-' | '*' | ' '))
+                .trim_matches(|c: char| c.is_whitespace() || matches!(c, '`' | '"' | '\''));
+            if candidate.is_empty() || !candidate.is_ascii() {
+                continue;
+            }
+            
+            let words = split_identifier(candidate);
+            if words.first().is_none_or(|w| w.starts_with(|c: char| c.is_ascii_digit())) {
+                continue;
+            }
+            
+            let name = casing.convert(&words, options.style);
+            if name != identifier && !validated.contains(&name) {
+                validated.push(name);
+            }
+        }
+        
+        validated
+    }
+    
+    #[cfg(feature = "llm")]
+    fn cache_key(&self, description: &str, options: &GenerateOptions) -> String {
+        format!(
+            "llm:{}:{}:{:?}:{}",
+            self.config.model(),
+            self.config.profile().unwrap_or_default(),
+            options,
+            description
+        )
+    }
+    
+    /// 读取未过期的大模型缓存；未启用缓存或读取失败时返回 None
+    #[cfg(feature = "llm")]
+    fn cached_names(&self, key: &str) -> Option<Vec<String>> {
+        let storage = self.config.store().filter(|_| self.config.cache_enabled())?;
+        match storage.get_fresh_cache(key, self.config.cache_ttl()) {
+            Ok(value) => value.and_then(|v| serde_json::from_str(&v).ok()),
+            Err(e) => {
+                eprintln!("{}", t!("警告: 读取缓存失败: {error}", error = e));
+                None
+            }
+        }
+    }
+    
+    #[cfg(feature = "llm")]
+    fn cache_names(&self, key: &str, names: &[String]) {
+        let Some(storage) = self.config.store().filter(|_| self.config.cache_enabled()) else {
+            return;
+        };
+        let result = serde_json::to_string(names)
+            .map_err(Error::from)
+            .and_then(|value| storage.save_cache(key, &crate::storage::CacheEntry::new(&value)?));
+        if let Err(e) = result {
+            eprintln!("{}", t!("警告: 写入缓存失败: {error}", error = e));
+        }
+    }
+    
+    #[cfg(feature = "llm")]
     async fn check_network(&self) -> bool {
         // 简单的网络连接检查
-        match reqwest::get("https://www.baidu.com").await {
-            Ok(_) => true,
-            Err(_) => false,
+        reqwest::get("https://www.baidu.com").await.is_ok()
+    }
+}
+
+/// 去掉与上下文中已有名称重复的候选；全部重复时保留结果并给出警告
+fn avoid_used_names(variable_names: Vec<String>, context: Option<&CodeContext>) -> Vec<String> {
+    let Some(context) = context else {
+        return variable_names;
+    };
+    
+    let (used, fresh): (Vec<String>, Vec<String>) =
+        variable_names.into_iter().partition(|name| context.is_used(name));
+    if fresh.is_empty() {
+        for name in &used {
+            eprintln!("{}", t!("警告: {name} 与上下文中已有的名称重复，会遮蔽原有变量", name = name));
         }
+        return used;
     }
+    
+    fresh
 }
 
 // 用于测试的辅助函数
@@ -115,17 +334,37 @@ impl Generator {
 #[allow(dead_code)]
 pub fn mock_generator() -> Generator {
     use crate::config::mock_config;
-    use crate::storage::mock_storage;
     
     let config = Arc::new(mock_config());
-    let storage = Arc::new(mock_storage().unwrap());
     
     Generator {
+        #[cfg(feature = "llm")]
         llm_generator: None,
         rule_generator: RuleGenerator::new().unwrap(),
-        storage,
         config,
+        profile: None,
     }
 }
+#[cfg(all(test, feature = "llm"))]
+mod tests {
+    use super::*;
+    use crate::storage::{CacheEntry, MemoryStore, Store};
+
+    #[tokio::test]
+    async fn test_generate_uses_cached_llm_result() {
+        let storage: Arc<dyn Store> = Arc::new(MemoryStore::new());
+        let config = Config::builder().store(storage.clone()).api_key("mock-api-key").build();
+        let generator = Generator::new(Arc::new(config)).unwrap();
+        let options = GenerateOptions::resolve(Some(NamingStyle::Camel), None, None, None);
 
-use reqwest;
\ No newline at end of file
+        let key = generator.cache_key("用户信息", &options);
+        storage.save_cache(&key, &CacheEntry::new(r#"["cachedUserInfo"]"#).unwrap()).unwrap();
+
+        let names = generator.generate("用户信息", None, &options, false).await.unwrap();
+        assert_eq!(names, vec!["cachedUserInfo"]);
+
+        // 强制规则引擎时不读缓存
+        let names = generator.generate("用户信息", None, &options, true).await.unwrap();
+        assert_eq!(names[0], "userInfo");
+    }
+}
@@ -0,0 +1,308 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::naming::{Language, NamingStyle};
+
+/// 缩写偏好
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum AbbreviationPreference {
+    /// 不缩写，并展开已知缩写（id、info 等通用缩写保留）
+    #[cfg_attr(feature = "cli", clap(name = "full", help = t!("不缩写，并展开已知缩写（id、info 等通用缩写保留）")))]
+    Full,
+    /// 尽可能使用词典中的缩写
+    #[cfg_attr(feature = "cli", clap(name = "abbreviated", help = t!("尽可能使用词典中的缩写")))]
+    Abbreviated,
+    /// 保留原词，仅在超出长度目标时缩写
+    #[default]
+    #[cfg_attr(feature = "cli", clap(name = "auto", help = t!("保留原词，仅在超出长度目标时缩写")))]
+    Auto,
+}
+
+impl std::fmt::Display for AbbreviationPreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AbbreviationPreference::Full => write!(f, "full"),
+            AbbreviationPreference::Abbreviated => write!(f, "abbreviated"),
+            AbbreviationPreference::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+/// 内置的通用缩写词典（完整词 -> 缩写），顺序决定反查时的优先级
+const DEFAULT_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("configuration", "cfg"),
+    ("config", "cfg"),
+    ("information", "info"),
+    ("number", "num"),
+    ("database", "db"),
+    ("connection", "conn"),
+    ("message", "msg"),
+    ("parameter", "param"),
+    ("argument", "arg"),
+    ("temporary", "tmp"),
+    ("reference", "ref"),
+    ("previous", "prev"),
+    ("current", "cur"),
+    ("maximum", "max"),
+    ("minimum", "min"),
+    ("index", "idx"),
+    ("count", "cnt"),
+    ("context", "ctx"),
+    ("request", "req"),
+    ("response", "resp"),
+    ("error", "err"),
+    ("buffer", "buf"),
+    ("string", "str"),
+    ("value", "val"),
+    ("address", "addr"),
+    ("password", "pwd"),
+    ("directory", "dir"),
+    ("document", "doc"),
+    ("initialize", "init"),
+    ("application", "app"),
+    ("environment", "env"),
+    ("authentication", "auth"),
+    ("administrator", "admin"),
+    ("statistics", "stats"),
+    ("source", "src"),
+    ("destination", "dst"),
+    ("quantity", "qty"),
+    ("identifier", "id"),
+    ("length", "len"),
+    ("attribute", "attr"),
+    ("specification", "spec"),
+    ("utility", "util"),
+    ("description", "desc"),
+    ("calculate", "calc"),
+    ("repository", "repo"),
+    ("transaction", "txn"),
+    ("average", "avg"),
+    ("image", "img"),
+];
+
+/// 通用缩写：比完整单词更常用，偏好完整单词时也不展开
+const STANDARD_ABBREVIATIONS: &[&str] = &["id", "info", "max", "min", "app", "url", "str"];
+
+/// 缩写策略：缩写词典 + 长度目标 + 完整/缩写偏好
+#[derive(Debug, Clone)]
+pub struct AbbreviationPolicy {
+    /// 可接受的缩写（完整词 -> 缩写）
+    dictionary: Vec<(String, String)>,
+    /// 必须使用的偏好缩写（来自配置档）
+    preferred: HashMap<String, String>,
+    /// 标识符最大长度目标
+    max_length: Option<usize>,
+    /// 完整/缩写偏好
+    preference: AbbreviationPreference,
+}
+
+impl Default for AbbreviationPolicy {
+    fn default() -> Self {
+        Self {
+            dictionary: DEFAULT_ABBREVIATIONS
+                .iter()
+                .map(|(full, short)| (full.to_string(), short.to_string()))
+                .collect(),
+            preferred: HashMap::new(),
+            max_length: None,
+            preference: AbbreviationPreference::Auto,
+        }
+    }
+}
+
+impl AbbreviationPolicy {
+    /// 按语言风格指南构建默认策略
+    pub fn for_language(language: Option<Language>) -> Self {
+        let mut policy = Self::default();
+
+        match language {
+            // Go 偏好简短的名称（ctx、err、buf、cfg）
+            Some(Language::Go) => {
+                policy.preference = AbbreviationPreference::Abbreviated;
+                policy.max_length = Some(24);
+            }
+            // Java 编码规范建议使用完整单词
+            Some(Language::Java) => {
+                policy.preference = AbbreviationPreference::Full;
+            }
+            Some(Language::Python) | Some(Language::Rust) => {
+                policy.max_length = Some(30);
+            }
+            Some(Language::JavaScript) | Some(Language::TypeScript) => {
+                policy.max_length = Some(32);
+            }
+            None => {}
+        }
+
+        policy
+    }
+
+    /// 合并配置档中的偏好缩写
+    pub fn with_preferred(mut self, preferred: &HashMap<String, String>) -> Self {
+        for (full, short) in preferred {
+            self.preferred.insert(full.clone(), short.clone());
+            if !self.dictionary.iter().any(|(f, _)| f == full) {
+                self.dictionary.push((full.clone(), short.clone()));
+            }
+        }
+        self
+    }
+
+    /// 覆盖长度目标（None 表示保持当前值）
+    pub fn with_max_length(mut self, max_length: Option<usize>) -> Self {
+        if max_length.is_some() {
+            self.max_length = max_length;
+        }
+        self
+    }
+
+    /// 覆盖缩写偏好（None 表示保持当前值）
+    pub fn with_preference(mut self, preference: Option<AbbreviationPreference>) -> Self {
+        if let Some(preference) = preference {
+            self.preference = preference;
+        }
+        self
+    }
+
+    /// 完整/缩写偏好
+    pub fn preference(&self) -> AbbreviationPreference {
+        self.preference
+    }
+
+    /// 查找完整词对应的缩写
+    pub fn abbreviate(&self, word: &str) -> Option<&str> {
+        self.preferred
+            .get(word)
+            .or_else(|| self.dictionary.iter().find(|(full, _)| full == word).map(|(_, s)| s))
+            .map(|s| s.as_str())
+    }
+
+    /// 查找缩写对应的完整词
+    pub fn expand(&self, abbreviation: &str) -> Option<&str> {
+        self.dictionary
+            .iter()
+            .find(|(_, short)| short == abbreviation)
+            .map(|(full, _)| full.as_str())
+    }
+
+    /// 是否为通用缩写（id、info 等），这类缩写不展开
+    pub fn is_standard(&self, abbreviation: &str) -> bool {
+        STANDARD_ABBREVIATIONS.contains(&abbreviation)
+    }
+
+    /// 偏好完整单词时的写法：配置档的偏好缩写照常使用，通用缩写保留，其他已知缩写展开
+    fn full_form<'a>(&'a self, token: &'a str) -> &'a str {
+        if let Some(short) = self.preferred.get(token) {
+            return short;
+        }
+        if self.is_standard(token) || self.preferred.values().any(|short| short == token) {
+            return token;
+        }
+        self.expand(token).unwrap_or(token)
+    }
+
+    /// 对分词结果应用缩写策略
+    pub fn apply(&self, tokens: &[String], style: NamingStyle) -> Vec<String> {
+        match self.preference {
+            AbbreviationPreference::Full => tokens.iter().map(|token| self.full_form(token).to_string()).collect(),
+            AbbreviationPreference::Abbreviated => tokens
+                .iter()
+                .map(|token| self.abbreviate(token).unwrap_or(token).to_string())
+                .collect(),
+            AbbreviationPreference::Auto => self.shorten_to_target(tokens, style),
+        }
+    }
+
+    /// 先应用偏好缩写，若仍超出长度目标，则从最长的词开始逐个缩写
+    fn shorten_to_target(&self, tokens: &[String], style: NamingStyle) -> Vec<String> {
+        let mut result: Vec<String> = tokens
+            .iter()
+            .map(|token| self.preferred.get(token).unwrap_or(token).clone())
+            .collect();
+
+        let Some(max_length) = self.max_length else {
+            return result;
+        };
+
+        let mut candidates: Vec<usize> = (0..result.len())
+            .filter(|&i| self.abbreviate(&result[i]).is_some())
+            .collect();
+        candidates.sort_by_key(|&i| std::cmp::Reverse(result[i].len()));
+
+        for i in candidates {
+            if identifier_length(&result, style) <= max_length {
+                break;
+            }
+            if let Some(short) = self.abbreviate(&result[i]) {
+                result[i] = short.to_string();
+            }
+        }
+
+        result
+    }
+}
+
+/// 按命名规范计算拼接后的标识符长度
+pub fn identifier_length(tokens: &[String], style: NamingStyle) -> usize {
+    let separators = tokens.len().saturating_sub(1) * style.separator().len();
+    tokens.iter().map(|t| t.chars().count()).sum::<usize>() + separators
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_auto_shortens_longest_words_first() {
+        let policy = AbbreviationPolicy::default().with_max_length(Some(30));
+        let input = tokens(&["database", "connection", "configuration", "information"]);
+
+        let result = policy.apply(&input, NamingStyle::Snake);
+        assert!(identifier_length(&result, NamingStyle::Snake) <= 30);
+        assert_eq!(result, tokens(&["database", "connection", "cfg", "info"]));
+    }
+
+    #[test]
+    fn test_auto_without_target_keeps_words() {
+        let policy = AbbreviationPolicy::default();
+        let input = tokens(&["database", "configuration"]);
+        assert_eq!(policy.apply(&input, NamingStyle::Snake), input);
+    }
+
+    #[test]
+    fn test_full_expands_known_abbreviations() {
+        let policy = AbbreviationPolicy::for_language(Some(Language::Java));
+        let result = policy.apply(&tokens(&["db", "cfg", "tmp"]), NamingStyle::Camel);
+        assert_eq!(result, tokens(&["database", "configuration", "temporary"]));
+    }
+
+    #[test]
+    fn test_full_keeps_standard_and_preferred_abbreviations() {
+        let mut preferred = HashMap::new();
+        preferred.insert("transaction".to_string(), "txn".to_string());
+
+        let policy = AbbreviationPolicy::for_language(Some(Language::Java)).with_preferred(&preferred);
+        let result = policy.apply(&tokens(&["user", "id", "info", "max", "url"]), NamingStyle::Camel);
+        assert_eq!(result, tokens(&["user", "id", "info", "max", "url"]));
+        let result = policy.apply(&tokens(&["transaction", "txn"]), NamingStyle::Camel);
+        assert_eq!(result, tokens(&["txn", "txn"]));
+    }
+
+    #[test]
+    fn test_abbreviated_and_preferred() {
+        let mut preferred = HashMap::new();
+        preferred.insert("customer".to_string(), "cust".to_string());
+
+        let policy = AbbreviationPolicy::default()
+            .with_preferred(&preferred)
+            .with_preference(Some(AbbreviationPreference::Abbreviated));
+        let result = policy.apply(&tokens(&["customer", "number"]), NamingStyle::Snake);
+        assert_eq!(result, tokens(&["cust", "num"]));
+    }
+}
@@ -0,0 +1,382 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::naming::{Language, NamingStyle};
+
+/// 内置缩略词列表
+pub const DEFAULT_ACRONYMS: &[&str] = &[
+    "api", "ascii", "cli", "cpu", "css", "csv", "db", "dns", "gpu", "gui", "html", "http",
+    "https", "id", "io", "ip", "json", "jwt", "os", "pdf", "ram", "rpc", "sdk", "sql", "ssh",
+    "ssl", "tcp", "tls", "ttl", "ui", "udp", "uri", "url", "utf8", "uuid", "xml", "yaml",
+];
+
+/// 缩略词大小写策略
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum AcronymPolicy {
+    /// 缩略词保持全大写：userID、HTTPClient
+    #[cfg_attr(feature = "cli", clap(name = "preserve", help = t!("缩略词保持全大写：userID、HTTPClient")))]
+    Preserve,
+    /// 缩略词按普通单词处理：userId、HttpClient
+    #[cfg_attr(feature = "cli", clap(name = "capitalize", help = t!("缩略词按普通单词处理：userId、HttpClient")))]
+    Capitalize,
+}
+
+impl AcronymPolicy {
+    /// 各语言风格指南的默认策略
+    pub fn for_language(language: Option<Language>) -> Self {
+        match language {
+            // Go Code Review Comments：userID、ServeHTTP
+            Some(Language::Go) => AcronymPolicy::Preserve,
+            // PEP 8：CapWords 中的缩略词全部大写（HTTPServerError）
+            Some(Language::Python) => AcronymPolicy::Preserve,
+            // Rust RFC 430、Google Java/JS 风格指南：缩略词视为一个单词（Uuid、XmlHttpRequest）
+            Some(Language::Rust)
+            | Some(Language::Java)
+            | Some(Language::JavaScript)
+            | Some(Language::TypeScript)
+            | None => AcronymPolicy::Capitalize,
+        }
+    }
+}
+
+impl std::fmt::Display for AcronymPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AcronymPolicy::Preserve => write!(f, "preserve"),
+            AcronymPolicy::Capitalize => write!(f, "capitalize"),
+        }
+    }
+}
+
+/// 大小写转换器：把单词列表按命名规范拼接，并处理缩略词
+#[derive(Debug, Clone)]
+pub struct Casing {
+    acronyms: HashSet<String>,
+    policy: AcronymPolicy,
+}
+
+impl Casing {
+    pub fn new(policy: AcronymPolicy) -> Self {
+        Self {
+            acronyms: DEFAULT_ACRONYMS.iter().map(|s| s.to_string()).collect(),
+            policy,
+        }
+    }
+
+    /// 追加自定义缩略词
+    pub fn with_acronyms<I, S>(mut self, acronyms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.acronyms
+            .extend(acronyms.into_iter().map(|s| s.as_ref().to_lowercase()));
+        self
+    }
+
+    pub fn is_acronym(&self, word: &str) -> bool {
+        self.acronyms.contains(&word.to_lowercase())
+    }
+
+    /// 按命名规范拼接单词
+    pub fn convert(&self, words: &[String], style: NamingStyle) -> String {
+        match style {
+            NamingStyle::Camel | NamingStyle::LowerCamel => self.to_camel_case(words),
+            NamingStyle::Pascal => self.to_pascal_case(words),
+            NamingStyle::Train | NamingStyle::Ada => self.join_capitalized(words, style.separator()),
+            NamingStyle::UpperSnake | NamingStyle::ScreamingKebab => {
+                join_lower(words, style.separator()).to_uppercase()
+            }
+            NamingStyle::Snake
+            | NamingStyle::Kebab
+            | NamingStyle::Dot
+            | NamingStyle::Flat
+            | NamingStyle::Path
+            | NamingStyle::Namespace => join_lower(words, style.separator()),
+        }
+    }
+
+    pub fn to_camel_case(&self, words: &[String]) -> String {
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    // 第一个词（包括缩略词）保持小写
+                    word.to_lowercase()
+                } else {
+                    self.capitalize_word(word)
+                }
+            })
+            .collect()
+    }
+
+    pub fn to_pascal_case(&self, words: &[String]) -> String {
+        words.iter().map(|word| self.capitalize_word(word)).collect()
+    }
+
+    /// 每个词首字母大写后用分隔符连接：Train-Case、Ada_Case
+    fn join_capitalized(&self, words: &[String], separator: &str) -> String {
+        words
+            .iter()
+            .map(|word| self.capitalize_word(word))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// 词首字母大写；按策略保留的缩略词全部大写
+    fn capitalize_word(&self, word: &str) -> String {
+        if self.policy == AcronymPolicy::Preserve && self.is_acronym(word) {
+            return word.to_uppercase();
+        }
+
+        let lower = word.to_lowercase();
+        let mut chars = lower.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+}
+
+fn join_lower(words: &[String], separator: &str) -> String {
+    words
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// 把标识符或短语拆分为小写单词，识别缩略词和数字
+///
+/// `XMLHttpRequest` -> xml, http, request；`parseURLFromJSON` -> parse, url, from, json；
+/// `userIDs` -> user, ids；`utf8Decoder` -> utf8, decoder
+pub fn split_identifier(text: &str) -> Vec<String> {
+    split_words(text)
+        .into_iter()
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+/// 按单词边界拆分，保留原始大小写
+fn split_words(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if !current.is_empty() && c.is_uppercase() {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1).copied();
+            let after_next = chars.get(i + 2).copied();
+
+            // 小写或数字后出现大写：userName、utf8Decoder
+            let lower_to_upper = prev.is_lowercase() || prev.is_numeric();
+            // 连续大写后接小写，最后一个大写字母属于下一个词：HTTPServer
+            // 但缩略词复数（IDs）除外
+            let is_acronym_plural =
+                next == Some('s') && !after_next.is_some_and(|a| a.is_lowercase());
+            let acronym_end =
+                prev.is_uppercase() && next.is_some_and(|n| n.is_lowercase()) && !is_acronym_plural;
+
+            if lower_to_upper || acronym_end {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+/// 在大小写混合的标识符中以全大写出现的缩略词（`userID` 中的 ID）
+pub fn uppercase_acronyms(identifier: &str) -> Vec<String> {
+    if !identifier.chars().any(|c| c.is_lowercase()) {
+        return Vec::new();
+    }
+
+    split_words(identifier)
+        .into_iter()
+        .filter(|w| w.chars().filter(|c| c.is_alphabetic()).count() > 1)
+        .filter(|w| !w.chars().any(|c| c.is_lowercase()))
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+/// 推断标识符使用的命名规范；无法区分时（如单个小写单词）返回 None
+pub fn detect_style(identifier: &str) -> Option<NamingStyle> {
+    let has_upper = identifier.chars().any(|c| c.is_uppercase());
+    let has_lower = identifier.chars().any(|c| c.is_lowercase());
+    let words = split_words(identifier);
+    let all_capitalized = !words.is_empty()
+        && words
+            .iter()
+            .all(|w| w.chars().next().is_some_and(|c| !c.is_lowercase()));
+
+    let separated = |upper: NamingStyle, capitalized: Option<NamingStyle>, lower: NamingStyle| {
+        if !has_lower {
+            Some(upper)
+        } else if !has_upper {
+            Some(lower)
+        } else if all_capitalized {
+            capitalized
+        } else {
+            None
+        }
+    };
+
+    if identifier.contains("::") {
+        return (!has_upper).then_some(NamingStyle::Namespace);
+    }
+    if identifier.contains('/') {
+        return (!has_upper).then_some(NamingStyle::Path);
+    }
+    if identifier.contains('.') {
+        return (!has_upper).then_some(NamingStyle::Dot);
+    }
+    if identifier.trim_matches('_').contains('_') {
+        return separated(NamingStyle::UpperSnake, Some(NamingStyle::Ada), NamingStyle::Snake);
+    }
+    if identifier.trim_matches('-').contains('-') {
+        return separated(NamingStyle::ScreamingKebab, Some(NamingStyle::Train), NamingStyle::Kebab);
+    }
+
+    match identifier.chars().find(|c| c.is_alphabetic()) {
+        _ if !has_upper => None,
+        _ if !has_lower => Some(NamingStyle::UpperSnake),
+        Some(first) if first.is_uppercase() => Some(NamingStyle::Pascal),
+        Some(_) => Some(NamingStyle::Camel),
+        None => None,
+    }
+}
+
+/// 单个标识符的转换结果
+#[derive(Debug, Clone, Serialize)]
+pub struct Conversion {
+    pub input: String,
+    pub from: Option<&'static str>,
+    pub to: &'static str,
+    pub output: String,
+}
+
+/// 在命名规范之间无损转换已有标识符，不做翻译和词形处理
+///
+/// 未指定缩略词策略时沿用输入中的写法：`userID` 转为 Pascal 得到 `UserID`
+pub fn convert_identifier(
+    identifier: &str,
+    style: NamingStyle,
+    policy: Option<AcronymPolicy>,
+    language: Option<Language>,
+) -> Conversion {
+    let acronyms = uppercase_acronyms(identifier);
+    let policy = policy.unwrap_or_else(|| {
+        if acronyms.is_empty() {
+            AcronymPolicy::for_language(language)
+        } else {
+            AcronymPolicy::Preserve
+        }
+    });
+
+    let words = split_identifier(identifier);
+    let output = Casing::new(policy).with_acronyms(&acronyms).convert(&words, style);
+
+    Conversion {
+        input: identifier.to_string(),
+        from: detect_style(identifier).map(|s| s.name()),
+        to: style.name(),
+        output,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_split_identifier_acronyms() {
+        assert_eq!(split_identifier("XMLHttpRequest"), words(&["xml", "http", "request"]));
+        assert_eq!(split_identifier("HTTPServer"), words(&["http", "server"]));
+        assert_eq!(split_identifier("parseURLFromJSON"), words(&["parse", "url", "from", "json"]));
+        assert_eq!(split_identifier("userIDs"), words(&["user", "ids"]));
+        assert_eq!(split_identifier("utf8Decoder"), words(&["utf8", "decoder"]));
+        assert_eq!(split_identifier("user_name-field"), words(&["user", "name", "field"]));
+    }
+
+    #[test]
+    fn test_acronym_policy() {
+        let input = words(&["http", "client", "user", "id"]);
+
+        let preserve = Casing::new(AcronymPolicy::Preserve);
+        assert_eq!(preserve.to_pascal_case(&input), "HTTPClientUserID");
+        assert_eq!(preserve.to_camel_case(&input), "httpClientUserID");
+
+        let capitalize = Casing::new(AcronymPolicy::Capitalize);
+        assert_eq!(capitalize.to_pascal_case(&input), "HttpClientUserId");
+        assert_eq!(capitalize.convert(&input, NamingStyle::UpperSnake), "HTTP_CLIENT_USER_ID");
+    }
+
+    #[test]
+    fn test_additional_styles() {
+        let input = words(&["content", "type", "id"]);
+        let casing = Casing::new(AcronymPolicy::Capitalize);
+
+        assert_eq!(casing.convert(&input, NamingStyle::Dot), "content.type.id");
+        assert_eq!(casing.convert(&input, NamingStyle::Train), "Content-Type-Id");
+        assert_eq!(casing.convert(&input, NamingStyle::Flat), "contenttypeid");
+        assert_eq!(casing.convert(&input, NamingStyle::ScreamingKebab), "CONTENT-TYPE-ID");
+        assert_eq!(casing.convert(&input, NamingStyle::Ada), "Content_Type_Id");
+        assert_eq!(casing.convert(&input, NamingStyle::Path), "content/type/id");
+        assert_eq!(casing.convert(&input, NamingStyle::Namespace), "content::type::id");
+        assert_eq!(
+            Casing::new(AcronymPolicy::Preserve).convert(&input, NamingStyle::Train),
+            "Content-Type-ID"
+        );
+    }
+
+    #[test]
+    fn test_detect_style() {
+        assert_eq!(detect_style("getUserInfo"), Some(NamingStyle::Camel));
+        assert_eq!(detect_style("HttpClient"), Some(NamingStyle::Pascal));
+        assert_eq!(detect_style("user_name"), Some(NamingStyle::Snake));
+        assert_eq!(detect_style("MAX_SIZE"), Some(NamingStyle::UpperSnake));
+        assert_eq!(detect_style("Content-Type"), Some(NamingStyle::Train));
+        assert_eq!(detect_style("app.config.port"), Some(NamingStyle::Dot));
+        assert_eq!(detect_style("std::io"), Some(NamingStyle::Namespace));
+        assert_eq!(detect_style("user"), None);
+    }
+
+    #[test]
+    fn test_convert_identifier_is_lossless() {
+        let snake = convert_identifier("getUserID", NamingStyle::Snake, None, None);
+        assert_eq!(snake.output, "get_user_id");
+        assert_eq!(snake.from, Some("camel"));
+
+        assert_eq!(convert_identifier("getUserID", NamingStyle::Pascal, None, None).output, "GetUserID");
+        assert_eq!(convert_identifier("utf8_decoder", NamingStyle::Camel, None, None).output, "utf8Decoder");
+        assert_eq!(convert_identifier("XMLHttpRequest", NamingStyle::Kebab, None, None).output, "xml-http-request");
+    }
+
+    #[test]
+    fn test_custom_acronyms() {
+        let casing = Casing::new(AcronymPolicy::Preserve).with_acronyms(["SKU"]);
+        assert_eq!(casing.to_camel_case(&words(&["product", "sku"])), "productSKU");
+    }
+}
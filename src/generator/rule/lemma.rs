@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+/// 不规则名词复数（复数 -> 单数）
+const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    ("children", "child"),
+    ("people", "person"),
+    ("men", "man"),
+    ("women", "woman"),
+    ("mice", "mouse"),
+    ("geese", "goose"),
+    ("feet", "foot"),
+    ("teeth", "tooth"),
+    ("indices", "index"),
+    ("matrices", "matrix"),
+    ("vertices", "vertex"),
+    ("analyses", "analysis"),
+    ("axes", "axis"),
+    ("crises", "crisis"),
+    ("criteria", "criterion"),
+    ("phenomena", "phenomenon"),
+    ("leaves", "leaf"),
+    ("lives", "life"),
+    ("knives", "knife"),
+    ("halves", "half"),
+    ("selves", "self"),
+    ("wives", "wife"),
+    ("shelves", "shelf"),
+];
+
+/// 不可数或单复同形的词，不做单复数变换
+const UNCOUNTABLE: &[&str] = &[
+    "data", "metadata", "info", "information", "equipment", "feedback", "software",
+    "hardware", "firmware", "middleware", "news", "series", "species", "media", "progress",
+    "knowledge", "traffic", "music", "money", "research", "evidence", "config", "staff",
+];
+
+/// 常用词词表（单数/原形），只有词形还原的结果在词表中时才采用
+const LEXICON: &[&str] = &[
+    "account", "action", "activity", "address", "admin", "agent", "alert", "amount", "analysis",
+    "answer", "api", "app", "application", "argument", "array", "article", "asset", "attachment",
+    "attempt", "attribute", "author", "balance", "base", "batch", "bill", "block", "body", "book", "box",
+    "branch", "buffer", "bug", "button", "byte", "cache", "call", "callback", "card", "cart",
+    "case", "category", "cell", "change", "channel", "char", "character", "chart", "check",
+    "child", "chunk", "city", "class", "client", "code", "column", "command", "comment", "commit",
+    "company", "component", "condition", "connection", "constant", "contact", "container",
+    "content", "context", "cookie", "copy", "cost", "count", "country", "coupon", "course",
+    "credential", "currency", "customer", "dashboard", "database", "date", "day", "deadline", "department",
+    "dependency", "description", "detail", "device", "dictionary", "directory", "discount",
+    "document", "domain", "download", "draft", "driver", "edge", "element", "email", "employee",
+    "entity", "entry", "error", "event", "exception", "expense", "factory", "failure", "fee",
+    "field", "file", "filter", "flag", "flow", "folder", "font", "form", "format", "frame",
+    "function", "game", "group", "handler", "hash", "header", "history", "host", "hour", "icon",
+    "id", "image", "income", "index", "input", "instance", "interface", "inventory", "invoice",
+    "issue", "item", "job", "key", "label", "language", "layer", "leaf", "level", "library",
+    "life", "limit", "line", "link", "list", "log", "login", "match", "matrix", "member",
+    "menu", "message", "method", "metric", "minute", "mode", "model", "module", "month",
+    "mouse", "name", "node", "note", "notification", "number", "object", "option", "order",
+    "output", "owner", "package", "page", "pair", "parameter", "parent", "part", "password",
+    "path", "payment", "permission", "person", "phone", "photo", "plan", "player", "plugin",
+    "point", "policy", "pool", "port", "post", "price", "process", "product", "profile",
+    "project", "property", "provider", "query", "queue", "quantity", "question", "range",
+    "rate", "record", "reference", "refund", "region", "reply", "report", "repository",
+    "request", "resource", "response", "result", "role", "room", "route", "row", "rule",
+    "sample", "schema", "score", "screen", "script", "second", "section", "service", "session",
+    "setting", "shape", "shelf", "size", "slot", "source", "state", "status", "step", "stock",
+    "store", "stream", "string", "student", "style", "subscription", "supplier", "surname",
+    "system", "tab", "table", "tag", "target", "task", "team", "template", "tenant", "test",
+    "text", "theme", "thread", "ticket", "time", "timestamp", "title", "token", "tool", "topic",
+    "total", "transaction", "tree", "type", "unit", "update", "upload", "url", "user",
+    "username", "value", "variable", "version", "vertex", "video", "view", "warning", "window",
+    "word", "worker", "year", "zone",
+];
+
+/// 英文词形还原器：基于不规则形式表和常用词表，把复数名词还原为单数
+#[derive(Debug, Clone)]
+pub struct Lemmatizer {
+    lexicon: HashSet<String>,
+}
+
+impl Default for Lemmatizer {
+    fn default() -> Self {
+        Self::new(std::iter::empty::<String>())
+    }
+}
+
+impl Lemmatizer {
+    /// 创建词形还原器，`extra_words` 会补充到已知词表中（如映射配置中的英文词）
+    pub fn new<I, S>(extra_words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut lexicon: HashSet<String> = LEXICON.iter().map(|w| w.to_string()).collect();
+        lexicon.extend(IRREGULAR_PLURALS.iter().map(|(_, singular)| singular.to_string()));
+        lexicon.extend(extra_words.into_iter().map(Into::into));
+        Self { lexicon }
+    }
+
+    /// 是否为已知词
+    pub fn is_known(&self, word: &str) -> bool {
+        self.lexicon.contains(word)
+    }
+
+    /// 词是否为复数形式
+    pub fn is_plural(&self, word: &str) -> bool {
+        self.lemma(word) != word
+    }
+
+    /// 返回词的原形；无法确认时保留原始形式
+    ///
+    /// 只处理名词复数，`-ing`、`-ed` 等形式在标识符中有独立含义（如 `is_loading`、`created_at`），保持不变。
+    pub fn lemma(&self, word: &str) -> String {
+        if word.is_empty() || self.is_known(word) || UNCOUNTABLE.contains(&word) {
+            return word.to_string();
+        }
+
+        if let Some((_, singular)) = IRREGULAR_PLURALS.iter().find(|(plural, _)| *plural == word) {
+            return singular.to_string();
+        }
+
+        // 常规复数规则，按优先级尝试，取第一个已知词
+        let mut candidates = Vec::new();
+        if let Some(stem) = word.strip_suffix("ies") {
+            candidates.push(format!("{}y", stem));
+        }
+        if let Some(stem) = word.strip_suffix("ves") {
+            candidates.push(format!("{}f", stem));
+            candidates.push(format!("{}fe", stem));
+        }
+        if let Some(stem) = word.strip_suffix("es") {
+            candidates.push(stem.to_string());
+        }
+        if let Some(stem) = word.strip_suffix('s') {
+            if !stem.ends_with('s') {
+                candidates.push(stem.to_string());
+            }
+        }
+
+        candidates
+            .into_iter()
+            .find(|candidate| self.is_known(candidate))
+            .unwrap_or_else(|| word.to_string())
+    }
+
+    /// 把单数名词变为复数
+    pub fn pluralize(&self, word: &str) -> String {
+        if UNCOUNTABLE.contains(&word) || self.is_plural(word) {
+            return word.to_string();
+        }
+
+        if let Some((plural, _)) = IRREGULAR_PLURALS.iter().find(|(_, singular)| *singular == word) {
+            return plural.to_string();
+        }
+
+        let ends_with_consonant_y = word.ends_with('y')
+            && !word
+                .chars()
+                .rev()
+                .nth(1)
+                .map(|c| "aeiou".contains(c))
+                .unwrap_or(true);
+
+        if ends_with_consonant_y {
+            format!("{}ies", &word[..word.len() - 1])
+        } else if ["s", "x", "z", "ch", "sh"].iter().any(|suffix| word.ends_with(suffix)) {
+            format!("{}es", word)
+        } else {
+            format!("{}s", word)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lemma_regular_and_irregular() {
+        let lemmatizer = Lemmatizer::default();
+        assert_eq!(lemmatizer.lemma("users"), "user");
+        assert_eq!(lemmatizer.lemma("queries"), "query");
+        assert_eq!(lemmatizer.lemma("boxes"), "box");
+        assert_eq!(lemmatizer.lemma("children"), "child");
+        assert_eq!(lemmatizer.lemma("indices"), "index");
+    }
+
+    #[test]
+    fn test_lemma_keeps_surface_form() {
+        let lemmatizer = Lemmatizer::default();
+        assert_eq!(lemmatizer.lemma("using"), "using");
+        assert_eq!(lemmatizer.lemma("query"), "query");
+        assert_eq!(lemmatizer.lemma("easily"), "easily");
+        assert_eq!(lemmatizer.lemma("status"), "status");
+        assert_eq!(lemmatizer.lemma("data"), "data");
+        assert_eq!(lemmatizer.lemma("foos"), "foos");
+    }
+
+    #[test]
+    fn test_pluralize() {
+        let lemmatizer = Lemmatizer::default();
+        assert_eq!(lemmatizer.pluralize("user"), "users");
+        assert_eq!(lemmatizer.pluralize("query"), "queries");
+        assert_eq!(lemmatizer.pluralize("key"), "keys");
+        assert_eq!(lemmatizer.pluralize("box"), "boxes");
+        assert_eq!(lemmatizer.pluralize("child"), "children");
+        assert_eq!(lemmatizer.pluralize("users"), "users");
+        assert_eq!(lemmatizer.pluralize("data"), "data");
+    }
+}
@@ -1,56 +1,139 @@
-use jieba_rs::Jieba;
-use rust_stemmers::{Algorithm, Stemmer};
 use std::collections::{HashMap, HashSet};
 use std::sync::OnceLock;
 
 use crate::utils::error::Error;
-use crate::cli::NamingStyle;
+use crate::naming::IdentifierKind;
 use crate::config::mapping::{MappingConfig, MappingConfigManager};
+use crate::config::profile::Profile;
+use crate::generator::GenerateOptions;
+
+pub mod abbreviation;
+pub mod casing;
+pub mod lemma;
+pub mod pinyin;
+pub mod segment;
+
+use abbreviation::{AbbreviationPolicy, AbbreviationPreference};
+use casing::{split_identifier, AcronymPolicy, Casing};
+use lemma::Lemmatizer;
+use pinyin::PinyinDecoder;
+use segment::Segmenter;
+
+/// 映射配置、停用词表和中英映射表
+type MappingTables = (Option<MappingConfig>, HashSet<String>, HashMap<String, String>);
+
+/// 分词结果的翻译来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenSource {
+    /// 整词在映射表中
+    Mapping,
+    /// 整词没有映射，逐字翻译
+    PerChar,
+    /// 没有找到任何映射，保留原文
+    Untranslated,
+    /// 描述中的英文，只按标识符拆分
+    English,
+}
+
+/// 描述中一个词的翻译结果，见 [`RuleGenerator::explain`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenMapping {
+    pub token: String,
+    pub translation: String,
+    pub source: TokenSource,
+    /// 翻译结果是停用词，生成时会被过滤
+    pub stop_word: bool,
+}
+
+impl TokenMapping {
+    fn new(token: &str, translation: String, source: TokenSource, generator: &RuleGenerator) -> Self {
+        let stop_word = generator.is_stop_word(&translation);
+        Self { token: token.to_string(), translation, source, stop_word }
+    }
+}
 
 pub struct RuleGenerator {
-    chinese_tokenizer: Jieba,
-    english_stemmer: Stemmer,
+    chinese_tokenizer: Segmenter,
+    lemmatizer: Lemmatizer,
     stop_words: HashSet<String>,
     chinese_to_english: HashMap<String, String>,
     mapping_config: Option<MappingConfig>,
+    abbreviations: HashMap<String, String>,
+    max_length: Option<usize>,
+    abbreviation_preference: Option<AbbreviationPreference>,
+    acronyms: Vec<String>,
+    acronym_policy: Option<AcronymPolicy>,
 }
 
 impl std::fmt::Debug for RuleGenerator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RuleGenerator")
-            .field("chinese_tokenizer", &"Jieba")
-            .field("english_stemmer", &"Stemmer")
+            .field("chinese_tokenizer", &self.chinese_tokenizer)
+            .field("lemmatizer", &"Lemmatizer")
             .field("stop_words", &format!("HashSet with {} elements", self.stop_words.len()))
             .finish()
     }
 }
 
 impl RuleGenerator {
+    #[allow(dead_code)]
     pub fn new() -> Result<Self, Error> {
-        Self::new_with_config(None)
+        Self::new_with_config(None, None)
     }
     
-    pub fn new_with_config(mapping_config_path: Option<&str>) -> Result<Self, Error> {
-        // 初始化中文分词器
-        let chinese_tokenizer = Jieba::new();
-        
-        // 初始化英文词根提取器
-        let english_stemmer = Stemmer::create(Algorithm::English);
-        
+    pub fn new_with_config(mapping_config_path: Option<&str>, profile: Option<&Profile>) -> Result<Self, Error> {
         // 加载配置
-        let (mapping_config, stop_words, chinese_to_english) = 
+        let (mut mapping_config, mut stop_words, mut chinese_to_english) = 
             Self::load_mapping_config(mapping_config_path)?;
         
+        // 叠加配置档的映射层，配置档中的词汇覆盖基础映射
+        let mut abbreviations = HashMap::new();
+        let mut max_length = None;
+        let mut abbreviation_preference = None;
+        let mut acronyms = Vec::new();
+        let mut acronym_policy = None;
+        if let Some(profile) = profile {
+            let layer = profile.mapping_layer();
+            chinese_to_english.extend(layer.mappings.clone());
+            stop_words.extend(layer.stop_words.iter().cloned());
+            
+            let mut config = mapping_config.unwrap_or_default();
+            config.merge(layer);
+            mapping_config = Some(config);
+            
+            abbreviations = profile.abbreviations.clone();
+            max_length = profile.max_length;
+            abbreviation_preference = profile.abbreviation_preference;
+            acronyms = profile.acronyms.clone();
+            acronym_policy = profile.acronym_policy;
+        }
+        
+        // 初始化中文分词器，映射表中的中文词作为离线分词的词典
+        let chinese_tokenizer = Segmenter::new(chinese_to_english.keys());
+        
+        // 映射表中的英文词也视为已知词
+        let lemmatizer = Lemmatizer::new(chinese_to_english.values().cloned());
+        
         Ok(Self {
             chinese_tokenizer,
-            english_stemmer,
+            lemmatizer,
             stop_words,
             chinese_to_english,
             mapping_config,
+            abbreviations,
+            max_length,
+            abbreviation_preference,
+            acronyms,
+            acronym_policy,
         })
     }
     
-    fn load_mapping_config(mapping_config_path: Option<&str>) -> Result<(Option<MappingConfig>, HashSet<String>, HashMap<String, String>), Error> {
+    /// 只加载中英映射表，不初始化分词器（用于命令行补全）
+    pub fn load_mappings(mapping_config_path: Option<&str>) -> Result<HashMap<String, String>, Error> {
+        Ok(Self::load_mapping_config(mapping_config_path)?.2)
+    }
+    
+    fn load_mapping_config(mapping_config_path: Option<&str>) -> Result<MappingTables, Error> {
         if let Some(config_path) = mapping_config_path {
             // 使用自定义配置文件
             let manager = MappingConfigManager::new(config_path)?;
@@ -79,7 +162,7 @@ impl RuleGenerator {
         }
     }
     
-    pub fn generate(&self, description: &str, style: NamingStyle) -> Result<Vec<String>, Error> {
+    pub fn generate(&self, description: &str, options: &GenerateOptions) -> Result<Vec<String>, Error> {
         // 文本预处理
         let processed_text = self.preprocess_text(description);
         
@@ -89,19 +172,96 @@ impl RuleGenerator {
         // 过滤停用词
         let filtered_tokens = self.filter_stop_words(&tokens);
         
+        // 词形还原，单复数由标识符类型决定
+        let normalized_tokens = self.normalize_number(&filtered_tokens, options.kind);
+        
+        // 应用缩写策略
+        let abbreviated_tokens = self
+            .abbreviation_policy(options)
+            .apply(&normalized_tokens, options.style);
+        
         // 根据命名规范转换
-        let variable_name = self.convert_to_style(&filtered_tokens, style)?;
+        let variable_name = self.convert_to_style(&abbreviated_tokens, options)?;
         
         Ok(vec![variable_name])
     }
     
+    /// 离线推荐重命名候选：把拼音或拼音首字母缩写（yhxx）解码为映射词典中的英文，并展开常见缩写
+    pub fn suggest_rename(&self, identifier: &str, options: &GenerateOptions) -> Result<Vec<String>, Error> {
+        let decoder = PinyinDecoder::new(&self.chinese_to_english, &self.chinese_tokenizer);
+        let policy = self.abbreviation_policy(options);
+        let casing = self.casing(options);
+        let words = split_identifier(identifier);
+        
+        // 每个单词的候选写法，第一个为首选
+        let alternatives: Vec<Vec<Vec<String>>> = words
+            .iter()
+            .map(|word| {
+                if self.lemmatizer.is_known(&self.lemmatizer.lemma(word))
+                    || casing.is_acronym(word)
+                    || policy.abbreviate(word).is_some()
+                    || policy.is_standard(word)
+                {
+                    return vec![vec![word.clone()]];
+                }
+                if let Some(full) = policy.expand(word) {
+                    // 偏好缩写时保留缩写
+                    if policy.preference() == AbbreviationPreference::Abbreviated {
+                        return vec![vec![word.clone()]];
+                    }
+                    return vec![vec![full.to_string()]];
+                }
+                let decoded = decoder.decode(word);
+                if decoded.is_empty() {
+                    vec![vec![word.clone()]]
+                } else {
+                    decoded
+                }
+            })
+            .collect();
+        
+        // 首选组合，再依次替换单个单词的其他写法
+        let first: Vec<Vec<String>> = alternatives.iter().map(|a| a[0].clone()).collect();
+        let mut combinations = vec![first.clone()];
+        for (i, choices) in alternatives.iter().enumerate() {
+            for alternative in choices.iter().skip(1) {
+                let mut combination = first.clone();
+                combination[i] = alternative.clone();
+                combinations.push(combination);
+            }
+        }
+        
+        let mut candidates = Vec::new();
+        for combination in combinations {
+            let tokens: Vec<String> = combination
+                .iter()
+                .flatten()
+                .flat_map(|word| split_identifier(word))
+                .collect();
+            if tokens == words {
+                continue;
+            }
+            let candidate = casing.convert(&tokens, options.style);
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+        
+        if candidates.is_empty() {
+            return Err(Error::RuleError(t!(
+                "无法离线推断 `{identifier}` 的含义，请配置大模型API密钥或提供更具体的描述",
+                identifier = identifier
+            )));
+        }
+        
+        Ok(candidates)
+    }
+    
     fn preprocess_text(&self, text: &str) -> String {
-        // 移除特殊字符，保留字母、数字、中文和空格
+        // 把特殊字符替换为空格，保留字母、数字、中文和空格
+        // 不在此处转换大小写，驼峰和缩略词边界由分词阶段识别
         let re = regex::Regex::new(r"[^\p{L}\p{N}\p{Han}\s]").unwrap();
-        let cleaned = re.replace_all(text, "");
-        
-        // 转换为小写
-        cleaned.to_lowercase()
+        re.replace_all(text, " ").to_string()
     }
     
     fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
@@ -112,93 +272,87 @@ impl RuleGenerator {
         
         for part in parts {
             // 检查是否包含中文字符
-            if part.chars().any(|c| is_chinese_char(c)) {
+            if part.chars().any(is_chinese_char) {
                 // 中文分词
-                let chinese_tokens = self.chinese_tokenizer.cut(part, false);
+                let chinese_tokens = self.chinese_tokenizer.cut(part);
                 
                 // 将中文词汇转换为英文
                 for chinese_token in chinese_tokens {
-                    // 优先使用配置文件的映射
-                    let translation = if let Some(config) = &self.mapping_config {
-                        config.get_mapping(chinese_token)
-                            .or_else(|| self.chinese_to_english.get(chinese_token))
-                            .cloned()
-                    } else {
-                        self.chinese_to_english.get(chinese_token).cloned()
-                    };
-                    
-                    if let Some(english_translation) = translation {
-                        // 如果找到英文翻译，使用翻译结果
-                        tokens.push(english_translation);
-                    } else {
-                        // 如果没有找到翻译，尝试将每个中文字符单独翻译
-                        let mut translated_parts = Vec::new();
-                        for ch in chinese_token.chars() {
-                            if is_chinese_char(ch) {
-                                let ch_str = ch.to_string();
-                                
-                                // 优先使用配置文件的映射
-                                let char_translation = if let Some(config) = &self.mapping_config {
-                                    config.get_mapping(&ch_str)
-                                        .or_else(|| self.chinese_to_english.get(&ch_str))
-                                        .cloned()
-                                } else {
-                                    self.chinese_to_english.get(&ch_str).cloned()
-                                };
-                                
-                                if let Some(english) = char_translation {
-                                    translated_parts.push(english);
-                                } else {
-                                    // 如果单个字符也没有翻译，保留原始字符
-                                    translated_parts.push(ch_str);
-                                }
-                            } else {
-                                translated_parts.push(ch.to_string());
-                            }
-                        }
-                        // 将翻译的部分合并
-                        tokens.push(translated_parts.join(""));
+                    // 夹在中文里的英文片段按标识符拆分
+                    if !chinese_token.chars().any(is_chinese_char) {
+                        tokens.extend(split_identifier(chinese_token));
+                        continue;
                     }
+                    
+                    tokens.push(self.translate_token(chinese_token).0);
                 }
             } else {
-                // 英文处理
-                // 检查是否包含连字符
-                if part.contains('-') {
-                    let subparts: Vec<&str> = part.split('-').collect();
-                    tokens.extend(subparts.iter().map(|s| s.to_string()));
-                } else {
-                    // 检查是否包含驼峰命名
-                    let camel_tokens = self.split_camel_case(part);
-                    tokens.extend(camel_tokens);
-                }
+                // 英文处理：识别驼峰、缩略词和数字边界
+                tokens.extend(split_identifier(part));
             }
         }
         
         Ok(tokens)
     }
     
-    fn split_camel_case(&self, text: &str) -> Vec<String> {
-        let mut result = Vec::new();
-        
-        // 使用正则表达式拆分驼峰命名
-        let re = regex::Regex::new(r"([a-z])([A-Z])").unwrap();
-        let converted = re.replace_all(text, "$1 $2");
-        
-        // 转换为小写并按空格分割
-        let lowercased = converted.to_lowercase();
-        let parts: Vec<&str> = lowercased.split_whitespace().collect();
+    /// 查找映射：优先使用配置文件的映射，再查合并后的映射表
+    fn lookup(&self, word: &str) -> Option<String> {
+        match &self.mapping_config {
+            Some(config) => config.get_mapping(word).or_else(|| self.chinese_to_english.get(word)).cloned(),
+            None => self.chinese_to_english.get(word).cloned(),
+        }
+    }
+    
+    /// 翻译一个中文分词结果：整词没有映射时逐字翻译，单个字符也没有映射时保留原字符
+    fn translate_token(&self, token: &str) -> (String, TokenSource) {
+        if let Some(english) = self.lookup(token) {
+            return (english, TokenSource::Mapping);
+        }
         
-        for part in parts {
-            // 只对短单词进行词干提取，保留长单词的完整性
-            if part.len() <= 6 {
-                let stem = self.english_stemmer.stem(part);
-                result.push(stem.to_string());
+        let mut translated = false;
+        let mut parts = Vec::new();
+        for ch in token.chars() {
+            let ch_str = ch.to_string();
+            match self.lookup(&ch_str).filter(|_| is_chinese_char(ch)) {
+                Some(english) => {
+                    translated = true;
+                    parts.push(english);
+                }
+                None => parts.push(ch_str),
+            }
+        }
+        let source = if translated { TokenSource::PerChar } else { TokenSource::Untranslated };
+        (parts.join(""), source)
+    }
+    
+    /// 说明描述中每个词的翻译过程（分词、映射来源、是否为停用词），不做词形和缩写处理
+    pub fn explain(&self, description: &str) -> Vec<TokenMapping> {
+        let mut mappings = Vec::new();
+        for part in self.preprocess_text(description).split_whitespace() {
+            let segments = if part.chars().any(is_chinese_char) {
+                self.chinese_tokenizer.cut(part)
             } else {
-                result.push(part.to_string());
+                vec![part]
+            };
+            for segment in segments {
+                if segment.chars().any(is_chinese_char) {
+                    let (translation, source) = self.translate_token(segment);
+                    mappings.push(TokenMapping::new(segment, translation, source, self));
+                } else {
+                    for word in split_identifier(segment) {
+                        mappings.push(TokenMapping::new(segment, word, TokenSource::English, self));
+                    }
+                }
             }
         }
-        
-        result
+        mappings
+    }
+    
+    fn is_stop_word(&self, token: &str) -> bool {
+        match &self.mapping_config {
+            Some(config) => config.is_stop_word(token),
+            None => self.stop_words.contains(token),
+        }
     }
     
     fn filter_stop_words(&self, tokens: &[String]) -> Vec<String> {
@@ -210,17 +364,12 @@ impl RuleGenerator {
             .iter()
             .filter(|token| {
                 // 如果token包含中文字符，不过滤（保留中文词汇）
-                if token.chars().any(|c| is_chinese_char(c)) {
+                if token.chars().any(is_chinese_char) {
                     return true;
                 }
                 
-                // 优先使用配置文件中的停用词检查
-                if let Some(config) = &self.mapping_config {
-                    !config.is_stop_word(token)
-                } else {
-                    // 回退到内置停用词
-                    !self.stop_words.contains(*token)
-                }
+                // 优先使用配置文件中的停用词，否则使用内置停用词
+                !self.is_stop_word(token)
             })
             .cloned()
             .collect();
@@ -228,7 +377,7 @@ impl RuleGenerator {
         // 如果过滤后没有剩余词汇，但原始有中文词汇，保留第一个中文词汇
         if filtered.is_empty() {
             for token in tokens {
-                if token.chars().any(|c| is_chinese_char(c)) {
+                if token.chars().any(is_chinese_char) {
                     return vec![token.clone()];
                 }
             }
@@ -237,71 +386,68 @@ impl RuleGenerator {
         filtered
     }
     
-    fn convert_to_style(&self, tokens: &[String], style: NamingStyle) -> Result<String, Error> {
-        if tokens.is_empty() {
-            return Err(Error::RuleError("No valid tokens after processing".to_string()));
-        }
-        
-        match style {
-            NamingStyle::Camel => self.to_camel_case(tokens),
-            NamingStyle::Pascal => self.to_pascal_case(tokens),
-            NamingStyle::Snake => self.to_snake_case(tokens),
-            NamingStyle::Kebab => self.to_kebab_case(tokens),
-            NamingStyle::UpperSnake => self.to_upper_snake_case(tokens),
-            NamingStyle::LowerCamel => self.to_camel_case(tokens), // 与 camel 相同
-        }
-    }
-    
-    fn to_camel_case(&self, tokens: &[String]) -> Result<String, Error> {
-        let mut result = String::new();
-        
-        for (i, token) in tokens.iter().enumerate() {
-            if i == 0 {
-                // 第一个词保持小写
-                result.push_str(token);
-            } else {
-                // 后续词首字母大写
-                let capitalized = token.chars()
-                    .enumerate()
-                    .map(|(i, c)| if i == 0 { c.to_ascii_uppercase() } else { c })
-                    .collect::<String>();
-                result.push_str(&capitalized);
-            }
-        }
+    /// 修饰词统一还原为单数（`users table` -> `user_table`），末尾的中心词按标识符类型处理：
+    /// 集合取复数，类型和模块取单数，其他类型保留描述中的单复数
+    fn normalize_number(&self, tokens: &[String], kind: Option<IdentifierKind>) -> Vec<String> {
+        let last = tokens.len().saturating_sub(1);
         
-        Ok(result)
+        tokens
+            .iter()
+            .enumerate()
+            .map(|(i, token)| {
+                if !token.is_ascii() {
+                    return token.clone();
+                }
+                
+                let lemma = self.lemmatizer.lemma(token);
+                if i < last {
+                    return lemma;
+                }
+                
+                match kind {
+                    Some(IdentifierKind::Collection) => self.lemmatizer.pluralize(&lemma),
+                    Some(IdentifierKind::Type) | Some(IdentifierKind::Module) => lemma,
+                    _ => token.clone(),
+                }
+            })
+            .collect()
     }
     
-    fn to_pascal_case(&self, tokens: &[String]) -> Result<String, Error> {
-        let mut result = String::new();
-        
-        for token in tokens {
-            // 每个词首字母大写
-            let capitalized = token.chars()
-                .enumerate()
-                .map(|(i, c)| if i == 0 { c.to_ascii_uppercase() } else { c })
-                .collect::<String>();
-            result.push_str(&capitalized);
-        }
-        
-        Ok(result)
+    /// 当前生效的中英映射表（内置词典、自定义映射和配置档合并后的结果）
+    pub fn mappings(&self) -> &HashMap<String, String> {
+        &self.chinese_to_english
     }
     
-    fn to_snake_case(&self, tokens: &[String]) -> Result<String, Error> {
-        Ok(tokens.join("_"))
+    /// 组合语言默认值、配置档和本次请求的缩写策略（后者优先）
+    pub fn abbreviation_policy(&self, options: &GenerateOptions) -> AbbreviationPolicy {
+        AbbreviationPolicy::for_language(options.language)
+            .with_preferred(&self.abbreviations)
+            .with_max_length(self.max_length)
+            .with_preference(self.abbreviation_preference)
+            .with_max_length(options.max_length)
+            .with_preference(options.abbreviation)
     }
     
-    fn to_kebab_case(&self, tokens: &[String]) -> Result<String, Error> {
-        Ok(tokens.join("-"))
+    /// 组合语言默认值、配置档和本次请求的缩略词策略（后者优先）
+    pub fn casing(&self, options: &GenerateOptions) -> Casing {
+        let policy = options
+            .acronyms
+            .or(self.acronym_policy)
+            .unwrap_or_else(|| AcronymPolicy::for_language(options.language));
+        Casing::new(policy).with_acronyms(&self.acronyms)
     }
     
-    fn to_upper_snake_case(&self, tokens: &[String]) -> Result<String, Error> {
-        Ok(tokens.join("_").to_uppercase())
+    fn convert_to_style(&self, tokens: &[String], options: &GenerateOptions) -> Result<String, Error> {
+        if tokens.is_empty() {
+            return Err(Error::RuleError(t!("处理后没有可用的单词").to_string()));
+        }
+        
+        Ok(self.casing(options).convert(tokens, options.style))
     }
 }
 
 /// 判断字符是否为中文字符
-fn is_chinese_char(c: char) -> bool {
+pub fn is_chinese_char(c: char) -> bool {
     matches!(c, '\u{4e00}'..='\u{9fff}' | '\u{3400}'..='\u{4dbf}' | '\u{20000}'..='\u{2a6df}' | '\u{2a700}'..='\u{2b73f}' | '\u{2b740}'..='\u{2b81f}' | '\u{2b820}'..='\u{2ceaf}' | '\u{2ceb0}'..='\u{2ebef}')
 }
 
@@ -427,6 +573,4 @@ fn load_default_stop_words() -> Result<HashSet<String>, Error> {
 #[allow(dead_code)]
 pub fn mock_rule_generator() -> RuleGenerator {
     RuleGenerator::new().unwrap()
-}
-
-use regex;
\ No newline at end of file
+}
\ No newline at end of file
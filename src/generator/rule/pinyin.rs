@@ -0,0 +1,166 @@
+use pinyin::ToPinyin;
+use std::collections::HashMap;
+
+use super::segment::Segmenter;
+
+/// 最多返回的解码结果数
+const MAX_DECODINGS: usize = 5;
+
+/// 拼音解码器：把拼音（yonghu）或拼音首字母缩写（yh）还原为映射词典中的英文词
+#[derive(Debug, Clone, Default)]
+pub struct PinyinDecoder {
+    /// 全拼 -> 英文候选（按词频降序）
+    full: HashMap<String, Vec<String>>,
+    /// 首字母 -> 英文候选（按词频降序）
+    initials: HashMap<String, Vec<String>>,
+}
+
+/// 单个片段的匹配结果
+#[derive(Debug, Clone)]
+struct Segment {
+    candidates: Vec<String>,
+    by_initials: bool,
+}
+
+impl PinyinDecoder {
+    /// 从中英映射表构建；只收录两个字以上的中文词，单字的首字母过于模糊
+    pub fn new(mappings: &HashMap<String, String>, tokenizer: &Segmenter) -> Self {
+        let mut entries: Vec<(usize, &str, &str)> = mappings
+            .iter()
+            .filter(|(chinese, english)| chinese.chars().count() >= 2 && english.is_ascii())
+            .map(|(chinese, english)| (tokenizer.frequency(chinese), chinese.as_str(), english.as_str()))
+            .collect();
+        // 词频高的优先，词频相同时按中文排序保证结果稳定
+        entries.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(b.1)));
+
+        let mut decoder = Self::default();
+        for (_, chinese, english) in entries {
+            let syllables: Option<Vec<&str>> = chinese.to_pinyin().map(|p| p.map(|p| p.plain())).collect();
+            let Some(syllables) = syllables else {
+                continue;
+            };
+            let english = english.to_lowercase();
+            let full = syllables.concat();
+            let initials: String = syllables.iter().filter_map(|s| s.chars().next()).collect();
+
+            for (table, key) in [(&mut decoder.full, full), (&mut decoder.initials, initials)] {
+                let list = table.entry(key).or_default();
+                if !list.contains(&english) {
+                    list.push(english.clone());
+                }
+            }
+        }
+
+        decoder
+    }
+
+    /// 把小写单词整体解码为英文词序列；片段越少越好，片段数相同时优先全拼匹配
+    ///
+    /// `yhxx` -> [user, info]；`dingdanid` 无法整体解码，返回空
+    pub fn decode(&self, word: &str) -> Vec<Vec<String>> {
+        let word = word.to_lowercase();
+        if !word.chars().all(|c| c.is_ascii_lowercase()) {
+            return Vec::new();
+        }
+
+        let n = word.len();
+        // best[i]：前 i 个字母的最优切分（片段数、首字母片段数、上一个片段的起点与结果）
+        let mut best: Vec<Option<(usize, usize, usize, Segment)>> = vec![None; n + 1];
+        for start in 0..n {
+            if start > 0 && best[start].is_none() {
+                continue;
+            }
+            let (count, initials) = best[start].as_ref().map_or((0, 0), |b| (b.0, b.1));
+            for end in start + 1..=n {
+                let key = &word[start..end];
+                let matched = self
+                    .full
+                    .get(key)
+                    .map(|c| (c, false))
+                    .or_else(|| self.initials.get(key).map(|c| (c, true)));
+                let Some((candidates, by_initials)) = matched else {
+                    continue;
+                };
+
+                let score = (count + 1, initials + by_initials as usize);
+                let better = best[end].as_ref().is_none_or(|b| score < (b.0, b.1));
+                if better {
+                    best[end] = Some((
+                        score.0,
+                        score.1,
+                        start,
+                        Segment { candidates: candidates.clone(), by_initials },
+                    ));
+                }
+            }
+        }
+
+        // 回溯得到片段序列
+        let mut segments = Vec::new();
+        let mut position = n;
+        while position > 0 {
+            let Some((_, _, start, segment)) = &best[position] else {
+                return Vec::new();
+            };
+            segments.push(segment);
+            position = *start;
+        }
+        segments.reverse();
+        if segments.is_empty() {
+            return Vec::new();
+        }
+
+        expand(&segments)
+    }
+}
+
+/// 组合各片段的候选：先取每个片段的首选，再依次替换单个片段的次选
+fn expand(segments: &[&Segment]) -> Vec<Vec<String>> {
+    let first: Vec<String> = segments.iter().map(|s| s.candidates[0].clone()).collect();
+    let mut decodings = vec![first.clone()];
+
+    // 首字母匹配的歧义更大，优先展开
+    let mut order: Vec<usize> = (0..segments.len()).collect();
+    order.sort_by_key(|&i| !segments[i].by_initials);
+
+    for rank in 1..MAX_DECODINGS {
+        for &i in &order {
+            if let Some(candidate) = segments[i].candidates.get(rank) {
+                let mut decoding = first.clone();
+                decoding[i] = candidate.clone();
+                if decodings.len() < MAX_DECODINGS && !decodings.contains(&decoding) {
+                    decodings.push(decoding);
+                }
+            }
+        }
+    }
+
+    decodings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decoder() -> PinyinDecoder {
+        let mappings: HashMap<String, String> = [("用户", "user"), ("信息", "info"), ("订单", "order"), ("名称", "name")]
+            .iter()
+            .map(|(c, e)| (c.to_string(), e.to_string()))
+            .collect();
+        PinyinDecoder::new(&mappings, &Segmenter::new(mappings.keys()))
+    }
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_decode_initials_and_full_pinyin() {
+        let decoder = decoder();
+        assert_eq!(decoder.decode("yhxx"), vec![words(&["user", "info"])]);
+        assert_eq!(decoder.decode("dingdanmc"), vec![words(&["order", "name"])]);
+        assert_eq!(decoder.decode("yonghu"), vec![words(&["user"])]);
+        assert!(decoder.decode("yhx").is_empty());
+        assert!(decoder.decode("data").is_empty());
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(not(feature = "jieba"))]
+use std::collections::HashSet;
+
+#[cfg(not(feature = "jieba"))]
+use super::is_chinese_char;
+
+/// 中文分词器：启用 `jieba` 特性时使用 jieba，否则按映射词典正向最大匹配
+pub struct Segmenter {
+    #[cfg(feature = "jieba")]
+    jieba: jieba_rs::Jieba,
+    #[cfg(not(feature = "jieba"))]
+    words: HashSet<String>,
+    /// 词典中最长词的字数
+    #[cfg(not(feature = "jieba"))]
+    max_chars: usize,
+}
+
+impl std::fmt::Debug for Segmenter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[cfg(feature = "jieba")]
+        return f.write_str("Segmenter(jieba)");
+        #[cfg(not(feature = "jieba"))]
+        return write!(f, "Segmenter(dictionary with {} words)", self.words.len());
+    }
+}
+
+impl Segmenter {
+    /// 用映射词典的中文词构建分词器；使用 jieba 时词典只用于离线匹配的回退
+    #[cfg_attr(feature = "jieba", allow(unused_variables))]
+    pub fn new<'a>(dictionary: impl IntoIterator<Item = &'a String>) -> Self {
+        #[cfg(feature = "jieba")]
+        return Self { jieba: jieba_rs::Jieba::new() };
+
+        #[cfg(not(feature = "jieba"))]
+        {
+            let words: HashSet<String> = dictionary.into_iter().cloned().collect();
+            let max_chars = words.iter().map(|w| w.chars().count()).max().unwrap_or(1);
+            Self { words, max_chars }
+        }
+    }
+
+    /// 切分文本，连续的非中文字符作为一个片段
+    pub fn cut<'t>(&self, text: &'t str) -> Vec<&'t str> {
+        #[cfg(feature = "jieba")]
+        return self.jieba.cut(text, false);
+
+        #[cfg(not(feature = "jieba"))]
+        {
+            let chars: Vec<(usize, char)> = text.char_indices().collect();
+            let offset = |i: usize| chars.get(i).map_or(text.len(), |c| c.0);
+            let mut tokens = Vec::new();
+            let mut i = 0;
+            while i < chars.len() {
+                let start = i;
+                if !is_chinese_char(chars[i].1) {
+                    while i < chars.len() && !is_chinese_char(chars[i].1) {
+                        i += 1;
+                    }
+                } else {
+                    // 从最长的候选开始匹配，都不匹配时取单字
+                    let longest = (1..=self.max_chars.min(chars.len() - i))
+                        .rev()
+                        .find(|&n| self.words.contains(&text[offset(i)..offset(i + n)]))
+                        .unwrap_or(1);
+                    i += longest;
+                }
+                tokens.push(&text[offset(start)..offset(i)]);
+            }
+            tokens
+        }
+    }
+
+    /// 词频，用于给拼音解码的候选排序；不使用 jieba 时都为 0
+    #[cfg_attr(not(feature = "jieba"), allow(unused_variables))]
+    pub fn frequency(&self, word: &str) -> usize {
+        #[cfg(feature = "jieba")]
+        return self.jieba.suggest_freq(word);
+        #[cfg(not(feature = "jieba"))]
+        return 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cut_keeps_dictionary_words() {
+        let dictionary: Vec<String> = ["用户", "信息", "订单"].iter().map(|w| w.to_string()).collect();
+        let segmenter = Segmenter::new(&dictionary);
+        assert_eq!(segmenter.cut("用户信息"), vec!["用户", "信息"]);
+        assert_eq!(segmenter.cut("订单ID"), vec!["订单", "ID"]);
+    }
+}
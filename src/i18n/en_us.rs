@@ -0,0 +1,548 @@
+//! en-US 消息目录：中文原文 → 英文译文，按所在模块分组
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // i18n、main
+    ("未知的界面语言: {name}（可选 zh-CN、en-US）", "Unknown UI language: {name} (valid: zh-CN, en-US)"),
+    ("、", ", "),
+    ("错误", "Error"),
+    ("警告: {message}", "Warning: {message}"),
+
+    // utils::error
+    ("生成错误", "Generator error"),
+    ("大模型错误", "LLM error"),
+    ("规则引擎错误", "Rule engine error"),
+    ("存储错误", "Storage error"),
+    ("网络错误", "Network error"),
+    ("文件读写错误", "IO error"),
+    ("UTF-8 编码错误", "UTF-8 error"),
+    ("JSON 错误", "JSON error"),
+    ("Bincode 错误", "Bincode error"),
+    ("Sled 错误", "Sled error"),
+    ("正则表达式错误", "Regex error"),
+    ("交互输入错误", "Dialoguer error"),
+    ("行编辑错误", "Line editor error"),
+    ("配置错误", "Config error"),
+    ("命名检查错误", "Lint error"),
+    (
+        "{feature} 不可用：编译时未启用 `{flag}` 特性，请使用 `cargo install var-gen --features {flag}` 重新安装",
+        "{feature} is unavailable: the `{flag}` feature was not enabled at build time; reinstall with `cargo install var-gen --features {flag}`",
+    ),
+    ("无效的时长: \"{text}\"（示例: 3600、30m、12h、1d）", "Invalid duration: \"{text}\" (examples: 3600, 30m, 12h, 1d)"),
+
+    // naming
+    ("驼峰命名法", "Camel case"),
+    ("帕斯卡命名法", "Pascal case"),
+    ("下划线命名法", "Snake case"),
+    ("短横线命名法", "Kebab case"),
+    ("大写下划线命名法", "Upper snake case"),
+    ("小驼峰命名法", "Lower camel case"),
+    ("点分命名法", "Dot case"),
+    ("首字母大写短横线命名法", "Train case"),
+    ("全小写连写命名法", "Flat case"),
+    ("大写短横线命名法", "Screaming kebab case"),
+    ("Ada命名法", "Ada case"),
+    ("路径命名法", "Path case"),
+    ("命名空间命名法", "Namespace case"),
+    ("无效的命名规范: \"{name}\"（可选 {names}）", "Invalid naming style: \"{name}\" (valid: {names})"),
+
+    // config
+    ("默认命名规范（如 snake、camel）", "Default naming style (e.g. snake, camel)"),
+    ("默认大模型服务商（qwen、xinghuo）", "Default LLM provider (qwen, xinghuo)"),
+    ("当前服务商使用的模型", "Model used by the current provider"),
+    ("是否缓存大模型结果（true/false）", "Whether to cache LLM results (true/false)"),
+    ("缓存有效期（如 3600、30m、12h、1d）", "Cache lifetime (e.g. 3600, 30m, 12h, 1d)"),
+    ("自定义词汇映射文件路径", "Path of the custom vocabulary mapping file"),
+    ("默认领域配置档", "Default domain profile"),
+    ("界面语言（zh-CN、en-US）", "UI language (zh-CN, en-US)"),
+    ("命令行参数", "command line"),
+    ("环境变量", "environment variable"),
+    ("项目配置 {file}", "project config {file}"),
+    ("用户配置", "user config"),
+    ("配置档 {name}", "profile {name}"),
+    ("{language} 的命名惯例", "{language} conventions"),
+    ("默认值", "default"),
+    (
+        "API 密钥不通过 config set 保存，请使用 var-gen provider set-key 或环境变量",
+        "API keys are not stored with config set; use var-gen provider set-key or an environment variable",
+    ),
+    ("未知的配置项: {key}（可选 {keys}）", "Unknown setting: {key} (valid: {keys})"),
+    (
+        "配置文件 {path} 无效: {error}\n可执行 `var-gen config edit` 修改，或 `var-gen config unset <配置项>` 恢复默认值",
+        "Invalid config file {path}: {error}\nRun `var-gen config edit` to fix it, or `var-gen config unset <setting>` to restore the default",
+    ),
+    ("序列化配置失败: {error}", "Failed to serialize config: {error}"),
+    (
+        "# var-gen 用户配置，可直接编辑；`var-gen config list` 列出所有配置项",
+        "# var-gen user config, safe to edit by hand; `var-gen config list` lists all settings",
+    ),
+    ("环境变量 {name} 无效: {error}", "Invalid environment variable {name}: {error}"),
+    ("缓存有效期必须大于 0", "Cache lifetime must be greater than 0"),
+    ("模型名称不能为空", "Model name must not be empty"),
+    ("无法读取词汇映射文件 {path}: {error}", "Cannot read vocabulary mapping file {path}: {error}"),
+    ("无法确定配置目录", "Failed to get config directory"),
+    ("配置文件 {path} 不是有效的 TOML: {error}", "Config file {path} is not valid TOML: {error}"),
+    ("警告: 存储中的配置项 {key} 无效，未迁移: {error}", "Warning: stored setting {key} is invalid and was not migrated: {error}"),
+    ("已将配置从存储迁移到 {path}", "Migrated config from storage to {path}"),
+    ("无效的布尔值: \"{value}\"（可选 true、false）", "Invalid boolean: \"{value}\" (valid: true, false)"),
+    ("解析映射配置文件失败: {error}", "Failed to parse mapping file: {error}"),
+    ("映射配置文件不存在: {path}", "Mapping file does not exist: {path}"),
+    ("读取映射配置文件失败: {error}", "Failed to read mapping file: {error}"),
+    ("创建配置目录失败: {error}", "Failed to create config directory: {error}"),
+    ("序列化映射配置失败: {error}", "Failed to serialize mappings: {error}"),
+    ("保存映射配置文件失败: {error}", "Failed to save mapping file: {error}"),
+    ("无效的配置文件路径", "Invalid config file path"),
+    ("未找到配置档: {name}", "Profile not found: {name}"),
+    ("读取配置档失败: {error}", "Failed to read profile: {error}"),
+    ("解析配置档失败: {error}", "Failed to parse profile: {error}"),
+    ("读取项目配置失败: {error}", "Failed to read project config: {error}"),
+    ("解析项目配置失败 {path}: {error}", "Failed to parse project config {path}: {error}"),
+
+    // config::provider、config::secret
+    ("阿里云 DashScope 通义千问", "Alibaba Cloud DashScope Qwen"),
+    ("讯飞星火", "iFlytek Spark"),
+    ("通义千问", "Qwen"),
+    ("{credential} 不能为空或包含空白字符", "{credential} must not be empty or contain whitespace"),
+    (
+        "通义千问的 API Key 应以 sk- 开头，请在 DashScope 控制台复制",
+        "A Qwen API Key starts with sk-; copy it from the DashScope console",
+    ),
+    (
+        "讯飞星火的凭证应为控制台中的 APIPassword，或 APIKey:APISecret",
+        "The iFlytek Spark credential is the APIPassword from the console, or APIKey:APISecret",
+    ),
+    ("未知的服务商: {name}（可选 qwen、xinghuo）", "Unknown provider: {name} (valid: qwen, xinghuo)"),
+    ("环境变量 {name}", "environment variable {name}"),
+    ("密钥文件 {path}", "key file {path}"),
+    ("加密存储 {path}", "encrypted store {path}"),
+    ("程序内配置", "set in code"),
+    (
+        "密钥文件 {path} 的权限为 {mode}，其他用户可读，已忽略；请执行 chmod 600",
+        "Key file {path} has mode {mode} and is readable by other users, so it was ignored; run chmod 600",
+    ),
+    ("派生加密密钥失败: {error}", "Failed to derive encryption key: {error}"),
+    ("加密存储格式错误: {error}", "Malformed encrypted store: {error}"),
+    ("加密存储格式错误: nonce 长度不正确", "Malformed encrypted store: wrong nonce length"),
+    ("无法解密 {provider} 的密钥：口令错误或文件已损坏", "Cannot decrypt the {provider} key: wrong passphrase or corrupted file"),
+    ("口令不能为空", "Passphrase must not be empty"),
+    ("加密密钥失败", "Failed to encrypt key"),
+
+    // storage
+    ("无法确定用户目录", "Failed to get home directory"),
+    ("无法解析 {path}: {error}", "Failed to parse {path}: {error}"),
+    ("存储锁已损坏", "Store lock poisoned"),
+    ("无法获取当前时间: {error}", "Failed to get current time: {error}"),
+    ("未知的存储后端: {name}（可选 sled、json、memory）", "Unknown storage backend: {name} (valid: sled, json, memory)"),
+    ("sled 存储", "sled storage"),
+
+    // generator
+    ("{path} 不是目录", "{path} is not a directory"),
+    ("读取上下文文件失败 {path}: {error}", "Failed to read context file {path}: {error}"),
+    ("无效的行范围: {range}", "Invalid line range: {range}"),
+    ("未配置 API 密钥", "API key not configured"),
+    ("响应中没有有效的变量名", "No valid variable names found in response"),
+    ("调用{provider}API:", "Calling {provider} API:"),
+    ("模型: {model}", "Model: {model}"),
+    ("未知错误", "Unknown error"),
+    ("{provider}API请求失败详细信息:", "{provider} API request failed:"),
+    ("状态码: {status}", "Status: {status}"),
+    ("错误响应: {error}", "Response: {error}"),
+    ("使用的{credential}: {key}", "{credential} used: {key}"),
+    (
+        "{provider} 接口请求失败，状态码: {status}，错误: {error}",
+        "{provider} API request failed with status: {status}. Error: {error}",
+    ),
+    ("{provider} 接口返回的格式无效", "Invalid response format from {provider} API"),
+    ("警告: 大模型生成器初始化失败: {error}", "Warning: Failed to initialize LLM generator: {error}"),
+    (
+        "警告: 已配置 API 密钥，但编译时未启用 `llm` 特性，将只使用规则引擎",
+        "Warning: an API key is configured, but the `llm` feature was not enabled at build time; only the rule engine will be used",
+    ),
+    ("警告: 大模型生成失败: {error}", "Warning: LLM generation failed: {error}"),
+    ("警告: 大模型没有返回有效的名称", "Warning: LLM returned no valid names"),
+    ("警告: 大模型推荐名称失败: {error}", "Warning: LLM rename suggestion failed: {error}"),
+    ("警告: 读取缓存失败: {error}", "Warning: Failed to read cache: {error}"),
+    ("警告: 写入缓存失败: {error}", "Warning: Failed to write cache: {error}"),
+    (
+        "警告: {name} 与上下文中已有的名称重复，会遮蔽原有变量",
+        "Warning: {name} is already used in the context and would shadow the existing variable",
+    ),
+    ("不缩写，并展开已知缩写（id、info 等通用缩写保留）", "Never abbreviate, and expand known abbreviations (common ones such as id and info are kept)"),
+    ("尽可能使用词典中的缩写", "Use dictionary abbreviations wherever possible"),
+    ("保留原词，仅在超出长度目标时缩写", "Keep words as they are; abbreviate only when over the length target"),
+    ("缩略词保持全大写：userID、HTTPClient", "Keep acronyms upper case: userID, HTTPClient"),
+    ("缩略词按普通单词处理：userId、HttpClient", "Treat acronyms as ordinary words: userId, HttpClient"),
+    (
+        "无法离线推断 `{identifier}` 的含义，请配置大模型API密钥或提供更具体的描述",
+        "Cannot infer the meaning of `{identifier}` offline; configure an LLM API key or give a more specific description",
+    ),
+    ("处理后没有可用的单词", "No valid tokens after processing"),
+
+    // lint
+    ("标识符不符合该类型要求的命名规范", "Identifier does not follow the naming style required for its kind"),
+    ("标识符包含中文字符", "Identifier contains Chinese characters"),
+    ("标识符使用了拼音", "Identifier uses pinyin"),
+    ("同一单词在项目中混用完整形式和缩写", "The same word appears both in full and abbreviated in the project"),
+    ("标识符与语言的保留字、内置类型或内置函数冲突", "Identifier clashes with a reserved word, builtin type or builtin function"),
+    ("`{name}` 使用原始标识符规避关键字", "`{name}` uses a raw identifier to avoid a keyword"),
+    ("`{name}` 遮蔽了 {language} 的内置名称", "`{name}` shadows a {language} builtin"),
+    ("`{name}` 包含中文字符", "`{name}` contains Chinese characters"),
+    ("`{name}` 包含拼音: {words}", "`{name}` contains pinyin: {words}"),
+    ("{kind} `{name}` 应使用 {styles} 命名", "{kind} `{name}` should be {styles}"),
+    (" 或 ", " or "),
+    (
+        "`{name}` 中的 `{word}` 与项目中更常用的 `{preferred}` 不一致",
+        "`{word}` in `{name}` is inconsistent with the more common `{preferred}` in the project",
+    ),
+    (" (建议: {suggestion})", " (suggestion: {suggestion})"),
+    ("共发现 {issues} 个问题（扫描 {files} 个文件）", "Found {issues} issues ({files} files scanned)"),
+
+    // lsp、server
+    ("不支持的方法: {method}", "Unsupported method: {method}"),
+    ("转换为 {name} ({style})", "Convert to {name} ({style})"),
+    ("从注释生成名称: {name}", "Name from comment: {name}"),
+    ("重命名为建议名称: {name}", "Rename to suggested name: {name}"),
+    ("无效的 Content-Length: {value}", "Invalid Content-Length: {value}"),
+    ("无法监听 {address}: {error}", "Cannot listen on {address}: {error}"),
+    ("var-gen 服务已启动: http://{address}", "var-gen server listening on http://{address}"),
+    ("服务异常退出: {error}", "Server exited unexpectedly: {error}"),
+    ("缺少或无效的访问令牌", "Missing or invalid access token"),
+    ("description 不能为空", "description must not be empty"),
+    ("单次请求最多 {max} 条，实际 {count} 条", "At most {max} items per request, got {count}"),
+    ("生成超时", "Generation timed out"),
+
+    // cli：参数和子命令说明
+    (
+        "退出码：
+  0  成功
+  1  执行失败（如生成失败、lint 发现命名问题）
+  2  命令行参数错误
+  3  配置错误，或编译时未启用所需特性
+  4  大模型服务不可用（网络错误、认证失败）
+  5  存储错误
+  6  文件读写错误",
+        "Exit codes:
+  0  success
+  1  failure (e.g. generation failed, lint found naming issues)
+  2  invalid command-line arguments
+  3  configuration error, or a required feature was not enabled at build time
+  4  LLM service unavailable (network error, authentication failure)
+  5  storage error
+  6  file read/write error",
+    ),
+    ("领域配置档名称（如 commerce、support、streaming）", "Domain profile name (e.g. commerce, support, streaming)"),
+    ("本次使用的大模型服务商（不改变默认设置）", "LLM provider for this run (does not change the default)"),
+    (
+        "本次使用的模型，可推断服务商时一并切换（如 qwen-max、generalv3.5）",
+        "Model for this run; also switches provider when it can be inferred (e.g. qwen-max, generalv3.5)",
+    ),
+    (
+        "本次使用的词汇映射配置文件路径（长期使用请执行 config set mapping_config_path）",
+        "Vocabulary mapping file for this run (use config set mapping_config_path to keep it)",
+    ),
+    (
+        "界面语言（默认按用户配置 ui_lang 或 LANG 环境变量确定）",
+        "UI language (defaults to the ui_lang setting or the LANG environment variable)",
+    ),
+    ("根据描述生成变量名", "Generate identifiers from a description"),
+    ("批量生成：逐行读取文件中的描述", "Batch generation: read one description per line from a file"),
+    ("交互式生成变量名（不带子命令运行时的默认模式）", "Generate identifiers interactively (the default without a subcommand)"),
+    (
+        "全屏终端界面：实时预览候选名称在各命名规范下的写法（输出不是终端时改用交互式模式）",
+        "Full-screen terminal UI with a live preview of candidates in every naming style (falls back to interactive mode when output is not a terminal)",
+    ),
+    ("查看、清除、导入或导出历史记录", "Show, clear, import or export history"),
+    (
+        "在命名规范之间转换已有标识符（不翻译、不做词形处理）",
+        "Convert existing identifiers between naming styles (no translation or lemmatisation)",
+    ),
+    (
+        "检查源代码中的标识符命名（命名规范、拼音、中文、缩写一致性、保留字）",
+        "Check identifier naming in source code (style, pinyin, Chinese, abbreviation consistency, reserved words)",
+    ),
+    (
+        "退出码：0 未发现问题，1 发现命名问题，6 无法读取文件",
+        "Exit codes: 0 no issues, 1 naming issues found, 6 cannot read files",
+    ),
+    (
+        "为命名不佳的已有标识符（如 yhxx、data2、tmp）推荐新名称",
+        "Suggest new names for poorly named identifiers (e.g. yhxx, data2, tmp)",
+    ),
+    ("以 LSP 服务运行（通过标准输入输出与编辑器通信）", "Run as an LSP server (talks to the editor over stdin/stdout)"),
+    ("启动本地 HTTP/JSON 接口服务", "Start the local HTTP/JSON API server"),
+    ("查看和修改配置", "Show and change configuration"),
+    ("管理大模型服务商和 API 密钥", "Manage LLM providers and API keys"),
+    ("管理自定义词汇映射文件", "Manage the custom vocabulary mapping file"),
+    ("列出支持的命名规范", "List supported naming styles"),
+    ("列出可用的领域配置档", "List available domain profiles"),
+    ("查看或清除大模型结果缓存", "Show or clear the LLM result cache"),
+    ("输出 shell 补全脚本", "Print a shell completion script"),
+    (
+        "bash: var-gen completions bash > ~/.local/share/bash-completion/completions/var-gen
+zsh:  var-gen completions zsh > ~/.zfunc/_var-gen（~/.zfunc 需在 fpath 中）
+fish: var-gen completions fish > ~/.config/fish/completions/var-gen.fish",
+        "bash: var-gen completions bash > ~/.local/share/bash-completion/completions/var-gen
+zsh:  var-gen completions zsh > ~/.zfunc/_var-gen (~/.zfunc must be in fpath)
+fish: var-gen completions fish > ~/.config/fish/completions/var-gen.fish",
+    ),
+    ("生成 roff 格式的 man 手册", "Generate man pages in roff format"),
+    (
+        "生成不回调 var-gen 的静态脚本（不能补全配置档、配置项和映射词）",
+        "Generate a static script that does not call back into var-gen (cannot complete profiles, settings or mapping words)",
+    ),
+    (
+        "为主命令和每个子命令各生成一页，写入该目录（如 /usr/local/share/man/man1）；未指定时输出主页面",
+        "Write one page for the main command and each subcommand into this directory (e.g. /usr/local/share/man/man1); prints the main page when omitted",
+    ),
+    (
+        "命名规范风格（未指定时依次取环境变量、项目配置、用户配置、配置档、语言惯例，否则为 snake）",
+        "Naming style (defaults to the environment, project config, user config, profile, language convention, then snake)",
+    ),
+    ("标识符类型（变量、函数、类型等）", "Identifier kind (variable, function, type, ...)"),
+    ("目标编程语言", "Target programming language"),
+    ("标识符最大长度目标，超出时按缩写词典缩短", "Target maximum identifier length; longer names are shortened with the abbreviation dictionary"),
+    (
+        "缩写偏好：full（完整单词）、abbreviated（尽量缩写）、auto（超长时缩写）",
+        "Abbreviation preference: full (whole words), abbreviated (abbreviate where possible), auto (abbreviate when too long)",
+    ),
+    (
+        "缩略词大小写：preserve（userID、HTTPClient）或 capitalize（userId、HttpClient）",
+        "Acronym casing: preserve (userID, HTTPClient) or capitalize (userId, HttpClient)",
+    ),
+    ("强制使用规则引擎（不调用大模型）", "Force the rule engine (do not call the LLM)"),
+    (
+        "代码上下文：path[:行范围]（如 src/main.rs:10-40），`-` 表示从标准输入读取",
+        "Code context: path[:line range] (e.g. src/main.rs:10-40); `-` reads from stdin",
+    ),
+    ("检查候选名称是否与项目目录中已声明的标识符重名", "Check candidates against identifiers already declared in the project directory"),
+    ("以JSON格式输出", "Output JSON"),
+    ("变量描述文本", "Description of the variable"),
+    ("描述文件路径，每行一条描述", "Path of the description file, one description per line"),
+    ("输出文件路径（默认输出到终端）", "Output file path (defaults to the terminal)"),
+    ("显示最近的条数", "Number of recent entries to show"),
+    ("清除所有历史记录", "Clear all history"),
+    ("不询问，直接清除", "Clear without asking"),
+    ("把全部历史记录导出为 JSON 文件", "Export all history to a JSON file"),
+    ("从 JSON 文件导入历史记录（追加到现有记录之后）", "Import history from a JSON file (appended to existing entries)"),
+    (
+        "要转换的标识符；未提供时从 --file 或标准输入读取（每行可包含多个）",
+        "Identifiers to convert; read from --file or stdin when omitted (several per line allowed)",
+    ),
+    ("目标命名规范", "Target naming style"),
+    ("从文件读取标识符", "Read identifiers from a file"),
+    ("缩略词大小写策略（默认沿用输入中的写法）", "Acronym casing policy (defaults to the casing in the input)"),
+    ("目标编程语言，决定默认的缩略词策略", "Target programming language, which decides the default acronym policy"),
+    ("要检查的文件或目录", "File or directory to check"),
+    ("输出格式", "Output format"),
+    ("输出文件路径", "Output file path"),
+    (
+        "只检查指定语言的文件（单个文件无法识别扩展名时也用于指定语言）",
+        "Only check files of this language (also sets the language of a single file with an unknown extension)",
+    ),
+    ("要重命名的标识符", "Identifier to rename"),
+    ("上下文（周围的代码片段或注释）", "Context (surrounding code or comments)"),
+    ("从文件读取代码上下文：path[:行范围]，`-` 表示从标准输入读取", "Read code context from a file: path[:line range]; `-` reads from stdin"),
+    ("命名规范风格（未指定时沿用原标识符的风格）", "Naming style (defaults to the style of the original identifier)"),
+    ("标识符类型", "Identifier kind"),
+    ("强制使用规则引擎（只做拼音缩写解码，不调用大模型）", "Force the rule engine (only decode pinyin abbreviations, do not call the LLM)"),
+    ("监听地址", "Address to listen on"),
+    (
+        "访问令牌（也可通过环境变量 VAR_GEN_SERVER_TOKEN 设置），设置后请求需携带 `Authorization: Bearer <token>`",
+        "Access token (or set VAR_GEN_SERVER_TOKEN); requests must then send `Authorization: Bearer <token>`",
+    ),
+    ("请求体最大字节数", "Maximum request body size in bytes"),
+    ("批量生成、转换时单次请求的最大条数", "Maximum number of items per batch or convert request"),
+    ("同时处理的最大请求数", "Maximum number of concurrent requests"),
+    ("生成超时时间（秒），批量请求整批共用", "Generation timeout in seconds; a batch request shares one timeout"),
+    ("显示当前生效的配置（API 密钥只显示来源和指纹）", "Show the effective configuration (API keys show only source and fingerprint)"),
+    ("读取一个配置项", "Read a setting"),
+    ("校验并保存一个配置项", "Validate and save a setting"),
+    ("删除一个配置项，恢复默认值", "Remove a setting, restoring its default"),
+    ("列出所有配置项及其来源", "List all settings and where they come from"),
+    ("重置所有配置并删除当前服务商的 API 密钥", "Reset all settings and delete the current provider's API key"),
+    ("不询问，直接重置", "Reset without asking"),
+    ("用 $VISUAL 或 $EDITOR 编辑用户配置文件，保存时校验全部配置项", "Edit the user config file with $VISUAL or $EDITOR, validating every setting on save"),
+    ("显示用户配置文件的路径", "Show the path of the user config file"),
+    ("说明每个配置项的生效值来自哪里", "Explain where each setting's effective value comes from"),
+    ("按该语言的惯例确定默认命名规范", "Use this language's conventions for the default naming style"),
+    ("标识符类型，影响语言惯例", "Identifier kind, which affects language conventions"),
+    ("列出各服务商的模型和密钥状态", "List each provider's models and key status"),
+    ("请求各服务商的接口，检查密钥是否可用", "Call each provider's API to check that its key works"),
+    ("退出码：0 全部可用，4 有服务商不可用", "Exit codes: 0 all available, 4 some provider unavailable"),
+    ("只检查指定的服务商", "Only check this provider"),
+    ("切换默认服务商", "Switch the default provider"),
+    ("同时设置该服务商使用的模型", "Also set the model used by this provider"),
+    ("保存当前服务商（或 --provider 指定的服务商）的 API 密钥", "Save the API key of the current provider (or the one given by --provider)"),
+    ("密钥；省略或为 `-` 时从标准输入读取，避免留在命令历史中", "The key; read from stdin when omitted or `-`, keeping it out of shell history"),
+    ("用口令加密保存（口令取自 VAR_GEN_KEY_PASSPHRASE 或交互输入）", "Encrypt with a passphrase (from VAR_GEN_KEY_PASSPHRASE or a prompt)"),
+    ("删除当前服务商（或 --provider 指定的服务商）保存的 API 密钥", "Delete the saved API key of the current provider (or the one given by --provider)"),
+    ("显示当前使用的词汇映射文件及其来源", "Show the vocabulary mapping file in use and where it is set"),
+    (
+        "生成一份可编辑的词汇映射文件（扩展名为 .json 时使用 JSON，否则使用 TOML）",
+        "Create an editable vocabulary mapping file (JSON for a .json extension, TOML otherwise)",
+    ),
+    ("文件路径，默认为 ~/.var-gen/custom-mapping.json", "File path, defaults to ~/.var-gen/custom-mapping.json"),
+    ("覆盖已存在的文件", "Overwrite an existing file"),
+    ("检查词汇映射文件能否解析（默认检查当前使用的文件）", "Check that a vocabulary mapping file parses (defaults to the file in use)"),
+    ("显示缓存是否开启及有效期", "Show whether the cache is enabled and its lifetime"),
+    ("清除所有缓存的大模型结果", "Clear all cached LLM results"),
+
+    // cli：输出
+    ("旧版本的参数（如 -d、--file、--history）不能与子命令同时使用", "Legacy flags (such as -d, --file, --history) cannot be combined with subcommands"),
+    ("var-gen batch <文件>", "var-gen batch <FILE>"),
+    ("var-gen gen <描述>", "var-gen gen <DESCRIPTION>"),
+    ("警告:", "Warning:"),
+    ("{flag} 已弃用，请改用 `{replacement}`", "{flag} is deprecated, use `{replacement}` instead"),
+    ("{shell} 不支持动态补全，请使用 --static", "{shell} does not support dynamic completion, use --static"),
+    ("历史记录已导出到 {path}", "History exported to {path}"),
+    ("已从 {path} 导入历史记录", "History imported from {path}"),
+    ("{value}（来自{source}）", "{value} (from {source})"),
+    ("未设置，使用内置词表", "Not set, using the built-in vocabulary"),
+    ("{path} 已存在，使用 --force 覆盖", "{path} already exists, use --force to overwrite it"),
+    ("已生成词汇映射文件 {path}", "Created vocabulary mapping file {path}"),
+    (
+        "编辑后执行 `var-gen config set mapping_config_path {path}` 启用",
+        "After editing, run `var-gen config set mapping_config_path {path}` to enable it",
+    ),
+    ("未设置词汇映射文件，请指定要检查的文件", "No vocabulary mapping file is set; specify the file to check"),
+    ("{path}: {mappings} 条映射，{stop_words} 个停用词", "{path}: {mappings} mappings, {stop_words} stop words"),
+    ("缓存: {state}（来自{source}）", "Cache: {state} (from {source})"),
+    ("有效期: {ttl}（来自{source}）", "Lifetime: {ttl} (from {source})"),
+    ("确定要清除大模型结果缓存吗？", "Clear the LLM result cache?"),
+    ("已取消", "Cancelled"),
+    ("缓存已清除", "Cache cleared"),
+    ("结果已保存到 {path}", "Results saved to {path}"),
+    ("发现 {count} 个命名问题", "Found {count} naming issues"),
+    ("API密钥不能为空", "API key must not be empty"),
+    ("{provider} 的{credential}已设置: {key}", "{provider} {credential} set: {key}"),
+    ("注意：环境变量 {name} 已设置，会优先于保存的密钥", "Note: environment variable {name} is set and takes precedence over the saved key"),
+    ("加密口令", "Encryption passphrase"),
+    ("再次输入口令", "Repeat passphrase"),
+    ("两次输入的口令不一致", "Passphrases do not match"),
+    ("请通过环境变量 {name} 提供加密口令", "Provide the encryption passphrase through the {name} environment variable"),
+    ("{key} 已恢复默认值: {value}", "{key} restored to default: {value}"),
+    ("{key} 已删除", "{key} removed"),
+    ("{description}（{source}）", "{description} ({source})"),
+    ("重置所有配置并删除当前服务商的 API 密钥？", "Reset all settings and delete the current provider's API key?"),
+    ("配置已重置", "Configuration reset"),
+    (
+        "已保存到用户配置，但当前生效的 {key} = {value}，来自{source}",
+        "Saved to user config, but the effective {key} = {value} comes from {source}",
+    ),
+    ("（未设置）", "(not set)"),
+    ("来自{source}", "from {source}"),
+    ("生成时使用的命名规范: {style}（来自{source}）", "Naming style used for generation: {style} (from {source})"),
+    ("配置未修改", "Configuration unchanged"),
+    ("{error}；修改后的内容保留在 {path}", "{error}; your edits were kept in {path}"),
+    ("配置已保存到 {path}", "Configuration saved to {path}"),
+    ("无法启动编辑器 {editor}: {error}", "Cannot start editor {editor}: {error}"),
+    ("编辑器 {editor} 异常退出，配置未修改", "Editor {editor} exited abnormally; configuration unchanged"),
+    ("服务商: {provider}（{name}）", "Provider: {provider} ({name})"),
+    ("默认命名规范: {style}", "Default naming style: {style}"),
+    ("未设置（按语言惯例，否则为 snake）", "not set (language convention, otherwise snake)"),
+    ("配置档: {profile}", "Profile: {profile}"),
+    ("无", "none"),
+    ("词汇映射文件: {path}", "Vocabulary mapping file: {path}"),
+    ("默认", "default"),
+    ("缓存: {state}（有效期 {ttl}）", "Cache: {state} (lifetime {ttl})"),
+    ("界面语言: {locale}", "UI language: {locale}"),
+    ("开启", "on"),
+    ("关闭", "off"),
+    ("已加密保存，设置环境变量 {name} 后可用", "stored encrypted, available once {name} is set"),
+    (
+        "未设置（可用 var-gen --provider {provider} provider set-key 或环境变量 {name} 设置）",
+        "not set (use var-gen --provider {provider} provider set-key or the {name} environment variable)",
+    ),
+    ("可选模型: {models}", "Models: {models}"),
+    ("模型 {model} 不属于服务商 {provider}", "Model {model} does not belong to provider {provider}"),
+    ("默认服务商已切换为 {provider}（{name}），模型: {model}", "Default provider switched to {provider} ({name}), model: {model}"),
+    ("{provider} 的API密钥已清除", "{provider} API key cleared"),
+    ("{provider}: 未配置{credential}，跳过", "{provider}: no {credential} configured, skipped"),
+    ("{provider}（{model}）: 正常，耗时 {elapsed} ms", "{provider} ({model}): OK in {elapsed} ms"),
+    (
+        "{provider}（{model}）: 认证失败（HTTP {status}），请检查 {credential} {fingerprint}",
+        "{provider} ({model}): authentication failed (HTTP {status}), check {credential} {fingerprint}",
+    ),
+    ("{provider}（{model}）: {reason}", "{provider} ({model}): {reason}"),
+    ("{count} 个服务商不可用", "{count} providers unavailable"),
+    ("服务商检查", "Provider check"),
+    ("HTTP 接口服务", "HTTP API server"),
+    ("交互模式", "Interactive mode"),
+    ("终端界面", "Terminal UI"),
+    ("{identifier} 的建议名称：", "Suggested names for {identifier}:"),
+    ("支持的命名规范：", "Supported naming styles:"),
+    ("可用的领域配置档：", "Available domain profiles:"),
+    ("(当前)", "(current)"),
+    ("暂无历史记录", "No history yet"),
+    ("最近生成的变量名：", "Recently generated names:"),
+    ("(描述: \"{description}\", 风格: {style})", "(description: \"{description}\", style: {style})"),
+    ("确定要清除所有历史记录吗？", "Clear all history?"),
+    ("历史记录已清除", "History cleared"),
+    ("描述: {description}", "Description: {description}"),
+    ("变量名: {names}", "Names: {names}"),
+    ("生成的变量名：", "Generated names:"),
+    ("(已存在: {places})", "(already exists: {places})"),
+    ("可改用: {names}", "Alternatives: {names}"),
+    ("=== var-gen 交互模式 ===", "=== var-gen interactive mode ==="),
+    ("正在生成变量名...", "Generating names..."),
+    ("生成变量名失败：{error}", "Failed to generate names: {error}"),
+    ("读取输入失败：{error}，退出交互模式。", "Failed to read input: {error}, leaving interactive mode."),
+    ("退出交互模式。", "Left interactive mode."),
+
+    // cli::repl
+    (
+        "提示：输入变量描述生成变量名，↑↓ 回溯之前的描述，输入 :help 查看命令，Ctrl-D 退出。",
+        "Tip: enter a description to generate names, use ↑↓ to recall earlier descriptions, :help lists commands, Ctrl-D quits.",
+    ),
+    ("命名规范: {style}（来自{source}）", "Naming style: {style} (from {source})"),
+    ("未设置", "not set"),
+    ("标识符类型: {kind}", "Identifier kind: {kind}"),
+    ("目标语言: {language}", "Target language: {language}"),
+    ("使用规则引擎（不调用大模型）", "Using the rule engine (the LLM is not called)"),
+    ("输入 :pick <序号> 选择并保存到历史记录", "Enter :pick <N> to choose a name and save it to history"),
+    ("用法: :pick <序号>", "Usage: :pick <N>"),
+    ("无效的条数: {value}", "Invalid count: {value}"),
+    ("未知的命令: :{name}，输入 :help 查看可用命令", "Unknown command: :{name}; enter :help for the available commands"),
+    ("无效的取值: {value}（可选 {names}）", "Invalid value: {value} (valid: {names})"),
+    ("还没有可以重新生成的描述", "There is no description to regenerate yet"),
+    ("没有第 {n} 个候选名称（共 {count} 个）", "There is no candidate {n} (only {count})"),
+    ("已选择 {name}，并保存到历史记录", "Picked {name} and saved it to history"),
+    ("还没有生成任何名称", "No names have been generated yet"),
+    ("用法: :copy <文件>（之后可省略文件）", "Usage: :copy <FILE> (the file can be omitted afterwards)"),
+    ("已将 {name} 追加到 {path}", "Appended {name} to {path}"),
+    ("输入变量描述生成候选名称，或使用以下命令：", "Enter a description to generate candidate names, or use these commands:"),
+    (":style <规范>", ":style <STYLE>"),
+    ("设置命名规范，- 恢复默认", "Set the naming style; - restores the default"),
+    (":kind <类型>", ":kind <KIND>"),
+    ("设置标识符类型（如 fn、type），- 清除", "Set the identifier kind (e.g. fn, type); - clears it"),
+    (":lang <语言>", ":lang <LANG>"),
+    ("设置目标语言（如 rust、java），- 清除", "Set the target language (e.g. rust, java); - clears it"),
+    ("按当前设置重新生成上一条描述", "Regenerate the last description with the current settings"),
+    (":pick <序号>", ":pick <N>"),
+    ("选择候选名称并保存到历史记录", "Choose a candidate and save it to history"),
+    (":copy [文件]", ":copy [FILE]"),
+    ("把选中的名称追加到文件", "Append the chosen name to a file"),
+    (":history [条数]", ":history [N]"),
+    ("显示最近的历史记录", "Show recent history"),
+    ("退出（也可按 Ctrl-D）", "Quit (or press Ctrl-D)"),
+    ("警告: 保存输入历史失败: {error}", "Warning: Failed to save input history: {error}"),
+
+    // cli::tui
+    ("标准输入或输出不是终端，改用交互式模式", "Standard input or output is not a terminal, using interactive mode instead"),
+    (" 变量描述 ", " Description "),
+    (" 候选名称 ", " Candidates "),
+    (" 各命名规范预览 ", " Preview in every style "),
+    (" 分词与映射 ", " Tokens and mappings "),
+    (" 历史记录 ", " History "),
+    ("词典映射", "dictionary"),
+    ("逐字翻译", "character by character"),
+    ("未找到映射", "no mapping"),
+    ("英文", "English"),
+    ("停用词，已忽略", "stop word, ignored"),
+    (
+        "Enter 生成  Tab 切换候选  ↑↓ 选择命名规范  Ctrl-S 保存  Esc 退出",
+        "Enter generate  Tab next candidate  ↑↓ choose style  Ctrl-S save  Esc quit",
+    ),
+    ("已生成 {count} 个候选名称", "Generated {count} candidates"),
+    ("已保存 {name}", "Saved {name}"),
+    ("保存历史记录失败：{error}", "Failed to save history: {error}"),
+];
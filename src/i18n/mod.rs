@@ -0,0 +1,265 @@
+//! 界面文字的多语言支持
+//!
+//! 源代码中的界面文字以简体中文书写，并直接作为消息目录的键：`t!("缓存已清除")`
+//! 在 zh-CN 下原样返回，在 en-US 下查找 [`en_us`] 中的译文，缺少译文时回退到中文。
+//! 需要插值的文字使用 `{name}` 形式的具名占位符，译文可以调整占位符的顺序：
+//!
+//! ```
+//! use var_gen::i18n::{self, Locale};
+//!
+//! i18n::set_locale(Locale::EnUs);
+//! assert_eq!(var_gen::t!("缓存已清除"), "Cache cleared");
+//! assert_eq!(var_gen::t!("发现 {count} 个命名问题", count = 3), "Found 3 naming issues");
+//! ```
+//!
+//! 库默认使用 zh-CN，命令行按 `--lang-ui`、`VAR_GEN_UI_LANG`、用户配置 `ui_lang`、
+//! `LC_ALL`/`LC_MESSAGES`/`LANG` 的顺序确定界面语言。大模型的提示词不随界面语言变化。
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+use crate::utils::error::Error;
+
+mod en_us;
+
+/// 翻译界面文字：`t!("中文")` 返回 `&'static str`；带具名参数时替换 `{name}` 占位符并返回 `String`
+#[macro_export]
+macro_rules! t {
+    ($message:literal $(,)?) => {
+        $crate::i18n::translate($message)
+    };
+    ($message:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::fill(
+            $crate::i18n::translate($message),
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "zh-CN")]
+    #[cfg_attr(feature = "cli", clap(name = "zh-CN", alias = "zh"))]
+    ZhCn,
+    #[serde(rename = "en-US")]
+    #[cfg_attr(feature = "cli", clap(name = "en-US", alias = "en"))]
+    EnUs,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::ZhCn, Locale::EnUs];
+
+    /// 配置文件和命令行中使用的名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::ZhCn => "zh-CN",
+            Locale::EnUs => "en-US",
+        }
+    }
+
+    /// 按 POSIX 的优先级读取 `LC_ALL`、`LC_MESSAGES`、`LANG`：中文使用 zh-CN，
+    /// 其他语言使用 en-US；未设置或为 C/POSIX 时返回 None
+    pub fn from_env() -> Option<Locale> {
+        let value = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .find_map(|var| std::env::var(var).ok().filter(|v| !v.trim().is_empty()))?;
+        match language(&value).as_str() {
+            "c" | "posix" => None,
+            "zh" => Some(Locale::ZhCn),
+            _ => Some(Locale::EnUs),
+        }
+    }
+}
+
+/// 区域名称中的语言部分：`en_US.UTF-8` → `en`
+fn language(tag: &str) -> String {
+    tag.trim()
+        .split(['-', '_', '.', '@'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = Error;
+
+    /// 接受 zh-CN、en-US 以及 zh、en_US.UTF-8 这样的写法
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match language(s).as_str() {
+            "zh" => Ok(Locale::ZhCn),
+            "en" => Ok(Locale::EnUs),
+            _ => Err(Error::ConfigError(t!("未知的界面语言: {name}（可选 zh-CN、en-US）", name = s.trim()))),
+        }
+    }
+}
+
+/// 当前界面语言，进程内全局生效
+static LOCALE: AtomicU8 = AtomicU8::new(0);
+
+/// 切换界面语言，之后生成的文字（包括错误信息）使用该语言
+pub fn set_locale(locale: Locale) {
+    LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+/// 当前界面语言
+pub fn locale() -> Locale {
+    match LOCALE.load(Ordering::Relaxed) {
+        1 => Locale::EnUs,
+        _ => Locale::ZhCn,
+    }
+}
+
+/// en-US 的消息目录：中文原文 → 译文
+fn catalogue() -> &'static HashMap<&'static str, &'static str> {
+    static CATALOGUE: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    CATALOGUE.get_or_init(|| en_us::MESSAGES.iter().copied().collect())
+}
+
+/// 查找指定语言的译文，没有译文时返回 None
+pub fn lookup(locale: Locale, message: &str) -> Option<&'static str> {
+    match locale {
+        Locale::ZhCn => None,
+        Locale::EnUs => catalogue().get(message).copied(),
+    }
+}
+
+/// 把中文原文翻译为指定语言，没有译文时返回原文
+pub fn translate_in(locale: Locale, message: &str) -> &str {
+    lookup(locale, message).unwrap_or(message)
+}
+
+/// 把中文原文翻译为当前界面语言
+pub fn translate(message: &str) -> &str {
+    translate_in(locale(), message)
+}
+
+/// 用顿号（英文为逗号）连接可选值等列表
+pub fn list(items: &[&str]) -> String {
+    items.join(t!("、"))
+}
+
+/// 用参数替换文字中的 `{name}` 占位符
+pub fn fill(template: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let tail = &rest[start + 1..];
+        let value = tail
+            .find('}')
+            .and_then(|end| args.iter().find(|(name, _)| *name == &tail[..end]).map(|(_, value)| (end, value)));
+        match value {
+            Some((end, value)) => {
+                result.push_str(&value.to_string());
+                rest = &tail[end + 1..];
+            }
+            None => {
+                result.push('{');
+                rest = tail;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// 源代码中 `t!(` 后的字符串字面量（跳过注释）
+    fn messages(source: &str) -> Vec<String> {
+        let code: Vec<&str> = source.lines().filter(|line| !line.trim_start().starts_with("//")).collect();
+        let pattern = regex::Regex::new(r#"\bt!\(\s*"((?:[^"\\]|\\.)*)""#).unwrap();
+        pattern
+            .captures_iter(&code.join("\n"))
+            .map(|captures| captures[1].replace("\\n", "\n").replace("\\\"", "\"").replace("\\\\", "\\"))
+            .collect()
+    }
+
+    fn placeholders(message: &str) -> BTreeSet<&str> {
+        message
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
+    fn sources(dir: &std::path::Path, files: &mut Vec<std::path::PathBuf>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                sources(&path, files);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                files.push(path);
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_message_has_translation() {
+        let mut files = Vec::new();
+        sources(&std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &mut files);
+        let mut missing = Vec::new();
+        for file in files {
+            for message in messages(&std::fs::read_to_string(&file).unwrap()) {
+                match lookup(Locale::EnUs, &message) {
+                    Some(english) => assert_eq!(
+                        placeholders(&message),
+                        placeholders(english),
+                        "{} 的译文占位符不一致",
+                        message
+                    ),
+                    None => missing.push(format!("{}: {:?}", file.display(), message)),
+                }
+            }
+        }
+        assert!(missing.is_empty(), "缺少英文译文:\n{}", missing.join("\n"));
+
+        let mut seen = BTreeSet::new();
+        for (message, _) in en_us::MESSAGES {
+            assert!(seen.insert(message), "重复的消息: {}", message);
+        }
+    }
+
+    #[test]
+    fn test_translate_and_fill() {
+        assert_eq!(translate_in(Locale::ZhCn, "缓存已清除"), "缓存已清除");
+        assert_eq!(translate_in(Locale::EnUs, "缓存已清除"), "Cache cleared");
+        // 没有译文时回退到原文
+        assert_eq!(translate_in(Locale::EnUs, "不存在的消息"), "不存在的消息");
+
+        let english = translate_in(Locale::EnUs, "模型 {model} 不属于服务商 {provider}");
+        assert_eq!(
+            fill(english, &[("model", &"qwen-max"), ("provider", &"xinghuo")]),
+            "Model qwen-max does not belong to provider xinghuo"
+        );
+        // 未知的占位符和单独的花括号保持原样
+        assert_eq!(fill("{a} {b} {", &[("a", &1)]), "1 {b} {");
+    }
+
+    #[test]
+    fn test_locale_names() {
+        assert_eq!("en_US.UTF-8".parse::<Locale>().unwrap(), Locale::EnUs);
+        assert_eq!("zh-CN".parse::<Locale>().unwrap(), Locale::ZhCn);
+        assert_eq!("ZH".parse::<Locale>().unwrap(), Locale::ZhCn);
+        assert!("fr".parse::<Locale>().is_err());
+        for locale in Locale::ALL {
+            assert_eq!(serde_json::to_string(&locale).unwrap(), format!("\"{}\"", locale));
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! var-gen：根据中英文描述生成符合命名规范的标识符
+//!
+//! 命令行工具之外，也可以作为库嵌入其他 Rust 程序。规则引擎是纯离线的，
+//! 关闭默认特性后不依赖 tokio、reqwest 和 sled：
+//!
+//! ```toml
+//! var-gen = { version = "0.1", default-features = false }
+//! ```
+//!
+//! | 特性 | 内容 |
+//! |------|------|
+//! | `llm` | 大模型生成（reqwest） |
+//! | `sled-storage` | 基于 sled 的历史记录和配置存储 `SledStore`，关闭时使用 [`JsonFileStore`] |
+//! | `jieba` | jieba 中文分词，关闭时按映射词典最大匹配 |
+//! | `cli` | 命令行和 LSP 服务（clap、tokio） |
+//! | `interactive` | 交互模式：行编辑 REPL（rustyline），确认和口令输入（dialoguer） |
+//! | `tui` | 全屏终端界面（ratatui），非终端时回退到交互模式 |
+//! | `server` | HTTP 接口服务（axum） |
+//!
+//! 只使用规则引擎：
+//!
+//! ```
+//! use var_gen::{GenerateOptions, NamingStyle, RuleGenerator};
+//!
+//! let generator = RuleGenerator::new().unwrap();
+//! let options = GenerateOptions::resolve(Some(NamingStyle::Camel), None, None, None);
+//! let names = generator.generate("用户信息", &options).unwrap();
+//! assert_eq!(names[0], "userInfo");
+//! ```
+//!
+//! 使用完整的生成器（配置了 API 密钥且启用 `llm` 特性时优先调用大模型）：
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use var_gen::{Config, GenerateOptions, Generator, NamingStyle};
+//!
+//! # async fn example() -> Result<(), var_gen::Error> {
+//! let config = Config::builder().profile("commerce").build();
+//! let generator = Generator::new(Arc::new(config))?;
+//! let options = GenerateOptions::resolve(Some(NamingStyle::Snake), None, None, None);
+//! let names = generator.generate("订单总价", None, &options, false).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+// 界面文字的翻译宏 `t!`，需在其他模块之前声明
+#[macro_use]
+pub mod i18n;
+
+pub mod config;
+pub mod generator;
+pub mod lint;
+pub mod lsp;
+pub mod naming;
+pub mod storage;
+pub mod utils;
+
+#[cfg(feature = "cli")]
+pub mod cli;
+#[cfg(feature = "server")]
+pub mod server;
+
+pub use config::provider::Provider;
+pub use config::{Config, ConfigBuilder};
+pub use generator::rule::RuleGenerator;
+pub use generator::{GenerateOptions, Generation, Generator};
+pub use i18n::Locale;
+pub use naming::{IdentifierKind, Language, NamingStyle};
+pub use storage::{HistoryEntry, JsonFileStore, MemoryStore, StorageBackend, Store};
+pub use utils::error::Error;
+
+#[cfg(feature = "sled-storage")]
+pub use storage::SledStore;
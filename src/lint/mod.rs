@@ -0,0 +1,512 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::naming::{IdentifierKind, Language, NamingStyle};
+use crate::config::project::LintSettings;
+use crate::generator::rule::abbreviation::{AbbreviationPolicy, AbbreviationPreference};
+use crate::generator::rule::casing::{
+    detect_style, split_identifier, uppercase_acronyms, AcronymPolicy, Casing,
+};
+use crate::generator::rule::is_chinese_char;
+use crate::generator::rule::lemma::Lemmatizer;
+use crate::utils::error::Error;
+
+pub mod pinyin;
+pub mod report;
+pub mod scanner;
+
+use scanner::{builtins, language_for_path, Declaration, Scanner};
+
+/// 扫描目录时跳过的目录
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules", "vendor", "dist", "build", "__pycache__"];
+
+/// 检查规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintRule {
+    NamingStyle,
+    ChineseIdentifier,
+    PinyinIdentifier,
+    InconsistentAbbreviation,
+    ReservedWord,
+}
+
+impl LintRule {
+    pub const ALL: [LintRule; 5] = [
+        LintRule::NamingStyle,
+        LintRule::ChineseIdentifier,
+        LintRule::PinyinIdentifier,
+        LintRule::InconsistentAbbreviation,
+        LintRule::ReservedWord,
+    ];
+
+    /// 规则标识（用于配置中禁用规则和 SARIF 输出）
+    pub fn id(&self) -> &'static str {
+        match self {
+            LintRule::NamingStyle => "naming-style",
+            LintRule::ChineseIdentifier => "chinese-identifier",
+            LintRule::PinyinIdentifier => "pinyin-identifier",
+            LintRule::InconsistentAbbreviation => "inconsistent-abbreviation",
+            LintRule::ReservedWord => "reserved-word",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            LintRule::NamingStyle => t!("标识符不符合该类型要求的命名规范"),
+            LintRule::ChineseIdentifier => t!("标识符包含中文字符"),
+            LintRule::PinyinIdentifier => t!("标识符使用了拼音"),
+            LintRule::InconsistentAbbreviation => t!("同一单词在项目中混用完整形式和缩写"),
+            LintRule::ReservedWord => t!("标识符与语言的保留字、内置类型或内置函数冲突"),
+        }
+    }
+}
+
+/// 单个命名问题
+#[derive(Debug, Clone)]
+pub struct LintIssue {
+    /// 相对于扫描根目录的文件路径
+    pub path: String,
+    pub line: usize,
+    pub column: usize,
+    pub rule: LintRule,
+    pub identifier: String,
+    pub message: String,
+    pub suggestion: Option<String>,
+}
+
+/// 检查结果
+#[derive(Debug, Clone, Default)]
+pub struct LintReport {
+    /// 扫描的文件数
+    pub files: usize,
+    pub issues: Vec<LintIssue>,
+}
+
+/// 某种语言的扫描器与缩写策略
+struct LanguageContext {
+    scanner: Scanner,
+    abbreviations: AbbreviationPolicy,
+}
+
+/// 缩写一致性检查中记录的单词出现位置
+struct WordUse {
+    /// 声明在列表中的下标
+    declaration: usize,
+    /// 单词在标识符中的位置
+    position: usize,
+    word: String,
+    abbreviated: bool,
+}
+
+/// 命名规范检查器
+pub struct Linter {
+    settings: LintSettings,
+    language: Option<Language>,
+    lemmatizer: Lemmatizer,
+    casing: Casing,
+}
+
+impl Linter {
+    /// `language` 指定时只检查该语言的文件
+    pub fn new(settings: LintSettings, language: Option<Language>) -> Self {
+        Self {
+            settings,
+            language,
+            lemmatizer: Lemmatizer::default(),
+            casing: Casing::new(AcronymPolicy::Capitalize),
+        }
+    }
+
+    /// 检查文件或目录
+    pub fn lint_path(&self, path: &Path) -> Result<LintReport, Error> {
+        let files = if path.is_dir() {
+            source_files(path, &self.settings.ignore)?
+        } else {
+            vec![path.to_path_buf()]
+        };
+
+        let mut contexts: HashMap<Language, LanguageContext> = HashMap::new();
+        let mut declarations: Vec<(String, Language, Declaration)> = Vec::new();
+        let mut report = LintReport::default();
+
+        for file in &files {
+            let language = match (language_for_path(file), self.language) {
+                (Some(detected), Some(wanted)) if detected != wanted => continue,
+                (detected, wanted) => match detected.or(wanted) {
+                    Some(language) => language,
+                    None => continue,
+                },
+            };
+            // 跳过非 UTF-8 文件
+            let Ok(source) = String::from_utf8(fs::read(file)?) else {
+                continue;
+            };
+
+            let context = match contexts.entry(language) {
+                std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                std::collections::hash_map::Entry::Vacant(entry) => entry.insert(LanguageContext {
+                    scanner: Scanner::new(language)?,
+                    abbreviations: AbbreviationPolicy::for_language(Some(language)),
+                }),
+            };
+
+            let display = display_path(path, file);
+            report.files += 1;
+            for declaration in context.scanner.scan(&source) {
+                self.check_declaration(&display, language, &declaration, context, &mut report.issues);
+                declarations.push((display.clone(), language, declaration));
+            }
+        }
+
+        if self.enabled(LintRule::InconsistentAbbreviation) {
+            self.check_abbreviations(&declarations, &contexts, &mut report.issues);
+        }
+
+        report
+            .issues
+            .sort_by(|a, b| (&a.path, a.line, a.column).cmp(&(&b.path, b.line, b.column)));
+        Ok(report)
+    }
+
+    fn enabled(&self, rule: LintRule) -> bool {
+        !self.settings.disable.iter().any(|id| id == rule.id())
+    }
+
+    /// 该语言中某类标识符允许的命名规范，配置优先于语言默认值
+    fn allowed_styles(&self, language: Language, kind: IdentifierKind) -> Vec<NamingStyle> {
+        if let Some(style) = self.settings.styles.get(&kind) {
+            return vec![*style];
+        }
+
+        language.conventional_styles(kind).to_vec()
+    }
+
+    /// 检查单个声明（缩写一致性需要全局统计，单独处理）
+    fn check_declaration(
+        &self,
+        path: &str,
+        language: Language,
+        declaration: &Declaration,
+        context: &LanguageContext,
+        issues: &mut Vec<LintIssue>,
+    ) {
+        let name = declaration.name.as_str();
+        let core = core_name(name);
+        let mut issue = |rule: LintRule, message: String, suggestion: Option<String>| {
+            if self.enabled(rule) {
+                issues.push(LintIssue {
+                    path: path.to_string(),
+                    line: declaration.line,
+                    column: declaration.column,
+                    rule,
+                    identifier: name.to_string(),
+                    message,
+                    suggestion,
+                });
+            }
+        };
+
+        if name.starts_with("r#") {
+            issue(
+                LintRule::ReservedWord,
+                t!("`{name}` 使用原始标识符规避关键字", name = name),
+                None,
+            );
+        } else if builtins(language).contains(&name) {
+            // PEP 8 建议在名称后加下划线避免冲突
+            let suggestion = (language == Language::Python).then(|| format!("{}_", name));
+            issue(
+                LintRule::ReservedWord,
+                t!("`{name}` 遮蔽了 {language} 的内置名称", name = name, language = language),
+                suggestion,
+            );
+        }
+
+        if core.chars().any(is_chinese_char) {
+            issue(
+                LintRule::ChineseIdentifier,
+                t!("`{name}` 包含中文字符", name = name),
+                None,
+            );
+            return;
+        }
+
+        let words = split_identifier(core);
+        let pinyin: Vec<&str> = words
+            .iter()
+            .map(|w| w.as_str())
+            .filter(|w| self.is_pinyin(w, &context.abbreviations))
+            .collect();
+        if !pinyin.is_empty() {
+            issue(
+                LintRule::PinyinIdentifier,
+                t!("`{name}` 包含拼音: {words}", name = name, words = pinyin.join(", ")),
+                None,
+            );
+        }
+
+        let allowed = self.allowed_styles(language, declaration.kind);
+        if !core.is_empty() && !allowed.iter().any(|style| conforms(core, *style)) {
+            let expected: Vec<&str> = allowed.iter().map(|s| s.name()).collect();
+            issue(
+                LintRule::NamingStyle,
+                t!(
+                    "{kind} `{name}` 应使用 {styles} 命名",
+                    kind = declaration.kind,
+                    name = name,
+                    styles = expected.join(t!(" 或 "))
+                ),
+                Some(rename(name, &words, allowed[0], language)),
+            );
+        }
+    }
+
+    /// 排除英文单词、缩写和缩略词后判断是否为拼音
+    fn is_pinyin(&self, word: &str, abbreviations: &AbbreviationPolicy) -> bool {
+        !self.lemmatizer.is_known(&self.lemmatizer.lemma(word))
+            && abbreviations.abbreviate(word).is_none()
+            && abbreviations.expand(word).is_none()
+            && !self.casing.is_acronym(word)
+            && pinyin::is_pinyin(word)
+    }
+
+    /// 统计各单词的完整形式与缩写在项目中的使用次数，报告少数派写法
+    fn check_abbreviations(
+        &self,
+        declarations: &[(String, Language, Declaration)],
+        contexts: &HashMap<Language, LanguageContext>,
+        issues: &mut Vec<LintIssue>,
+    ) {
+        // (语言, 缩写) -> 出现位置
+        let mut uses: HashMap<(Language, String), Vec<WordUse>> = HashMap::new();
+        for (index, (_, language, declaration)) in declarations.iter().enumerate() {
+            let policy = &contexts[language].abbreviations;
+            for (position, word) in split_identifier(core_name(&declaration.name)).iter().enumerate() {
+                let (key, abbreviated) = match policy.abbreviate(word) {
+                    Some(short) => (short.to_string(), false),
+                    None if policy.expand(word).is_some() => (word.clone(), true),
+                    None => continue,
+                };
+                // 通用缩写（id、info 等）与完整单词并用不算不一致
+                if policy.is_standard(&key) {
+                    continue;
+                }
+                uses.entry((*language, key)).or_default().push(WordUse {
+                    declaration: index,
+                    position,
+                    word: word.clone(),
+                    abbreviated,
+                });
+            }
+        }
+
+        for ((language, key), list) in &uses {
+            let abbreviated = list.iter().filter(|u| u.abbreviated).count();
+            let full = list.len() - abbreviated;
+            if abbreviated == 0 || full == 0 {
+                continue;
+            }
+
+            let policy = &contexts[language].abbreviations;
+            // 数量相同时按语言偏好决定
+            let keep_abbreviated = abbreviated > full
+                || (abbreviated == full && policy.preference() == AbbreviationPreference::Abbreviated);
+            // 同一缩写可能对应多个完整词（config、configuration），取项目中最常用的
+            let preferred = if keep_abbreviated {
+                key.clone()
+            } else {
+                let mut counts: HashMap<&str, usize> = HashMap::new();
+                for word_use in list.iter().filter(|u| !u.abbreviated) {
+                    *counts.entry(word_use.word.as_str()).or_default() += 1;
+                }
+                counts
+                    .into_iter()
+                    .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+                    .map(|(word, _)| word.to_string())
+                    .unwrap_or_else(|| key.clone())
+            };
+
+            for word_use in list.iter().filter(|u| u.abbreviated != keep_abbreviated) {
+                let (path, _, declaration) = &declarations[word_use.declaration];
+                let mut words = split_identifier(core_name(&declaration.name));
+                words[word_use.position] = preferred.clone();
+
+                let style = detect_style(core_name(&declaration.name))
+                    .unwrap_or_else(|| self.allowed_styles(*language, declaration.kind)[0]);
+                issues.push(LintIssue {
+                    path: path.clone(),
+                    line: declaration.line,
+                    column: declaration.column,
+                    rule: LintRule::InconsistentAbbreviation,
+                    identifier: declaration.name.clone(),
+                    message: t!(
+                        "`{name}` 中的 `{word}` 与项目中更常用的 `{preferred}` 不一致",
+                        name = declaration.name,
+                        word = word_use.word,
+                        preferred = preferred
+                    ),
+                    suggestion: Some(rename(&declaration.name, &words, style, *language)),
+                });
+            }
+        }
+    }
+}
+
+/// 递归收集目录中支持的源文件（按路径排序，跳过隐藏目录、构建产物目录和 `ignore` 中的路径）
+///
+/// `ignore` 中的项匹配相对路径前缀或任一级文件/目录名
+pub fn source_files(root: &Path, ignore: &[String]) -> Result<Vec<PathBuf>, Error> {
+    let mut files = Vec::new();
+    collect_files(root, root, ignore, &mut files)?;
+    Ok(files)
+}
+
+fn collect_files(root: &Path, dir: &Path, ignore: &[String], files: &mut Vec<PathBuf>) -> Result<(), Error> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    entries.sort();
+
+    for entry in entries {
+        let name = entry.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        let relative = entry.strip_prefix(root).unwrap_or(&entry);
+        let ignored = ignore.iter().any(|pattern| {
+            relative.starts_with(pattern)
+                || relative.components().any(|c| c.as_os_str() == pattern.as_str())
+        });
+        if ignored {
+            continue;
+        }
+        if entry.is_dir() {
+            if !name.starts_with('.') && !SKIPPED_DIRS.contains(&name) {
+                collect_files(root, &entry, ignore, files)?;
+            }
+        } else if language_for_path(&entry).is_some() {
+            files.push(entry);
+        }
+    }
+
+    Ok(())
+}
+
+/// 去掉前后缀（_、$、r#）后的名称主体
+fn core_name(name: &str) -> &str {
+    name.trim_start_matches("r#").trim_matches(|c| c == '_' || c == '$')
+}
+
+/// 名称是否符合命名规范（缩略词全大写或按单词处理均可）
+fn conforms(core: &str, style: NamingStyle) -> bool {
+    let words = split_identifier(core);
+    let acronyms = uppercase_acronyms(core);
+    [AcronymPolicy::Preserve, AcronymPolicy::Capitalize]
+        .iter()
+        .any(|policy| Casing::new(*policy).with_acronyms(&acronyms).convert(&words, style) == core)
+}
+
+/// 按命名规范重新拼接单词，保留原名称的前后缀
+fn rename(name: &str, words: &[String], style: NamingStyle, language: Language) -> String {
+    let core = core_name(name);
+    let acronyms = uppercase_acronyms(core);
+    let policy = if acronyms.is_empty() {
+        AcronymPolicy::for_language(Some(language))
+    } else {
+        AcronymPolicy::Preserve
+    };
+    let converted = Casing::new(policy).with_acronyms(&acronyms).convert(words, style);
+
+    match name.find(core) {
+        Some(start) if !core.is_empty() => {
+            let prefix = name[..start].trim_start_matches("r#");
+            format!("{}{}{}", prefix, converted, &name[start + core.len()..])
+        }
+        _ => converted,
+    }
+}
+
+/// 报告中显示的路径：相对于扫描目录，统一使用 `/`
+fn display_path(root: &Path, file: &Path) -> String {
+    let relative = if root.is_dir() {
+        file.strip_prefix(root).unwrap_or(file)
+    } else {
+        file
+    };
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lint(files: &[(&str, &str)], settings: LintSettings) -> Vec<(String, LintRule, Option<String>)> {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, source) in files {
+            fs::write(dir.path().join(name), source).unwrap();
+        }
+        Linter::new(settings, None)
+            .lint_path(dir.path())
+            .unwrap()
+            .issues
+            .into_iter()
+            .map(|i| (i.identifier, i.rule, i.suggestion))
+            .collect()
+    }
+
+    #[test]
+    fn test_naming_style_and_reserved_words() {
+        let issues = lint(
+            &[("app.py", "def getUser(list):\n    pass\n\nclass user_info:\n    pass\n\nlist = []\n")],
+            LintSettings::default(),
+        );
+        assert_eq!(
+            issues,
+            vec![
+                ("getUser".to_string(), LintRule::NamingStyle, Some("get_user".to_string())),
+                ("user_info".to_string(), LintRule::NamingStyle, Some("UserInfo".to_string())),
+                ("list".to_string(), LintRule::ReservedWord, Some("list_".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pinyin_and_chinese() {
+        let issues = lint(
+            &[("main.rs", "fn get_yonghu_xinxi() {}\nfn share_config() {}\nlet 用户 = 1;\n")],
+            LintSettings::default(),
+        );
+        let rules: Vec<_> = issues.iter().map(|(n, r, _)| (n.as_str(), *r)).collect();
+        assert_eq!(
+            rules,
+            vec![
+                ("get_yonghu_xinxi", LintRule::PinyinIdentifier),
+                ("用户", LintRule::ChineseIdentifier),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inconsistent_abbreviation() {
+        let source = "fn load_config() {}\nfn save_config() {}\nfn reload_cfg() {}\n";
+        let issues = lint(&[("lib.rs", source)], LintSettings::default());
+        assert_eq!(
+            issues,
+            vec![(
+                "reload_cfg".to_string(),
+                LintRule::InconsistentAbbreviation,
+                Some("reload_config".to_string())
+            )]
+        );
+    }
+
+    #[test]
+    fn test_settings_override() {
+        let mut settings = LintSettings::default();
+        settings.styles.insert(IdentifierKind::Function, NamingStyle::Camel);
+        settings.disable.push("reserved-word".to_string());
+        let issues = lint(&[("app.py", "def getUser(): pass\ndef save_user(): pass\nlist = []\n")], settings);
+        assert_eq!(
+            issues,
+            vec![("save_user".to_string(), LintRule::NamingStyle, Some("saveUser".to_string()))]
+        );
+    }
+}
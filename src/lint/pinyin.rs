@@ -0,0 +1,97 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashSet;
+
+/// 汉语拼音音节表（不含声调）
+///
+/// 去掉了 a、o、e、ne、me、le 等容易与英文片段混淆的音节，以减少误报
+const SYLLABLES: &str = "
+    ba bo bai bei bao ban ben bang beng bi bie biao bian bin bing bu
+    pa po pai pei pao pou pan pen pang peng pi pie piao pian pin ping pu
+    ma mo mai mei mao mou man men mang meng mi mie miao miu mian min ming mu
+    fa fo fei fou fan fen fang feng fu
+    da de dai dei dao dou dan den dang deng dong di die diao diu dian ding du duo dui duan dun
+    ta te tai tao tou tan tang teng tong ti tie tiao tian ting tu tuo tui tuan tun
+    na nai nei nao nou nan nen nang neng nong ni nie niao niu nian nin niang ning nu nuo nuan nv nve
+    la lai lei lao lou lan lang leng long li lia lie liao liu lian lin liang ling lu luo luan lun lv lve
+    ga ge gai gei gao gou gan gen gang geng gong gu gua guo guai gui guan gun guang
+    ka ke kai kao kou kan ken kang keng kong ku kua kuo kuai kui kuan kun kuang
+    ha he hai hei hao hou han hen hang heng hong hu hua huo huai hui huan hun huang
+    ji jia jie jiao jiu jian jin jiang jing jiong ju jue juan jun
+    qi qia qie qiao qiu qian qin qiang qing qiong qu que quan qun
+    xi xia xie xiao xiu xian xin xiang xing xiong xu xue xuan xun
+    zha zhe zhi zhai zhei zhao zhou zhan zhen zhang zheng zhong zhu zhua zhuo zhuai zhui zhuan zhun zhuang
+    cha che chi chai chao chou chan chen chang cheng chong chu chua chuo chuai chui chuan chun chuang
+    sha she shi shai shei shao shou shan shen shang sheng shu shua shuo shuai shui shuan shun shuang
+    re ri rao rou ran ren rang reng rong ru ruo rui ruan run
+    za ze zi zai zei zao zou zan zen zang zeng zong zu zuo zui zuan zun
+    ca ce ci cai cao cou can cen cang ceng cong cu cuo cui cuan cun
+    sa se si sai sao sou san sen sang seng song su suo sui suan sun
+    ya yao you yan yin yang ying yong yi ye yu yue yuan yun
+    wa wo wai wei wan wen wang weng wu
+    ai ao an en ang er
+";
+
+/// 拼写上可以切分为拼音、但实际是英文的常见词
+const EXCEPTIONS: &[&str] = &["china", "taxi", "sushi", "hangman"];
+
+/// 最长音节长度
+const MAX_SYLLABLE_LEN: usize = 6;
+
+static SYLLABLE_SET: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| SYLLABLES.split_whitespace().collect());
+
+/// 英文中少见、拼音中常见的字母组合
+static MARKER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"zh|x[iu]|q[iu]|j[iu]|sh[iu]|ch[iu]|iao|iu|uo|iang|iong|ian|ao|ng[^aeiouv]")
+        .expect("valid regex")
+});
+
+/// 判断单个小写单词是否为拼音（如 yonghu、mingcheng）
+///
+/// 要求整个单词能切分为至少两个音节，并且包含拼音特有的字母组合
+pub fn is_pinyin(word: &str) -> bool {
+    let word = word.to_lowercase();
+    if word.len() < 4 || !word.chars().all(|c| c.is_ascii_lowercase()) {
+        return false;
+    }
+    if EXCEPTIONS.contains(&word.as_str()) || !MARKER.is_match(&word) {
+        return false;
+    }
+    syllable_count(&word).is_some_and(|n| n >= 2)
+}
+
+/// 把单词切分为拼音音节，返回最少的音节数；无法完整切分时返回 None
+fn syllable_count(word: &str) -> Option<usize> {
+    let n = word.len();
+    // best[i]：前 i 个字母切分所需的最少音节数
+    let mut best: Vec<Option<usize>> = vec![None; n + 1];
+    best[0] = Some(0);
+
+    for end in 1..=n {
+        for start in end.saturating_sub(MAX_SYLLABLE_LEN)..end {
+            if let Some(count) = best[start] {
+                if SYLLABLE_SET.contains(&word[start..end]) {
+                    best[end] = Some(best[end].map_or(count + 1, |b: usize| b.min(count + 1)));
+                }
+            }
+        }
+    }
+
+    best[n]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_pinyin() {
+        for word in ["yonghu", "mingcheng", "dingdan", "xinxi", "zhuangtai", "shuju", "jiage"] {
+            assert!(is_pinyin(word), "{} 应识别为拼音", word);
+        }
+        for word in ["share", "shake", "machine", "chinese", "along", "guide", "manage", "language", "median", "china", "user"] {
+            assert!(!is_pinyin(word), "{} 不应识别为拼音", word);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use serde_json::json;
+
+use super::{LintReport, LintRule};
+use crate::utils::error::Error;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// 文本格式：每个问题一行 `path:line:column: [rule] message`
+pub fn to_text(report: &LintReport) -> String {
+    let mut output = String::new();
+
+    for issue in &report.issues {
+        output.push_str(&format!(
+            "{}:{}:{}: [{}] {}",
+            issue.path,
+            issue.line,
+            issue.column,
+            issue.rule.id(),
+            issue.message
+        ));
+        if let Some(suggestion) = &issue.suggestion {
+            output.push_str(&t!(" (建议: {suggestion})", suggestion = suggestion));
+        }
+        output.push('\n');
+    }
+
+    output.push_str(&t!(
+        "共发现 {issues} 个问题（扫描 {files} 个文件）",
+        issues = report.issues.len(),
+        files = report.files
+    ));
+    output.push('\n');
+    output
+}
+
+/// SARIF 2.1.0 格式，可直接上传到代码扫描平台
+pub fn to_sarif(report: &LintReport) -> Result<String, Error> {
+    let rules: Vec<_> = LintRule::ALL
+        .iter()
+        .map(|rule| {
+            json!({
+                "id": rule.id(),
+                "shortDescription": { "text": rule.description() },
+            })
+        })
+        .collect();
+
+    let results: Vec<_> = report
+        .issues
+        .iter()
+        .map(|issue| {
+            let mut properties = json!({ "identifier": issue.identifier });
+            if let Some(suggestion) = &issue.suggestion {
+                properties["suggestion"] = json!(suggestion);
+            }
+            json!({
+                "ruleId": issue.rule.id(),
+                "level": "warning",
+                "message": { "text": issue.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": issue.path },
+                        "region": {
+                            "startLine": issue.line,
+                            "startColumn": issue.column,
+                        },
+                    },
+                }],
+                "properties": properties,
+            })
+        })
+        .collect();
+
+    let sarif = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "var-gen",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    });
+
+    Ok(serde_json::to_string_pretty(&sarif)?)
+}
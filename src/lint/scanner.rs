@@ -0,0 +1,658 @@
+use regex::Regex;
+use std::path::Path;
+
+use crate::naming::{IdentifierKind, Language};
+use crate::utils::error::Error;
+
+/// 标识符正则（支持 Unicode 标识符与 Rust 原始标识符）
+const IDENT: &str = r"(?:r#)?[\p{L}_$][\p{L}\p{N}_$]*";
+
+/// 从源代码中提取出的声明
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Declaration {
+    pub name: String,
+    pub kind: IdentifierKind,
+    /// 行号（从 1 开始）
+    pub line: usize,
+    /// 列号（从 1 开始，按字符计）
+    pub column: usize,
+}
+
+/// 根据文件扩展名判断编程语言
+pub fn language_for_path(path: &Path) -> Option<Language> {
+    match path.extension().and_then(|s| s.to_str())? {
+        "rs" => Some(Language::Rust),
+        "py" | "pyi" => Some(Language::Python),
+        "js" | "jsx" | "mjs" | "cjs" => Some(Language::JavaScript),
+        "ts" | "tsx" | "mts" | "cts" => Some(Language::TypeScript),
+        "java" => Some(Language::Java),
+        "go" => Some(Language::Go),
+        _ => None,
+    }
+}
+
+/// 语言关键字：扫描时跳过，不会作为声明名称
+pub fn keywords(language: Language) -> &'static [&'static str] {
+    match language {
+        Language::Rust => &[
+            "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+            "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+            "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super",
+            "trait", "true", "type", "unsafe", "use", "where", "while", "abstract", "become",
+            "box", "do", "final", "macro", "override", "priv", "try", "typeof", "unsized",
+            "virtual", "yield",
+        ],
+        Language::Python => &[
+            "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+            "continue", "def", "del", "elif", "else", "except", "finally", "for", "from",
+            "global", "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass",
+            "raise", "return", "try", "while", "with", "yield",
+        ],
+        Language::JavaScript | Language::TypeScript => &[
+            "break", "case", "catch", "class", "const", "continue", "debugger", "default",
+            "delete", "do", "else", "enum", "export", "extends", "false", "finally", "for",
+            "function", "if", "import", "in", "instanceof", "new", "null", "return", "super",
+            "switch", "this", "throw", "true", "try", "typeof", "var", "void", "while", "with",
+            "yield", "let", "static", "implements", "interface", "package", "private",
+            "protected", "public", "await",
+        ],
+        Language::Java => &[
+            "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char", "class",
+            "const", "continue", "default", "do", "double", "else", "enum", "extends", "final",
+            "finally", "float", "for", "goto", "if", "implements", "import", "instanceof", "int",
+            "interface", "long", "native", "new", "package", "private", "protected", "public",
+            "return", "short", "static", "strictfp", "super", "switch", "synchronized", "this",
+            "throw", "throws", "transient", "try", "void", "volatile", "while", "true", "false",
+            "null",
+        ],
+        Language::Go => &[
+            "break", "case", "chan", "const", "continue", "default", "defer", "else",
+            "fallthrough", "for", "func", "go", "goto", "if", "import", "interface", "map",
+            "package", "range", "return", "select", "struct", "switch", "type", "var",
+        ],
+    }
+}
+
+/// 语言的软关键字、内置类型和内置函数：可以声明，但会遮蔽原有含义
+pub fn builtins(language: Language) -> &'static [&'static str] {
+    match language {
+        Language::Rust => &[
+            "union", "Option", "Result", "Some", "None", "Ok", "Err", "Vec", "String", "Box",
+            "str", "bool", "char", "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64",
+            "isize", "f32", "f64",
+        ],
+        Language::Python => &[
+            "match", "case", "type", "abs", "all", "any", "bool", "bytes", "callable", "chr",
+            "dict", "dir", "divmod", "enumerate", "eval", "exec", "filter", "float", "format",
+            "frozenset", "getattr", "hash", "help", "hex", "id", "input", "int", "isinstance",
+            "iter", "len", "list", "map", "max", "min", "next", "object", "oct", "open", "ord",
+            "pow", "print", "property", "range", "repr", "reversed", "round", "set", "slice",
+            "sorted", "str", "sum", "super", "tuple", "vars", "zip",
+        ],
+        Language::JavaScript | Language::TypeScript => &[
+            "arguments", "eval", "undefined", "NaN", "Infinity", "Object", "Array", "String",
+            "Number", "Boolean", "Symbol", "Map", "Set", "Promise", "Error", "JSON", "Math",
+            "Date", "RegExp", "window", "document", "console", "async", "of", "any", "unknown",
+            "never", "string", "number", "boolean", "symbol", "object", "type",
+        ],
+        Language::Java => &[
+            "var", "record", "yield", "sealed", "permits", "String", "Object", "Integer", "Long",
+            "Double", "Boolean", "Character", "Class", "System", "Thread", "Exception", "Math",
+        ],
+        Language::Go => &[
+            "any", "bool", "byte", "comparable", "complex64", "complex128", "error", "float32",
+            "float64", "int", "int8", "int16", "int32", "int64", "rune", "string", "uint",
+            "uint8", "uint16", "uint32", "uint64", "uintptr", "true", "false", "iota", "nil",
+            "append", "cap", "clear", "close", "complex", "copy", "delete", "imag", "len", "make",
+            "max", "min", "new", "panic", "print", "println", "real", "recover",
+        ],
+    }
+}
+
+/// 词法状态（跨行的注释与字符串）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LexState {
+    Code,
+    BlockComment,
+    /// 多行字符串：结束定界符、是否为不处理转义的原始字符串
+    MultiLine(&'static str, bool),
+}
+
+/// 声明规则：正则、标识符类型
+struct Rule {
+    regex: Regex,
+    kind: IdentifierKind,
+}
+
+/// 代码块类型（用于识别结构体字段、类成员等）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Struct,
+    Enum,
+    Class,
+}
+
+/// 轻量级源码扫描器：按行去除注释和字符串后用正则提取声明
+pub struct Scanner {
+    language: Language,
+    rules: Vec<Rule>,
+    /// 代码块开头（struct X {、class X {）
+    block_opener: Regex,
+    /// 代码块内的成员声明
+    members: Vec<(Block, Rule)>,
+    /// Go 的 const ( / var ( / type ( 分组
+    group_opener: Option<Regex>,
+    group_member: Option<Regex>,
+}
+
+impl Scanner {
+    pub fn new(language: Language) -> Result<Self, Error> {
+        let rule = |pattern: &str, kind: IdentifierKind| -> Result<Rule, Error> {
+            Ok(Rule {
+                regex: Regex::new(&pattern.replace("IDENT", IDENT))?,
+                kind,
+            })
+        };
+        use IdentifierKind::*;
+
+        let (rules, block_opener, members) = match language {
+            Language::Rust => (
+                vec![
+                    rule(r"\bfn\s+(IDENT)", Function)?,
+                    rule(r"\b(?:struct|enum|trait|union|type)\s+(IDENT)", Type)?,
+                    rule(r"\b(?:const|static)\s+(?:mut\s+|ref\s+)?(IDENT)\s*:", Constant)?,
+                    rule(r"\bmod\s+(IDENT)", Module)?,
+                    rule(r"\blet\s+(?:mut\s+)?(IDENT)\s*(?:[:=;]|$)", Variable)?,
+                    rule(r"\bfor\s+(IDENT)\s+in\b", Variable)?,
+                ],
+                r"\b(struct|enum|union)\s+IDENT[^;(]*$",
+                vec![
+                    (Block::Struct, rule(r"^\s*(?:pub(?:\([^)]*\))?\s+)?(IDENT)\s*:[^:]", Field)?),
+                    (Block::Enum, rule(r"^\s*(IDENT)\s*(?:[,({=]|$)", Type)?),
+                ],
+            ),
+            Language::Python => (
+                vec![
+                    rule(r"^\s*(?:async\s+)?def\s+(IDENT)", Function)?,
+                    rule(r"^\s*class\s+(IDENT)", Type)?,
+                    rule(r"^(IDENT)\s*(?::[^=]+)?=(?:[^=]|$)", Constant)?,
+                    rule(r"^\s+(IDENT)\s*(?::[^=]+)?=(?:[^=]|$)", Variable)?,
+                    rule(r"\bself\.(IDENT)\s*(?::[^=]+)?=(?:[^=]|$)", Field)?,
+                    rule(r"\bfor\s+(IDENT)\s+in\b", Variable)?,
+                ],
+                // Python 没有花括号代码块
+                r"$^",
+                vec![],
+            ),
+            Language::JavaScript | Language::TypeScript => (
+                vec![
+                    rule(r"\bfunction\s*\*?\s*(IDENT)\s*[<(]", Function)?,
+                    rule(r"\b(?:class|interface|enum)\s+(IDENT)", Type)?,
+                    rule(r"\btype\s+(IDENT)\s*(?:<[^=]*>)?\s*=", Type)?,
+                    rule(
+                        r"\b(?:const|let|var)\s+(IDENT)\s*(?::[^=]+)?=\s*(?:async\s+)?(?:function\b|\([^)]*\)\s*(?::[^=]+)?=>|IDENT\s*=>)",
+                        Function,
+                    )?,
+                    rule(r"\bconst\s+(IDENT)", Constant)?,
+                    rule(r"\b(?:let|var)\s+(IDENT)", Variable)?,
+                ],
+                r"\bclass\s+IDENT[^;]*$",
+                vec![
+                    (
+                        Block::Class,
+                        rule(
+                            r"^\s*(?:(?:public|private|protected|static|async|readonly|override|abstract|get|set)\s+)*#?(IDENT)\s*(?:<[^>]*>)?\s*\(",
+                            Function,
+                        )?,
+                    ),
+                    (
+                        Block::Class,
+                        rule(
+                            r"^\s*(?:(?:public|private|protected|static|readonly|declare|override)\s+)*#?(IDENT)\s*[?!]?\s*[:=;]",
+                            Field,
+                        )?,
+                    ),
+                ],
+            ),
+            Language::Java => (
+                vec![
+                    rule(r"\b(?:class|interface|enum|record)\s+(IDENT)", Type)?,
+                    rule(r"\bfor\s*\(\s*(?:final\s+)?[\w.$]+(?:<[^()]*>)?(?:\[\])*\s+(IDENT)\s*[:=]", Variable)?,
+                ],
+                r"\b(class|interface|enum|record)\s+IDENT[^;]*$",
+                vec![],
+            ),
+            Language::Go => (
+                vec![
+                    rule(r"^func\s+(?:\([^)]*\)\s*)?(IDENT)", Function)?,
+                    rule(r"^\s*type\s+(IDENT)", Type)?,
+                    rule(r"^\s*const\s+(IDENT)", Constant)?,
+                    rule(r"^\s*var\s+(IDENT)", Variable)?,
+                    rule(r"^package\s+(IDENT)", Module)?,
+                ],
+                r"\btype\s+IDENT\s+struct\s*\{[^}]*$",
+                vec![],
+            ),
+        };
+
+        let (group_opener, group_member) = if language == Language::Go {
+            (
+                Some(Regex::new(r"^\s*(const|var|type)\s*\(\s*$")?),
+                Some(Regex::new(&format!(r"^\s*({})\b", IDENT))?),
+            )
+        } else {
+            (None, None)
+        };
+
+        Ok(Self {
+            language,
+            rules,
+            block_opener: Regex::new(&block_opener.replace("IDENT", IDENT))?,
+            members,
+            group_opener,
+            group_member,
+        })
+    }
+
+    /// 扫描源代码，返回声明列表
+    pub fn scan(&self, source: &str) -> Vec<Declaration> {
+        let mut declarations = Vec::new();
+        let mut state = LexState::Code;
+        let mut depth: i32 = 0;
+        // (代码块内部的深度, 代码块类型)
+        let mut blocks: Vec<(i32, Block)> = Vec::new();
+        let mut pending_block: Option<Block> = None;
+        let mut group: Option<IdentifierKind> = None;
+
+        let java_member = Regex::new(&format!(
+            r"^\s*((?:(?:public|private|protected|static|final|transient|volatile|abstract|synchronized|native|default|strictfp)\s+)*)(?:<[^>]+>\s+)?([\w.$]+(?:<[^()]*>)?(?:\[\])*)\s+({})\s*([=;,(]|$)",
+            IDENT
+        ))
+        .expect("valid regex");
+        let go_short = Regex::new(&format!(
+            r"^\s*(?:(?:if|for|switch)\s+)?((?:{0}\s*,\s*)*{0})\s*:=",
+            IDENT
+        ))
+        .expect("valid regex");
+        let go_field = Regex::new(&format!(r"^\s*((?:{0}\s*,\s*)*{0})\s+\S", IDENT)).expect("valid regex");
+
+        for (index, raw_line) in source.lines().enumerate() {
+            let line_no = index + 1;
+            let line = strip_line(self.language, raw_line, &mut state);
+            let current = blocks.last().filter(|(d, _)| *d == depth).map(|(_, b)| *b);
+            let mut found: Vec<(usize, &str, IdentifierKind)> = Vec::new();
+
+            // Go 分组声明
+            if let Some(kind) = group {
+                if line.trim() == ")" {
+                    group = None;
+                } else if let Some(caps) = self.group_member.as_ref().and_then(|r| r.captures(&line)) {
+                    let m = caps.get(1).unwrap();
+                    found.push((m.start(), m.as_str(), kind));
+                }
+            } else if let Some(caps) = self.group_opener.as_ref().and_then(|r| r.captures(&line)) {
+                group = Some(match &caps[1] {
+                    "const" => IdentifierKind::Constant,
+                    "var" => IdentifierKind::Variable,
+                    _ => IdentifierKind::Type,
+                });
+            }
+
+            for rule in &self.rules {
+                for caps in rule.regex.captures_iter(&line) {
+                    let m = caps.get(1).unwrap();
+                    found.push((m.start(), m.as_str(), rule.kind));
+                }
+            }
+
+            for (block, rule) in &self.members {
+                if current == Some(*block) {
+                    if let Some(caps) = rule.regex.captures(&line) {
+                        let m = caps.get(1).unwrap();
+                        found.push((m.start(), m.as_str(), rule.kind));
+                    }
+                }
+            }
+
+            match self.language {
+                Language::Java => {
+                    if let Some(caps) = java_member.captures(&line) {
+                        let modifiers = caps.get(1).map_or("", |m| m.as_str());
+                        let type_name = &caps[2];
+                        let m = caps.get(3).unwrap();
+                        let is_method = &caps[4] == "(";
+                        let in_class = current == Some(Block::Class);
+                        let kind = if is_method {
+                            IdentifierKind::Function
+                        } else if modifiers.contains("static") && modifiers.contains("final") {
+                            IdentifierKind::Constant
+                        } else if in_class {
+                            IdentifierKind::Field
+                        } else {
+                            IdentifierKind::Variable
+                        };
+                        let is_keyword = keywords(self.language).contains(&type_name)
+                            && !is_primitive(type_name);
+                        if !is_keyword && (!is_method || in_class) {
+                            found.push((m.start(), m.as_str(), kind));
+                        }
+                    }
+                }
+                Language::Go => {
+                    if let Some(caps) = go_short.captures(&line) {
+                        push_list(&mut found, caps.get(1).unwrap(), IdentifierKind::Variable);
+                    } else if current == Some(Block::Struct) {
+                        if let Some(caps) = go_field.captures(&line) {
+                            push_list(&mut found, caps.get(1).unwrap(), IdentifierKind::Field);
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            // 同一位置只保留第一条规则的结果
+            found.sort_by_key(|(start, _, _)| *start);
+            found.dedup_by_key(|(start, _, _)| *start);
+            for (start, name, kind) in found {
+                let bare = name.trim_start_matches("r#");
+                if name == "_" || (keywords(self.language).contains(&bare) && !name.starts_with("r#")) {
+                    continue;
+                }
+                // Python 模块级赋值和 JS/TS 的 const 只有全大写时才视为常量
+                let kind = match (self.language, kind) {
+                    (Language::Python | Language::JavaScript | Language::TypeScript, IdentifierKind::Constant)
+                        if name.chars().any(|c| c.is_lowercase()) =>
+                    {
+                        IdentifierKind::Variable
+                    }
+                    _ => kind,
+                };
+                declarations.push(Declaration {
+                    name: name.to_string(),
+                    kind,
+                    line: line_no,
+                    column: line[..start].chars().count() + 1,
+                });
+            }
+
+            // 更新代码块状态
+            if let Some(caps) = self.block_opener.captures(&line) {
+                pending_block = Some(match caps.get(1).map(|m| m.as_str()) {
+                    Some("enum") if self.language == Language::Rust => Block::Enum,
+                    Some("struct") | Some("union") => Block::Struct,
+                    _ if self.language == Language::Go => Block::Struct,
+                    _ => Block::Class,
+                });
+            }
+            for c in line.chars() {
+                match c {
+                    '{' => {
+                        depth += 1;
+                        if let Some(block) = pending_block.take() {
+                            blocks.push((depth, block));
+                        }
+                    }
+                    '}' => {
+                        depth -= 1;
+                        while blocks.last().is_some_and(|(d, _)| *d > depth) {
+                            blocks.pop();
+                        }
+                    }
+                    ';' => pending_block = None,
+                    _ => {}
+                }
+            }
+        }
+
+        declarations
+    }
+}
+
+/// 基本类型和 void（可以作为字段类型或方法返回类型的关键字）
+fn is_primitive(name: &str) -> bool {
+    matches!(name, "boolean" | "byte" | "char" | "double" | "float" | "int" | "long" | "short" | "void")
+}
+
+/// 把 `a, b, c` 形式的标识符列表逐个加入结果
+fn push_list<'a>(found: &mut Vec<(usize, &'a str, IdentifierKind)>, list: regex::Match<'a>, kind: IdentifierKind) {
+    let mut offset = list.start();
+    for part in list.as_str().split(',') {
+        let trimmed = part.trim();
+        let leading = part.len() - part.trim_start().len();
+        if !trimmed.is_empty() {
+            found.push((offset + leading, trimmed, kind));
+        }
+        offset += part.len() + 1;
+    }
+}
+
+/// 把一行中的注释和字符串内容替换为空格，保持列位置不变
+fn strip_line(language: Language, line: &str, state: &mut LexState) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut out = String::with_capacity(line.len());
+    let mut i = 0;
+    let line_comment = if language == Language::Python { "#" } else { "//" };
+    let c_like = language != Language::Python;
+
+    let starts_with = |i: usize, pat: &str| -> bool {
+        pat.chars().enumerate().all(|(k, p)| chars.get(i + k) == Some(&p))
+    };
+    let blank = |out: &mut String, n: usize| out.extend(std::iter::repeat_n(' ', n));
+
+    while i < chars.len() {
+        match *state {
+            LexState::BlockComment => {
+                if starts_with(i, "*/") {
+                    *state = LexState::Code;
+                    blank(&mut out, 2);
+                    i += 2;
+                } else {
+                    blank(&mut out, 1);
+                    i += 1;
+                }
+            }
+            LexState::MultiLine(end, raw) => {
+                if chars[i] == '\\' && !raw {
+                    blank(&mut out, 2.min(chars.len() - i));
+                    i += 2;
+                } else if starts_with(i, end) {
+                    *state = LexState::Code;
+                    let n = end.chars().count();
+                    blank(&mut out, n);
+                    i += n;
+                } else {
+                    blank(&mut out, 1);
+                    i += 1;
+                }
+            }
+            LexState::Code => {
+                let c = chars[i];
+                if starts_with(i, line_comment) {
+                    blank(&mut out, chars.len() - i);
+                    break;
+                } else if c_like && starts_with(i, "/*") {
+                    *state = LexState::BlockComment;
+                    blank(&mut out, 2);
+                    i += 2;
+                } else if language == Language::Python && (starts_with(i, "\"\"\"") || starts_with(i, "'''")) {
+                    *state = LexState::MultiLine(if c == '"' { "\"\"\"" } else { "'''" }, false);
+                    blank(&mut out, 3);
+                    i += 3;
+                } else if c == '`' && matches!(language, Language::JavaScript | Language::TypeScript | Language::Go) {
+                    // Go 的反引号字符串不支持转义
+                    *state = LexState::MultiLine("`", language == Language::Go);
+                    blank(&mut out, 1);
+                    i += 1;
+                } else if let Some((open, end, raw)) = rust_string(language, &chars, i) {
+                    // Rust 的字符串可以跨行
+                    *state = LexState::MultiLine(end, raw);
+                    blank(&mut out, open);
+                    i += open;
+                } else if c == '"' || (c == '\'' && is_quote(language, &chars, i)) {
+                    // 单行字符串：保留引号，清空内容
+                    out.push(c);
+                    i += 1;
+                    while i < chars.len() && chars[i] != c {
+                        let n = if chars[i] == '\\' { 2.min(chars.len() - i) } else { 1 };
+                        blank(&mut out, n);
+                        i += n;
+                    }
+                    if i < chars.len() {
+                        out.push(c);
+                        i += 1;
+                    }
+                } else {
+                    out.push(c);
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// Rust 字符串的开头：返回（开头长度、结束定界符、是否为原始字符串）
+fn rust_string(language: Language, chars: &[char], i: usize) -> Option<(usize, &'static str, bool)> {
+    if language != Language::Rust {
+        return None;
+    }
+    // 前一个字符属于标识符时（如 br"..."、变量名以 r 结尾）交给后续字符处理
+    let prev_ident = i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == '_');
+    match chars[i] {
+        '"' => Some((1, "\"", false)),
+        'r' if !prev_ident || chars[i - 1] == 'b' => {
+            let hashes = chars[i + 1..].iter().take_while(|&&c| c == '#').count();
+            if chars.get(i + 1 + hashes) != Some(&'"') {
+                return None;
+            }
+            let end = match hashes {
+                0 => "\"",
+                1 => "\"#",
+                2 => "\"##",
+                _ => "\"###",
+            };
+            Some((hashes + 2, end, true))
+        }
+        _ => None,
+    }
+}
+
+/// 单引号是否开始字符/字符串字面量（Rust 中排除生命周期 'a）
+fn is_quote(language: Language, chars: &[char], i: usize) -> bool {
+    if language != Language::Rust {
+        return true;
+    }
+    chars.get(i + 1) == Some(&'\\') || chars.get(i + 2) == Some(&'\'')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(language: Language, source: &str) -> Vec<(String, IdentifierKind)> {
+        Scanner::new(language)
+            .unwrap()
+            .scan(source)
+            .into_iter()
+            .map(|d| (d.name, d.kind))
+            .collect()
+    }
+
+    fn names(list: &[(&str, IdentifierKind)]) -> Vec<(String, IdentifierKind)> {
+        list.iter().map(|(n, k)| (n.to_string(), *k)).collect()
+    }
+
+    #[test]
+    fn test_scan_rust() {
+        use IdentifierKind::*;
+        let source = r#"
+const MAX_SIZE: usize = 10;
+pub struct UserInfo {
+    pub userName: String, // fn ignored()
+    age: u32,
+}
+fn getUser<'a>(x: &'a str) -> &'a str {
+    let mut count = "let fake = 1";
+    let raw = r"
+fn fake() {}
+";
+    x
+}
+"#;
+        assert_eq!(
+            scan(Language::Rust, source),
+            names(&[
+                ("MAX_SIZE", Constant),
+                ("UserInfo", Type),
+                ("userName", Field),
+                ("age", Field),
+                ("getUser", Function),
+                ("count", Variable),
+                ("raw", Variable),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_scan_python() {
+        use IdentifierKind::*;
+        let source = "MAX_RETRY = 3\nclass user_info:\n    def __init__(self):\n        self.userName = ''\n        \"\"\"\n        x = 1\n        \"\"\"\n        list = []\n";
+        assert_eq!(
+            scan(Language::Python, source),
+            names(&[
+                ("MAX_RETRY", Constant),
+                ("user_info", Type),
+                ("__init__", Function),
+                ("userName", Field),
+                ("list", Variable),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_scan_java_and_go() {
+        use IdentifierKind::*;
+        let java = "public class OrderService {\n    private static final int maxCount = 1;\n    private String user_name;\n    public void Save(int a) {\n        int total_count = 0;\n        return helper(a);\n    }\n}\n";
+        assert_eq!(
+            scan(Language::Java, java),
+            names(&[
+                ("OrderService", Type),
+                ("maxCount", Constant),
+                ("user_name", Field),
+                ("Save", Function),
+                ("total_count", Variable),
+            ])
+        );
+
+        let go = "package main\n\ntype User struct {\n\tuser_name string\n}\n\nconst (\n\tMaxSize = 1\n)\n\nfunc (u *User) get_name() string {\n\tn, err := load()\n\treturn n\n}\n";
+        assert_eq!(
+            scan(Language::Go, go),
+            names(&[
+                ("main", Module),
+                ("User", Type),
+                ("user_name", Field),
+                ("MaxSize", Constant),
+                ("get_name", Function),
+                ("n", Variable),
+                ("err", Variable),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_scan_typescript() {
+        use IdentifierKind::*;
+        let source = "export const fetch_user = async (id: string) => {};\nconst API_URL = `http://x`;\nclass userStore {\n  private cache_map = new Map();\n  loadAll() {}\n}\n";
+        assert_eq!(
+            scan(Language::TypeScript, source),
+            names(&[
+                ("fetch_user", Function),
+                ("API_URL", Constant),
+                ("userStore", Type),
+                ("cache_map", Field),
+                ("loadAll", Function),
+            ])
+        );
+    }
+}
@@ -0,0 +1,459 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use crate::naming::{Language, NamingStyle};
+use crate::generator::context::CodeContext;
+use crate::generator::rule::casing::{convert_identifier, detect_style};
+use crate::generator::{GenerateOptions, Generator};
+use crate::lint::scanner::language_for_path;
+use crate::utils::error::Error;
+
+pub mod transport;
+
+/// 补全触发注释：`// name: 描述`（Python 为 `# name: 描述`）
+static TRIGGER: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?://|#)\s*name:\s*(\S.*?)\s*$").unwrap());
+
+/// “转换命名规范”提供的目标规范（只包含在各语言中都是合法标识符的规范）
+const CONVERT_STYLES: [NamingStyle; 4] = [
+    NamingStyle::Camel,
+    NamingStyle::Pascal,
+    NamingStyle::Snake,
+    NamingStyle::UpperSnake,
+];
+
+/// 每类代码操作最多给出的候选数
+const MAX_CANDIDATES: usize = 3;
+
+/// 作为生成上下文的光标前后行数
+const CONTEXT_LINES: usize = 20;
+
+/// JSON-RPC 错误码
+const METHOD_NOT_FOUND: i64 = -32601;
+const INTERNAL_ERROR: i64 = -32603;
+
+/// CodeActionTriggerKind.Automatic：编辑器在光标移动时自动请求
+const TRIGGER_AUTOMATIC: u64 = 2;
+
+/// 编辑器中打开的文档
+#[derive(Debug, Clone)]
+struct Document {
+    text: String,
+    language: Option<Language>,
+}
+
+/// 通过标准输入输出与编辑器通信的 LSP 服务
+pub struct Server<'a> {
+    generator: &'a Generator,
+    force_rule: bool,
+    documents: HashMap<String, Document>,
+    /// 生成结果缓存，避免同一注释或标识符重复调用大模型
+    cache: HashMap<String, Vec<String>>,
+    exited: bool,
+}
+
+/// 在标准输入输出上运行 LSP 服务，直到收到 exit 或输入结束
+pub async fn run(generator: &Generator, force_rule: bool) -> Result<(), Error> {
+    let stdin = std::io::stdin();
+    let mut server = Server::new(generator, force_rule);
+    server.serve(&mut stdin.lock(), &mut std::io::stdout()).await
+}
+
+impl<'a> Server<'a> {
+    pub fn new(generator: &'a Generator, force_rule: bool) -> Self {
+        Self {
+            generator,
+            force_rule,
+            documents: HashMap::new(),
+            cache: HashMap::new(),
+            exited: false,
+        }
+    }
+
+    pub async fn serve<R: BufRead, W: Write>(&mut self, reader: &mut R, writer: &mut W) -> Result<(), Error> {
+        while let Some(message) = transport::read_message(reader)? {
+            if let Some(response) = self.handle(message).await {
+                transport::write_message(writer, &response)?;
+            }
+            if self.exited {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// 处理一条消息；请求返回响应，通知返回 None
+    async fn handle(&mut self, message: Value) -> Option<Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+
+        let result = match method {
+            "initialize" => Ok(capabilities()),
+            "shutdown" => Ok(Value::Null),
+            "exit" => {
+                self.exited = true;
+                return None;
+            }
+            "textDocument/didOpen" => {
+                self.open(params);
+                return None;
+            }
+            "textDocument/didChange" => {
+                self.change(params);
+                return None;
+            }
+            "textDocument/didClose" => {
+                if let Some(uri) = params["textDocument"]["uri"].as_str() {
+                    self.documents.remove(uri);
+                }
+                return None;
+            }
+            "textDocument/codeAction" => self.code_actions(params).await,
+            "textDocument/completion" => self.completion(params).await,
+            _ => {
+                // 未知的通知直接忽略
+                let id = message.get("id")?;
+                return Some(error_response(id, METHOD_NOT_FOUND, t!("不支持的方法: {method}", method = method)));
+            }
+        };
+
+        let id = message.get("id")?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(e) => error_response(id, INTERNAL_ERROR, e.to_string()),
+        })
+    }
+
+    fn open(&mut self, params: &Value) {
+        let item = &params["textDocument"];
+        let Some(uri) = item["uri"].as_str() else {
+            return;
+        };
+        let language = item["languageId"]
+            .as_str()
+            .and_then(language_for_id)
+            .or_else(|| language_for_path(Path::new(uri)));
+        let text = item["text"].as_str().unwrap_or_default().to_string();
+        self.documents.insert(uri.to_string(), Document { text, language });
+    }
+
+    /// 只支持全量同步：取最后一次变更的完整文本
+    fn change(&mut self, params: &Value) {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let text = params["contentChanges"]
+            .as_array()
+            .and_then(|changes| changes.last())
+            .and_then(|change| change["text"].as_str());
+        if let (Some(document), Some(text)) = (self.documents.get_mut(uri), text) {
+            document.text = text.to_string();
+        }
+    }
+
+    async fn code_actions(&mut self, params: &Value) -> Result<Value, Error> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(document) = self.documents.get(uri).cloned() else {
+            return Ok(json!([]));
+        };
+        let (line_number, column) = position(&params["range"]["start"]);
+        let Some(line) = document.text.lines().nth(line_number) else {
+            return Ok(json!([]));
+        };
+        let cursor = utf16_to_byte(line, column);
+        let word = word_at(line, cursor);
+        let context = context_around(&document, line_number)?;
+
+        let mut actions = Vec::new();
+
+        // 转换命名规范：不需要生成器，总是提供；与重命名一样替换文档中所有同名标识符
+        if let Some((start, end)) = word {
+            let identifier = &line[start..end];
+            for style in CONVERT_STYLES {
+                let converted = convert_identifier(identifier, style, None, document.language).output;
+                if converted != identifier {
+                    let edits = rename_edits(&document.text, identifier, &converted)?;
+                    actions.push(code_action(&t!("转换为 {name} ({style})", name = converted, style = style.name()), uri, edits));
+                }
+            }
+        }
+
+        // 自动触发时不调用生成器，避免光标每次移动都请求大模型
+        if params["context"]["triggerKind"].as_u64() == Some(TRIGGER_AUTOMATIC) {
+            return Ok(Value::Array(actions));
+        }
+
+        // 从注释生成名称：替换光标处的标识符，没有标识符时插入到光标处
+        if let Some(comment) = comment_for(&document.text, line_number, document.language) {
+            let options = self.generator.resolve_options(context.dominant_style(None), None, document.language);
+            let (start, end) = word.unwrap_or((cursor, cursor));
+            for name in self.generate(&comment, &context, &options).await.into_iter().take(MAX_CANDIDATES) {
+                let edit = text_edit(line_number, line, start, end, &name);
+                actions.push(code_action(&t!("从注释生成名称: {name}", name = name), uri, vec![edit]));
+            }
+        }
+
+        // 重命名为建议名称：替换文档中所有同名标识符
+        if let Some((start, end)) = word {
+            let identifier = &line[start..end];
+            let options = self.generator.resolve_options(detect_style(identifier), None, document.language);
+            for name in self.suggest(identifier, &context, &options).await.into_iter().take(MAX_CANDIDATES) {
+                let edits = rename_edits(&document.text, identifier, &name)?;
+                actions.push(code_action(&t!("重命名为建议名称: {name}", name = name), uri, edits));
+            }
+        }
+
+        Ok(Value::Array(actions))
+    }
+
+    /// 在 `// name: 描述` 注释后补全生成的名称，选中后替换整条注释
+    async fn completion(&mut self, params: &Value) -> Result<Value, Error> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(document) = self.documents.get(uri).cloned() else {
+            return Ok(json!([]));
+        };
+        let (line_number, column) = position(&params["position"]);
+        let Some(line) = document.text.lines().nth(line_number) else {
+            return Ok(json!([]));
+        };
+        let prefix = &line[..utf16_to_byte(line, column)];
+        let Some(captures) = TRIGGER.captures(prefix) else {
+            return Ok(json!([]));
+        };
+        let (start, description) = (captures.get(0).map_or(0, |m| m.start()), &captures[1]);
+
+        let context = context_around(&document, line_number)?;
+        let options = self.generator.resolve_options(context.dominant_style(None), None, document.language);
+        let names = self.generate(description, &context, &options).await;
+
+        let items: Vec<Value> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                json!({
+                    "label": name,
+                    "kind": 6,
+                    "detail": format!("var-gen: {}", description),
+                    "sortText": format!("{:02}", i),
+                    // 让编辑器按整条注释过滤，否则候选会被当前输入的单词过滤掉
+                    "filterText": &prefix[start..],
+                    "textEdit": text_edit(line_number, line, start, prefix.len(), name),
+                })
+            })
+            .collect();
+
+        Ok(json!({ "isIncomplete": false, "items": items }))
+    }
+
+    async fn generate(&mut self, description: &str, context: &CodeContext, options: &GenerateOptions) -> Vec<String> {
+        let key = format!("generate:{}:{}", options.style.name(), description);
+        if let Some(names) = self.cache.get(&key) {
+            return names.clone();
+        }
+        match self.generator.generate(description, Some(context), options, self.force_rule).await {
+            Ok(names) => {
+                self.cache.insert(key, names.clone());
+                names
+            }
+            Err(e) => {
+                eprintln!("{}", t!("警告: {message}", message = e));
+                Vec::new()
+            }
+        }
+    }
+
+    async fn suggest(&mut self, identifier: &str, context: &CodeContext, options: &GenerateOptions) -> Vec<String> {
+        let key = format!("rename:{}", identifier);
+        if let Some(names) = self.cache.get(&key) {
+            return names.clone();
+        }
+        // 无法离线推断（名称本身没有问题）时不提供重命名
+        let names = self
+            .generator
+            .suggest_rename(identifier, Some(&context.snippet), options, self.force_rule)
+            .await
+            .unwrap_or_default();
+        self.cache.insert(key, names.clone());
+        names
+    }
+}
+
+fn capabilities() -> Value {
+    json!({
+        "capabilities": {
+            // 全量同步
+            "textDocumentSync": 1,
+            "codeActionProvider": { "codeActionKinds": ["refactor.rewrite"] },
+            "completionProvider": { "triggerCharacters": [":", " "] },
+        },
+        "serverInfo": { "name": "var-gen", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+fn error_response(id: &Value, code: i64, message: String) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+fn code_action(title: &str, uri: &str, edits: Vec<Value>) -> Value {
+    json!({
+        "title": title,
+        "kind": "refactor.rewrite",
+        "edit": { "changes": { uri: edits } },
+    })
+}
+
+/// 把行内的字节范围转换为 LSP 的 TextEdit（列号按 UTF-16 计算）
+fn text_edit(line_number: usize, line: &str, start: usize, end: usize, new_text: &str) -> Value {
+    json!({
+        "range": {
+            "start": { "line": line_number, "character": byte_to_utf16(line, start) },
+            "end": { "line": line_number, "character": byte_to_utf16(line, end) },
+        },
+        "newText": new_text,
+    })
+}
+
+fn position(value: &Value) -> (usize, usize) {
+    let get = |key: &str| value[key].as_u64().unwrap_or_default() as usize;
+    (get("line"), get("character"))
+}
+
+fn language_for_id(id: &str) -> Option<Language> {
+    match id {
+        "rust" => Some(Language::Rust),
+        "python" => Some(Language::Python),
+        "javascript" | "javascriptreact" => Some(Language::JavaScript),
+        "typescript" | "typescriptreact" => Some(Language::TypeScript),
+        "java" => Some(Language::Java),
+        "go" => Some(Language::Go),
+        _ => None,
+    }
+}
+
+/// UTF-16 列号转换为字节偏移，超出行尾时取行尾
+fn utf16_to_byte(line: &str, column: usize) -> usize {
+    let mut units = 0;
+    for (offset, c) in line.char_indices() {
+        if units >= column {
+            return offset;
+        }
+        units += c.len_utf16();
+    }
+    line.len()
+}
+
+fn byte_to_utf16(line: &str, offset: usize) -> usize {
+    line[..offset].chars().map(char::len_utf16).sum()
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// 光标所在（或紧邻光标左侧）的标识符的字节范围
+fn word_at(line: &str, cursor: usize) -> Option<(usize, usize)> {
+    let start = line[..cursor]
+        .rfind(|c: char| !is_identifier_char(c))
+        .map_or(0, |i| i + 1);
+    let end = line[cursor..]
+        .find(|c: char| !is_identifier_char(c))
+        .map_or(line.len(), |i| cursor + i);
+    let word = &line[start..end];
+    if word.is_empty() || word.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((start, end))
+}
+
+/// 行注释的起始位置；未知语言时两种注释符都尝试
+fn comment_start(line: &str, language: Option<Language>) -> Option<usize> {
+    match language {
+        Some(Language::Python) => line.find('#'),
+        Some(_) => line.find("//"),
+        None => line.find("//").or_else(|| line.find('#')),
+    }
+}
+
+/// 命名依据的注释：当前行的行尾注释，否则为上一行的整行注释
+fn comment_for(text: &str, line_number: usize, language: Option<Language>) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let comment = |line: &str| {
+        let start = comment_start(line, language)?;
+        let comment = line[start..].trim_start_matches(['/', '#']).trim();
+        let comment = comment.strip_prefix("name:").unwrap_or(comment).trim();
+        (!comment.is_empty()).then(|| comment.to_string())
+    };
+
+    let line = lines.get(line_number)?;
+    if let Some(comment) = comment(line) {
+        return Some(comment);
+    }
+    let previous = lines.get(line_number.checked_sub(1)?)?;
+    let start = comment_start(previous, language)?;
+    if previous[..start].trim().is_empty() {
+        comment(previous)
+    } else {
+        None
+    }
+}
+
+/// 光标前后若干行作为生成上下文
+fn context_around(document: &Document, line_number: usize) -> Result<CodeContext, Error> {
+    let lines: Vec<&str> = document.text.lines().collect();
+    let start = line_number.saturating_sub(CONTEXT_LINES);
+    let end = (line_number + CONTEXT_LINES + 1).min(lines.len());
+    CodeContext::from_snippet(&lines[start.min(end)..end].join("\n"), document.language)
+}
+
+/// 把文档中所有同名标识符替换为新名称
+fn rename_edits(text: &str, identifier: &str, new_name: &str) -> Result<Vec<Value>, Error> {
+    Ok(occurrences(text, identifier)?
+        .into_iter()
+        .map(|(n, line, start, end)| text_edit(n, line, start, end, new_name))
+        .collect())
+}
+
+/// 文档中所有完整匹配的标识符：(行号, 行, 起始字节, 结束字节)
+fn occurrences<'t>(text: &'t str, identifier: &str) -> Result<Vec<(usize, &'t str, usize, usize)>, Error> {
+    let pattern = Regex::new(&format!(r"\b{}\b", regex::escape(identifier)))?;
+    Ok(text
+        .lines()
+        .enumerate()
+        .flat_map(|(n, line)| pattern.find_iter(line).map(move |m| (n, line, m.start(), m.end())))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_and_positions() {
+        let line = "    let 名称 = userName + 1;";
+        let cursor = utf16_to_byte(line, 15);
+        let (start, end) = word_at(line, cursor).unwrap();
+        assert_eq!(&line[start..end], "userName");
+        assert_eq!(byte_to_utf16(line, start), 13);
+        assert_eq!(word_at(line, utf16_to_byte(line, 3)), None);
+    }
+
+    #[test]
+    fn test_comments_and_trigger() {
+        let text = "// 订单总价\nlet total = 0;\nlet count = 1; // 商品数量\n";
+        assert_eq!(comment_for(text, 1, Some(Language::Rust)).as_deref(), Some("订单总价"));
+        assert_eq!(comment_for(text, 2, Some(Language::Rust)).as_deref(), Some("商品数量"));
+        assert_eq!(comment_for("x = 1\ny = 2\n", 1, Some(Language::Python)), None);
+
+        let captures = TRIGGER.captures("    # name: user name ").unwrap();
+        assert_eq!(&captures[1], "user name");
+        assert!(TRIGGER.captures("let name = 1;").is_none());
+    }
+
+    #[test]
+    fn test_occurrences() {
+        let text = "let total = 0;\ntotal += subtotal;\n";
+        let found: Vec<(usize, usize)> = occurrences(text, "total").unwrap().iter().map(|o| (o.0, o.2)).collect();
+        assert_eq!(found, vec![(0, 4), (1, 0)]);
+    }
+}
@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+
+/// 命名规范
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum NamingStyle {
+    #[cfg_attr(feature = "cli", clap(name = "camel"))]
+    #[serde(alias = "camel")]
+    Camel,
+    #[cfg_attr(feature = "cli", clap(name = "pascal"))]
+    #[serde(alias = "pascal")]
+    Pascal,
+    #[cfg_attr(feature = "cli", clap(name = "snake"))]
+    #[serde(alias = "snake")]
+    Snake,
+    #[cfg_attr(feature = "cli", clap(name = "kebab"))]
+    #[serde(alias = "kebab")]
+    Kebab,
+    #[cfg_attr(feature = "cli", clap(name = "upper_snake"))]
+    #[serde(alias = "upper_snake")]
+    UpperSnake,
+    #[cfg_attr(feature = "cli", clap(name = "lower_camel"))]
+    #[serde(alias = "lower_camel")]
+    LowerCamel,
+    #[cfg_attr(feature = "cli", clap(name = "dot"))]
+    #[serde(alias = "dot")]
+    Dot,
+    #[cfg_attr(feature = "cli", clap(name = "train"))]
+    #[serde(alias = "train")]
+    Train,
+    #[cfg_attr(feature = "cli", clap(name = "flat"))]
+    #[serde(alias = "flat")]
+    Flat,
+    #[cfg_attr(feature = "cli", clap(name = "screaming_kebab", alias = "cobol"))]
+    #[serde(alias = "screaming_kebab", alias = "cobol")]
+    ScreamingKebab,
+    #[cfg_attr(feature = "cli", clap(name = "ada"))]
+    #[serde(alias = "ada")]
+    Ada,
+    #[cfg_attr(feature = "cli", clap(name = "path"))]
+    #[serde(alias = "path")]
+    Path,
+    #[cfg_attr(feature = "cli", clap(name = "namespace"))]
+    #[serde(alias = "namespace")]
+    Namespace,
+}
+
+impl std::fmt::Display for NamingStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NamingStyle::Camel => write!(f, "{} (camelCase)", t!("驼峰命名法")),
+            NamingStyle::Pascal => write!(f, "{} (PascalCase)", t!("帕斯卡命名法")),
+            NamingStyle::Snake => write!(f, "{} (snake_case)", t!("下划线命名法")),
+            NamingStyle::Kebab => write!(f, "{} (kebab-case)", t!("短横线命名法")),
+            NamingStyle::UpperSnake => write!(f, "{} (UPPER_SNAKE_CASE)", t!("大写下划线命名法")),
+            NamingStyle::LowerCamel => write!(f, "{} (lowerCamelCase)", t!("小驼峰命名法")),
+            NamingStyle::Dot => write!(f, "{} (dot.case)", t!("点分命名法")),
+            NamingStyle::Train => write!(f, "{} (Train-Case)", t!("首字母大写短横线命名法")),
+            NamingStyle::Flat => write!(f, "{} (flatcase)", t!("全小写连写命名法")),
+            NamingStyle::ScreamingKebab => write!(f, "{} (SCREAMING-KEBAB-CASE)", t!("大写短横线命名法")),
+            NamingStyle::Ada => write!(f, "{} (Ada_Case)", t!("Ada命名法")),
+            NamingStyle::Path => write!(f, "{} (path/case)", t!("路径命名法")),
+            NamingStyle::Namespace => write!(f, "{} (namespace::case)", t!("命名空间命名法")),
+        }
+    }
+}
+
+impl NamingStyle {
+    pub const ALL: [NamingStyle; 13] = [
+        NamingStyle::Camel,
+        NamingStyle::Pascal,
+        NamingStyle::Snake,
+        NamingStyle::Kebab,
+        NamingStyle::UpperSnake,
+        NamingStyle::LowerCamel,
+        NamingStyle::Dot,
+        NamingStyle::Train,
+        NamingStyle::Flat,
+        NamingStyle::ScreamingKebab,
+        NamingStyle::Ada,
+        NamingStyle::Path,
+        NamingStyle::Namespace,
+    ];
+    
+    /// 命令行中使用的名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            NamingStyle::Camel => "camel",
+            NamingStyle::Pascal => "pascal",
+            NamingStyle::Snake => "snake",
+            NamingStyle::Kebab => "kebab",
+            NamingStyle::UpperSnake => "upper_snake",
+            NamingStyle::LowerCamel => "lower_camel",
+            NamingStyle::Dot => "dot",
+            NamingStyle::Train => "train",
+            NamingStyle::Flat => "flat",
+            NamingStyle::ScreamingKebab => "screaming_kebab",
+            NamingStyle::Ada => "ada",
+            NamingStyle::Path => "path",
+            NamingStyle::Namespace => "namespace",
+        }
+    }
+    
+    /// 单词之间的分隔符
+    pub fn separator(&self) -> &'static str {
+        match self {
+            NamingStyle::Camel | NamingStyle::Pascal | NamingStyle::LowerCamel | NamingStyle::Flat => "",
+            NamingStyle::Snake | NamingStyle::UpperSnake | NamingStyle::Ada => "_",
+            NamingStyle::Kebab | NamingStyle::Train | NamingStyle::ScreamingKebab => "-",
+            NamingStyle::Dot => ".",
+            NamingStyle::Path => "/",
+            NamingStyle::Namespace => "::",
+        }
+    }
+}
+
+impl std::str::FromStr for NamingStyle {
+    type Err = crate::utils::error::Error;
+    
+    /// 按命令行名称解析（如 snake、upper_snake），也接受 cobol 别名
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase();
+        if name == "cobol" {
+            return Ok(NamingStyle::ScreamingKebab);
+        }
+        NamingStyle::ALL
+            .into_iter()
+            .find(|style| style.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = NamingStyle::ALL.iter().map(|style| style.name()).collect();
+                crate::utils::error::Error::ConfigError(t!(
+                    "无效的命名规范: \"{name}\"（可选 {names}）",
+                    name = s.trim(),
+                    names = crate::i18n::list(&names)
+                ))
+            })
+    }
+}
+
+/// 标识符类型
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum IdentifierKind {
+    #[cfg_attr(feature = "cli", clap(name = "variable", alias = "var"))]
+    Variable,
+    #[cfg_attr(feature = "cli", clap(name = "function", alias = "fn"))]
+    Function,
+    #[cfg_attr(feature = "cli", clap(name = "type", alias = "class"))]
+    Type,
+    #[cfg_attr(feature = "cli", clap(name = "constant", alias = "const"))]
+    Constant,
+    #[cfg_attr(feature = "cli", clap(name = "field"))]
+    Field,
+    #[cfg_attr(feature = "cli", clap(name = "module", alias = "mod"))]
+    Module,
+    #[cfg_attr(feature = "cli", clap(name = "collection", alias = "list"))]
+    Collection,
+}
+
+impl std::fmt::Display for IdentifierKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdentifierKind::Variable => write!(f, "variable"),
+            IdentifierKind::Function => write!(f, "function"),
+            IdentifierKind::Type => write!(f, "type"),
+            IdentifierKind::Constant => write!(f, "constant"),
+            IdentifierKind::Field => write!(f, "field"),
+            IdentifierKind::Module => write!(f, "module"),
+            IdentifierKind::Collection => write!(f, "collection"),
+        }
+    }
+}
+
+/// 目标编程语言
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[cfg_attr(feature = "cli", clap(name = "rust", alias = "rs"))]
+    Rust,
+    #[cfg_attr(feature = "cli", clap(name = "python", alias = "py"))]
+    Python,
+    #[cfg_attr(feature = "cli", clap(name = "javascript", alias = "js"))]
+    JavaScript,
+    #[cfg_attr(feature = "cli", clap(name = "typescript", alias = "ts"))]
+    TypeScript,
+    #[cfg_attr(feature = "cli", clap(name = "java"))]
+    Java,
+    #[cfg_attr(feature = "cli", clap(name = "go"))]
+    Go,
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Language::Rust => write!(f, "Rust"),
+            Language::Python => write!(f, "Python"),
+            Language::JavaScript => write!(f, "JavaScript"),
+            Language::TypeScript => write!(f, "TypeScript"),
+            Language::Java => write!(f, "Java"),
+            Language::Go => write!(f, "Go"),
+        }
+    }
+}
+
+impl Language {
+    /// 该语言中某类标识符惯用的命名规范，第一个为默认值
+    pub fn conventional_styles(&self, kind: IdentifierKind) -> &'static [NamingStyle] {
+        use IdentifierKind::*;
+        use NamingStyle::*;
+        match (self, kind) {
+            (Language::Go, Module) => &[Flat, Snake],
+            // Go 以首字母大小写区分导出与否
+            (Language::Go, _) => &[Camel, Pascal],
+            (_, Type) => &[Pascal],
+            (_, Constant) => &[UpperSnake],
+            (Language::Rust | Language::Python, _) => &[Snake],
+            (Language::JavaScript | Language::TypeScript, Function) => &[Camel, Pascal],
+            (Language::JavaScript | Language::TypeScript | Language::Java, _) => &[Camel],
+        }
+    }
+
+    /// 未指定类型时按变量处理
+    pub fn default_style(&self, kind: Option<IdentifierKind>) -> NamingStyle {
+        self.conventional_styles(kind.unwrap_or(IdentifierKind::Variable))[0]
+    }
+}
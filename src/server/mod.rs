@@ -0,0 +1,348 @@
+use axum::extract::{DefaultBodyLimit, Query, State};
+use axum::http::{header, Request, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use subtle::ConstantTimeEq;
+use tower::limit::ConcurrencyLimitLayer;
+
+use crate::naming::{IdentifierKind, Language, NamingStyle};
+use crate::generator::context::CodeContext;
+use crate::generator::rule::abbreviation::AbbreviationPreference;
+use crate::generator::rule::casing::{convert_identifier, AcronymPolicy, Conversion};
+use crate::generator::{GenerateOptions, Generation, Generator};
+use crate::storage::{HistoryEntry, Store};
+use crate::utils::error::Error;
+
+/// 服务的运行限制
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// 请求体最大字节数
+    pub max_body_bytes: usize,
+    /// 批量生成、转换时单次请求的最大条数
+    pub max_batch: usize,
+    /// 同时处理的最大请求数
+    pub concurrency: usize,
+    /// 单次请求的生成超时
+    pub timeout: Duration,
+}
+
+/// 所有请求共享的状态：常驻的生成器和存储，避免每次请求重新打开数据库和加载分词器
+pub struct AppState {
+    pub generator: Generator,
+    pub storage: Arc<dyn Store>,
+    /// 设置后除 /health 外的接口都要求 `Authorization: Bearer <token>`
+    pub token: Option<String>,
+    pub limits: Limits,
+    pub force_rule: bool,
+}
+
+/// 接口错误，以 `{"error": "..."}` 返回
+#[derive(Debug)]
+struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self { status, message: message.into() }
+    }
+}
+
+impl From<Error> for ApiError {
+    fn from(e: Error) -> Self {
+        let status = match e {
+            Error::RuleError(_) | Error::GeneratorError(_) | Error::LLMError(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Error::ConfigError(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        Self::new(status, e.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(json!({ "error": self.message }))).into_response()
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+/// 生成参数，与命令行选项同名
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct OptionsRequest {
+    style: Option<NamingStyle>,
+    kind: Option<IdentifierKind>,
+    lang: Option<Language>,
+    max_length: Option<usize>,
+    abbreviation: Option<AbbreviationPreference>,
+    acronyms: Option<AcronymPolicy>,
+    /// 代码上下文片段
+    context: Option<String>,
+    force_rule: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GenerateRequest {
+    description: String,
+    #[serde(flatten)]
+    options: OptionsRequest,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchRequest {
+    descriptions: Vec<String>,
+    #[serde(flatten)]
+    options: OptionsRequest,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConvertRequest {
+    identifiers: Vec<String>,
+    to: NamingStyle,
+    acronyms: Option<AcronymPolicy>,
+    lang: Option<Language>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HistoryQuery {
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MappingsQuery {
+    /// 只返回中文或英文包含该文本的映射
+    q: Option<String>,
+}
+
+/// 历史记录接口默认和最多返回的条数
+const DEFAULT_HISTORY_LIMIT: usize = 20;
+const MAX_HISTORY_LIMIT: usize = 1000;
+
+/// 启动服务，直到进程退出
+pub async fn serve(bind: SocketAddr, state: AppState) -> Result<(), Error> {
+    let app = router(Arc::new(state));
+    let server = axum::Server::try_bind(&bind)
+        .map_err(|e| Error::ConfigError(t!("无法监听 {address}: {error}", address = bind, error = e)))?;
+    println!("{}", t!("var-gen 服务已启动: http://{address}", address = bind));
+    server
+        .serve(app.into_make_service())
+        .await
+        .map_err(|e| Error::GeneratorError(t!("服务异常退出: {error}", error = e)))
+}
+
+pub fn router(state: Arc<AppState>) -> Router {
+    let limits = state.limits;
+    let api = Router::new()
+        .route("/generate", post(generate))
+        .route("/batch", post(batch))
+        .route("/convert", post(convert))
+        .route("/history", get(history))
+        .route("/mappings", get(mappings))
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorize));
+
+    Router::new()
+        .route("/health", get(health))
+        .merge(api)
+        .layer(DefaultBodyLimit::max(limits.max_body_bytes))
+        .layer(ConcurrencyLimitLayer::new(limits.concurrency))
+        .with_state(state)
+}
+
+/// 校验 bearer token（按常量时间比较，不因匹配的前缀长短泄露令牌内容）
+async fn authorize<B>(State(state): State<Arc<AppState>>, request: Request<B>, next: Next<B>) -> Response {
+    if let Some(token) = &state.token {
+        let provided = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        let valid = provided.is_some_and(|provided| bool::from(provided.as_bytes().ct_eq(token.as_bytes())));
+        if !valid {
+            return ApiError::new(StatusCode::UNAUTHORIZED, t!("缺少或无效的访问令牌")).into_response();
+        }
+    }
+    next.run(request).await
+}
+
+async fn health(State(state): State<Arc<AppState>>) -> Json<serde_json::Value> {
+    Json(json!({
+        "status": "ok",
+        "version": env!("CARGO_PKG_VERSION"),
+        "llm": state.generator.llm_available() && !state.force_rule,
+        "profile": state.generator.profile.as_ref().map(|p| p.name.clone()),
+    }))
+}
+
+async fn generate(State(state): State<Arc<AppState>>, Json(request): Json<GenerateRequest>) -> ApiResult<Generation> {
+    let description = request.description.trim();
+    if description.is_empty() {
+        return Err(ApiError::new(StatusCode::BAD_REQUEST, t!("description 不能为空")));
+    }
+
+    let (options, context) = resolve(&state, &request.options)?;
+    let force_rule = state.force_rule || request.options.force_rule;
+    let names = with_timeout(&state, state.generator.generate(description, context.as_ref(), &options, force_rule)).await?;
+
+    // 与命令行一样把首选名称记入历史
+    if let Some(first) = names.first() {
+        state.storage.save_history(description, options.style, first)?;
+    }
+
+    Ok(Json(Generation { description: description.to_string(), style: options.style.name(), names }))
+}
+
+/// 整批共用一个超时；与命令行的批量生成一样不写入历史记录
+async fn batch(State(state): State<Arc<AppState>>, Json(request): Json<BatchRequest>) -> ApiResult<Vec<Generation>> {
+    check_batch_size(&state, request.descriptions.len())?;
+
+    let (options, context) = resolve(&state, &request.options)?;
+    let force_rule = state.force_rule || request.options.force_rule;
+    let generations = with_timeout(&state, async {
+        let mut generations = Vec::new();
+        for description in request.descriptions.iter().map(|d| d.trim()).filter(|d| !d.is_empty()) {
+            let names = state.generator.generate(description, context.as_ref(), &options, force_rule).await?;
+            generations.push(Generation { description: description.to_string(), style: options.style.name(), names });
+        }
+        Ok(generations)
+    })
+    .await?;
+
+    Ok(Json(generations))
+}
+
+async fn convert(State(state): State<Arc<AppState>>, Json(request): Json<ConvertRequest>) -> ApiResult<Vec<Conversion>> {
+    check_batch_size(&state, request.identifiers.len())?;
+
+    Ok(Json(
+        request
+            .identifiers
+            .iter()
+            .map(|identifier| convert_identifier(identifier, request.to, request.acronyms, request.lang))
+            .collect(),
+    ))
+}
+
+async fn history(State(state): State<Arc<AppState>>, Query(query): Query<HistoryQuery>) -> ApiResult<Vec<HistoryEntry>> {
+    let limit = query.limit.unwrap_or(DEFAULT_HISTORY_LIMIT).min(MAX_HISTORY_LIMIT);
+    Ok(Json(state.storage.get_history(limit)?))
+}
+
+async fn mappings(State(state): State<Arc<AppState>>, Query(query): Query<MappingsQuery>) -> Json<BTreeMap<String, String>> {
+    let filter = query.q.as_deref().map(str::to_lowercase);
+    Json(
+        state
+            .generator
+            .rule_generator
+            .mappings()
+            .iter()
+            .filter(|(chinese, english)| {
+                filter
+                    .as_deref()
+                    .is_none_or(|q| chinese.contains(q) || english.to_lowercase().contains(q))
+            })
+            .map(|(chinese, english)| (chinese.clone(), english.clone()))
+            .collect(),
+    )
+}
+
+/// 用配置档默认值补全请求中的生成参数，并解析代码上下文
+fn resolve(state: &AppState, request: &OptionsRequest) -> Result<(GenerateOptions, Option<CodeContext>), ApiError> {
+    let context = request
+        .context
+        .as_deref()
+        .map(|snippet| CodeContext::from_snippet(snippet, request.lang))
+        .transpose()?;
+    let style = request
+        .style
+        .or_else(|| context.as_ref().and_then(|c| c.dominant_style(request.kind)));
+
+    let options = GenerateOptions {
+        max_length: request.max_length,
+        abbreviation: request.abbreviation,
+        acronyms: request.acronyms,
+        ..state.generator.resolve_options(style, request.kind, request.lang)
+    };
+    Ok((options, context))
+}
+
+fn check_batch_size(state: &AppState, len: usize) -> Result<(), ApiError> {
+    if len > state.limits.max_batch {
+        return Err(ApiError::new(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            t!("单次请求最多 {max} 条，实际 {count} 条", max = state.limits.max_batch, count = len),
+        ));
+    }
+    Ok(())
+}
+
+async fn with_timeout<T>(state: &AppState, future: impl Future<Output = Result<T, Error>>) -> Result<T, ApiError> {
+    match tokio::time::timeout(state.limits.timeout, future).await {
+        Ok(result) => Ok(result?),
+        Err(_) => Err(ApiError::new(StatusCode::GATEWAY_TIMEOUT, t!("生成超时"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::mock_generator;
+    use crate::storage::mock_storage;
+    use axum::body::Body;
+    use tower::ServiceExt;
+
+    fn app(token: Option<&str>) -> Router {
+        router(Arc::new(AppState {
+            generator: mock_generator(),
+            storage: Arc::new(mock_storage().unwrap()),
+            token: token.map(str::to_string),
+            limits: Limits {
+                max_body_bytes: 1024,
+                max_batch: 2,
+                concurrency: 4,
+                timeout: Duration::from_secs(10),
+            },
+            force_rule: true,
+        }))
+    }
+
+    fn post(uri: &str, body: &str, token: Option<&str>) -> Request<Body> {
+        let mut builder = Request::post(uri).header(header::CONTENT_TYPE, "application/json");
+        if let Some(token) = token {
+            builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        builder.body(Body::from(body.to_string())).unwrap()
+    }
+
+    async fn status(app: Router, request: Request<Body>) -> StatusCode {
+        app.oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_token_and_limits() {
+        let health = Request::get("/health").body(Body::empty()).unwrap();
+        assert_eq!(status(app(Some("secret")), health).await, StatusCode::OK);
+
+        let body = r#"{"description": "user name"}"#;
+        assert_eq!(status(app(Some("secret")), post("/generate", body, None)).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status(app(Some("secret")), post("/generate", body, Some("wrong"))).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status(app(Some("secret")), post("/generate", body, Some("secreT"))).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status(app(Some("secret")), post("/generate", body, Some("secret"))).await, StatusCode::OK);
+
+        let too_many = r#"{"identifiers": ["a", "b", "c"], "to": "camel"}"#;
+        assert_eq!(status(app(None), post("/convert", too_many, None)).await, StatusCode::PAYLOAD_TOO_LARGE);
+        let too_large = format!(r#"{{"description": "{}"}}"#, "x".repeat(2048));
+        assert_eq!(status(app(None), post("/generate", &too_large, None)).await, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(status(app(None), post("/generate", r#"{"description": " "}"#, None)).await, StatusCode::BAD_REQUEST);
+    }
+}
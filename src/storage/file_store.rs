@@ -0,0 +1,135 @@
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::memory_store::{lock, Data};
+use super::{CacheEntry, FileIndex, HistoryEntry, Store};
+use crate::utils::error::Error;
+
+/// 单个 JSON 文件的存储，默认位于 `~/.var-gen/store.json`，
+/// 不需要 sled，离线构建默认使用
+#[derive(Debug)]
+pub struct JsonFileStore {
+    path: PathBuf,
+    data: Mutex<Data>,
+}
+
+impl JsonFileStore {
+    /// 打开默认存储文件；环境变量 DATABASE_URL 可指定文件路径
+    pub fn new() -> Result<Self, Error> {
+        let path = if let Ok(custom_path) = std::env::var("DATABASE_URL") {
+            PathBuf::from(custom_path)
+        } else {
+            let home_dir = dirs::home_dir().ok_or_else(|| Error::StorageError(t!("无法确定用户目录").to_string()))?;
+            home_dir.join(".var-gen").join("store.json")
+        };
+
+        Self::open(&path)
+    }
+
+    /// 打开指定路径的存储文件，文件不存在时从空数据开始
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        let data = match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| Error::StorageError(t!("无法解析 {path}: {error}", path = path.display(), error = e)))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Data::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            data: Mutex::new(data),
+        })
+    }
+
+    /// 修改数据后整体写回文件：先写临时文件再改名，避免中途退出留下半个文件
+    fn update<T>(&self, f: impl FnOnce(&mut Data) -> T) -> Result<T, Error> {
+        let mut data = lock(&self.data)?;
+        let result = f(&mut data);
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec(&*data)?)?;
+        std::fs::rename(&tmp_path, &self.path)?;
+
+        Ok(result)
+    }
+}
+
+impl Store for JsonFileStore {
+    fn add_history(&self, entry: &HistoryEntry) -> Result<(), Error> {
+        self.update(|data| data.history.push(entry.clone()))
+    }
+
+    fn get_history(&self, limit: usize) -> Result<Vec<HistoryEntry>, Error> {
+        Ok(lock(&self.data)?.recent_history(limit))
+    }
+
+    fn clear_history(&self) -> Result<(), Error> {
+        self.update(|data| data.history.clear())
+    }
+
+    fn save_config(&self, key: &str, value: &str) -> Result<(), Error> {
+        self.update(|data| {
+            data.config.insert(key.to_string(), value.to_string());
+        })
+    }
+
+    fn get_config(&self, key: &str) -> Result<Option<String>, Error> {
+        Ok(lock(&self.data)?.config.get(key).cloned())
+    }
+
+    fn get_file_index(&self, path: &str) -> Result<Option<FileIndex>, Error> {
+        Ok(lock(&self.data)?.identifiers.get(path).cloned())
+    }
+
+    fn save_file_index(&self, path: &str, index: &FileIndex) -> Result<(), Error> {
+        self.update(|data| {
+            data.identifiers.insert(path.to_string(), index.clone());
+        })
+    }
+
+    fn prune_file_index(&self, prefix: &str, existing: &[String]) -> Result<(), Error> {
+        self.update(|data| data.prune_file_index(prefix, existing))
+    }
+
+    fn get_cache(&self, key: &str) -> Result<Option<CacheEntry>, Error> {
+        Ok(lock(&self.data)?.cache.get(key).cloned())
+    }
+
+    fn save_cache(&self, key: &str, entry: &CacheEntry) -> Result<(), Error> {
+        self.update(|data| {
+            data.cache.insert(key.to_string(), entry.clone());
+        })
+    }
+
+    fn clear_cache(&self) -> Result<(), Error> {
+        self.update(|data| data.cache.clear())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::naming::NamingStyle;
+
+    #[test]
+    fn test_persists_across_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("store.json");
+
+        let store = JsonFileStore::open(&path).unwrap();
+        store.save_history("用户名称", NamingStyle::Snake, "user_name").unwrap();
+        store.save_history("订单编号", NamingStyle::Camel, "orderId").unwrap();
+        store.save_config("default_style", "camel").unwrap();
+        drop(store);
+
+        let store = JsonFileStore::open(&path).unwrap();
+        let history = store.get_history(1).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].variable_name, "orderId");
+        assert!(store.check_duplicate("user_name").unwrap());
+        assert_eq!(store.get_config("default_style").unwrap().as_deref(), Some("camel"));
+    }
+}
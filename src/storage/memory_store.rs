@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard};
+
+use super::{CacheEntry, FileIndex, HistoryEntry, Store};
+use crate::utils::error::Error;
+
+/// 内存和 JSON 文件存储共用的数据
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct Data {
+    #[serde(default)]
+    pub history: Vec<HistoryEntry>,
+    #[serde(default)]
+    pub config: BTreeMap<String, String>,
+    #[serde(default)]
+    pub identifiers: BTreeMap<String, FileIndex>,
+    #[serde(default)]
+    pub cache: BTreeMap<String, CacheEntry>,
+}
+
+impl Data {
+    /// 按时间戳倒序返回历史记录，同一秒内后添加的在前
+    pub fn recent_history(&self, limit: usize) -> Vec<HistoryEntry> {
+        let mut entries = self.history.clone();
+        entries.reverse();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.timestamp));
+        entries.truncate(limit);
+        entries
+    }
+
+    /// 删除前缀下已不存在的文件的索引
+    pub fn prune_file_index(&mut self, prefix: &str, existing: &[String]) {
+        self.identifiers
+            .retain(|path, _| !path.starts_with(prefix) || existing.contains(path));
+    }
+}
+
+pub(super) fn lock(data: &Mutex<Data>) -> Result<MutexGuard<'_, Data>, Error> {
+    data.lock()
+        .map_err(|_| Error::StorageError(t!("存储锁已损坏").to_string()))
+}
+
+/// 只保存在内存中的存储，进程退出后丢失；用于测试和嵌入本库的程序
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    data: Mutex<Data>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Store for MemoryStore {
+    fn add_history(&self, entry: &HistoryEntry) -> Result<(), Error> {
+        lock(&self.data)?.history.push(entry.clone());
+        Ok(())
+    }
+
+    fn get_history(&self, limit: usize) -> Result<Vec<HistoryEntry>, Error> {
+        Ok(lock(&self.data)?.recent_history(limit))
+    }
+
+    fn clear_history(&self) -> Result<(), Error> {
+        lock(&self.data)?.history.clear();
+        Ok(())
+    }
+
+    fn save_config(&self, key: &str, value: &str) -> Result<(), Error> {
+        lock(&self.data)?.config.insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn get_config(&self, key: &str) -> Result<Option<String>, Error> {
+        Ok(lock(&self.data)?.config.get(key).cloned())
+    }
+
+    fn get_file_index(&self, path: &str) -> Result<Option<FileIndex>, Error> {
+        Ok(lock(&self.data)?.identifiers.get(path).cloned())
+    }
+
+    fn save_file_index(&self, path: &str, index: &FileIndex) -> Result<(), Error> {
+        lock(&self.data)?.identifiers.insert(path.to_string(), index.clone());
+        Ok(())
+    }
+
+    fn prune_file_index(&self, prefix: &str, existing: &[String]) -> Result<(), Error> {
+        lock(&self.data)?.prune_file_index(prefix, existing);
+        Ok(())
+    }
+
+    fn get_cache(&self, key: &str) -> Result<Option<CacheEntry>, Error> {
+        Ok(lock(&self.data)?.cache.get(key).cloned())
+    }
+
+    fn save_cache(&self, key: &str, entry: &CacheEntry) -> Result<(), Error> {
+        lock(&self.data)?.cache.insert(key.to_string(), entry.clone());
+        Ok(())
+    }
+
+    fn clear_cache(&self) -> Result<(), Error> {
+        lock(&self.data)?.cache.clear();
+        Ok(())
+    }
+}
@@ -1,11 +1,21 @@
 use serde::{Deserialize, Serialize};
-use sled::{Config as SledConfig, Db, Tree};
 use std::path::Path;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::naming::{IdentifierKind, NamingStyle};
 use crate::utils::error::Error;
 
-use super::cli::NamingStyle;
+mod file_store;
+mod memory_store;
+#[cfg(feature = "sled-storage")]
+mod sled_store;
+
+pub use file_store::JsonFileStore;
+pub use memory_store::MemoryStore;
+#[cfg(feature = "sled-storage")]
+pub use sled_store::SledStore;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryEntry {
@@ -15,166 +25,227 @@ pub struct HistoryEntry {
     pub timestamp: u64,
 }
 
-#[derive(Debug)]
-pub struct Storage {
-    #[allow(dead_code)]
-    db: Db,
-    history_tree: Tree,
-    config_tree: Tree,
-}
-
-impl Storage {
-    pub fn new() -> Result<Self, Error> {
-        // 检查环境变量是否指定了数据库路径
-        let db_path = if let Ok(custom_db_path) = std::env::var("DATABASE_URL") {
-            std::path::PathBuf::from(custom_db_path)
-        } else {
-            // 获取用户目录
-            let home_dir = dirs::home_dir().ok_or_else(|| Error::StorageError("Failed to get home directory".to_string()))?;
-            home_dir.join(".var-gen").join("db")
-        };
-        
-        // 创建目录
-        if let Some(parent) = db_path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-        
-        // 初始化数据库
-        let config = SledConfig::new()
-            .path(db_path)
-            .cache_capacity(1024 * 1024 * 10) // 10MB 缓存
-            .flush_every_ms(Some(1000)) // 每秒刷新
-            .use_compression(true);
-        
-        let db = config.open()?;
-        
-        // 打开树
-        let history_tree = db.open_tree("history")?;
-        let config_tree = db.open_tree("config")?;
-        
+impl HistoryEntry {
+    /// 以当前时间创建历史记录条目
+    pub fn new(description: &str, style: NamingStyle, variable_name: &str) -> Result<Self, Error> {
         Ok(Self {
-            db,
-            history_tree,
-            config_tree,
-        })
-    }
-    
-    pub fn save_history(&self, description: &str, style: NamingStyle, variable_name: &str) -> Result<(), Error> {
-        // 创建历史记录条目
-        let entry = HistoryEntry {
             description: description.to_string(),
             style,
             variable_name: variable_name.to_string(),
-            timestamp: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .map_err(|e| Error::StorageError(format!("Failed to get current time: {}", e)))?
-                .as_secs(),
-        };
-        
-        // 序列化
-        let entry_bytes = bincode::serialize(&entry)?;
-        
-        // 生成键（使用时间戳确保唯一性）
-        let key = format!("{}:{}:{}", entry.timestamp, style, variable_name);
-        
-        // 保存到数据库
-        self.history_tree.insert(key, entry_bytes)?;
-        
-        Ok(())
-    }
-    
-    pub fn get_history(&self, limit: usize) -> Result<Vec<HistoryEntry>, Error> {
-        let mut entries = Vec::new();
-        
-        // 按时间戳倒序遍历
-        for result in self.history_tree.iter().rev().take(limit) {
-            let (_key, value) = result?;
-            let entry: HistoryEntry = bincode::deserialize(&value)?;
-            entries.push(entry);
-        }
-        
-        Ok(entries)
+            timestamp: now()?,
+        })
     }
-    
-    #[allow(dead_code)]
-    pub fn check_duplicate(&self, variable_name: &str) -> Result<bool, Error> {
-        // 遍历历史记录，检查是否存在相同的变量名
-        for result in self.history_tree.iter() {
-            let (_key, value) = result?;
-            let entry: HistoryEntry = bincode::deserialize(&value)?;
-            if entry.variable_name == variable_name {
-                return Ok(true);
-            }
-        }
-        
-        Ok(false)
+}
+
+/// 缓存的生成结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub value: String,
+    /// 写入时间（秒级时间戳）
+    pub timestamp: u64,
+}
+
+impl CacheEntry {
+    /// 以当前时间创建缓存条目
+    pub fn new(value: &str) -> Result<Self, Error> {
+        Ok(Self {
+            value: value.to_string(),
+            timestamp: now()?,
+        })
     }
-    
-    #[allow(dead_code)]
-    pub fn save_config(&self, key: &str, value: &str) -> Result<(), Error> {
-        self.config_tree.insert(key, value.as_bytes())?;
-        Ok(())
+}
+
+fn now() -> Result<u64, Error> {
+    Ok(SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| Error::StorageError(t!("无法获取当前时间: {error}", error = e)))?
+        .as_secs())
+}
+
+/// 源文件中声明的标识符
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedIdentifier {
+    pub name: String,
+    pub kind: IdentifierKind,
+    pub line: usize,
+}
+
+/// 单个源文件的标识符索引，按修改时间判断是否过期
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileIndex {
+    /// 文件修改时间（毫秒时间戳）
+    pub mtime: u64,
+    pub identifiers: Vec<IndexedIdentifier>,
+}
+
+/// 历史记录、大模型结果缓存和标识符索引缓存的存储后端
+pub trait Store: Send + Sync + std::fmt::Debug {
+    /// 追加一条历史记录
+    fn add_history(&self, entry: &HistoryEntry) -> Result<(), Error>;
+
+    /// 按时间倒序返回最近的历史记录
+    fn get_history(&self, limit: usize) -> Result<Vec<HistoryEntry>, Error>;
+
+    fn clear_history(&self) -> Result<(), Error>;
+
+    /// 旧版本保存的配置项，只用于迁移到配置文件
+    fn save_config(&self, key: &str, value: &str) -> Result<(), Error>;
+
+    fn get_config(&self, key: &str) -> Result<Option<String>, Error>;
+
+    /// 读取源文件的标识符索引（键为文件的绝对路径）
+    fn get_file_index(&self, path: &str) -> Result<Option<FileIndex>, Error>;
+
+    fn save_file_index(&self, path: &str, index: &FileIndex) -> Result<(), Error>;
+
+    /// 删除目录下已不存在的文件的索引
+    fn prune_file_index(&self, prefix: &str, existing: &[String]) -> Result<(), Error>;
+
+    fn get_cache(&self, key: &str) -> Result<Option<CacheEntry>, Error>;
+
+    fn save_cache(&self, key: &str, entry: &CacheEntry) -> Result<(), Error>;
+
+    fn clear_cache(&self) -> Result<(), Error>;
+
+    /// 读取未过期的缓存值
+    fn get_fresh_cache(&self, key: &str, ttl: Duration) -> Result<Option<String>, Error> {
+        let now = now()?;
+        Ok(self
+            .get_cache(key)?
+            .filter(|entry| now.saturating_sub(entry.timestamp) < ttl.as_secs())
+            .map(|entry| entry.value))
     }
-    
-    pub fn get_config(&self, key: &str) -> Result<Option<String>, Error> {
-        if let Some(value) = self.config_tree.get(key)? {
-            Ok(Some(String::from_utf8(value.to_vec())?))
-        } else {
-            Ok(None)
-        }
+
+    fn save_history(&self, description: &str, style: NamingStyle, variable_name: &str) -> Result<(), Error> {
+        self.add_history(&HistoryEntry::new(description, style, variable_name)?)
     }
-    
-    pub fn clear_history(&self) -> Result<(), Error> {
-        self.history_tree.clear()?;
-        Ok(())
+
+    fn check_duplicate(&self, variable_name: &str) -> Result<bool, Error> {
+        // 遍历历史记录，检查是否存在相同的变量名
+        Ok(self
+            .get_history(usize::MAX)?
+            .iter()
+            .any(|entry| entry.variable_name == variable_name))
     }
-    
-    #[allow(dead_code)]
-    pub fn export_history(&self, path: &Path) -> Result<(), Error> {
+
+    fn export_history(&self, path: &Path) -> Result<(), Error> {
         let history = self.get_history(usize::MAX)?;
-        
+
         let file = std::fs::File::create(path)?;
         serde_json::to_writer_pretty(file, &history)?;
-        
+
         Ok(())
     }
-    
-    #[allow(dead_code)]
-    pub fn import_history(&self, path: &Path) -> Result<(), Error> {
+
+    fn import_history(&self, path: &Path) -> Result<(), Error> {
         let file = std::fs::File::open(path)?;
         let history: Vec<HistoryEntry> = serde_json::from_reader(file)?;
-        
-        for entry in history {
-            let key = format!("{}:{}:{}", entry.timestamp, entry.style, entry.variable_name);
-            let entry_bytes = bincode::serialize(&entry)?;
-            self.history_tree.insert(key, entry_bytes)?;
+
+        for entry in &history {
+            self.add_history(entry)?;
         }
-        
+
         Ok(())
     }
 }
 
+/// 存储后端
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// sled 数据库（需要 `sled-storage` 特性）
+    Sled,
+    /// 单个 JSON 文件
+    Json,
+    /// 只保存在内存中，进程退出后丢失
+    Memory,
+}
+
+impl Default for StorageBackend {
+    fn default() -> Self {
+        if cfg!(feature = "sled-storage") {
+            StorageBackend::Sled
+        } else {
+            StorageBackend::Json
+        }
+    }
+}
+
+impl FromStr for StorageBackend {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "sled" => Ok(StorageBackend::Sled),
+            "json" | "file" => Ok(StorageBackend::Json),
+            "memory" => Ok(StorageBackend::Memory),
+            other => Err(Error::StorageError(t!(
+                "未知的存储后端: {name}（可选 sled、json、memory）",
+                name = other
+            ))),
+        }
+    }
+}
+
+impl StorageBackend {
+    /// 当前生效的后端：环境变量 VAR_GEN_STORAGE > 项目配置的 storage > 编译时默认
+    pub fn configured() -> Result<Self, Error> {
+        if let Ok(value) = std::env::var("VAR_GEN_STORAGE") {
+            if !value.is_empty() {
+                return value.parse();
+            }
+        }
+
+        let project = crate::config::project::ProjectConfig::discover()?;
+        Ok(project.and_then(|p| p.storage).unwrap_or_default())
+    }
+
+    /// 打开后端的默认位置（DATABASE_URL 可指定 sled 和 JSON 存储的路径）
+    pub fn open(self) -> Result<Arc<dyn Store>, Error> {
+        match self {
+            #[cfg(feature = "sled-storage")]
+            StorageBackend::Sled => Ok(Arc::new(SledStore::new()?)),
+            #[cfg(not(feature = "sled-storage"))]
+            StorageBackend::Sled => Err(Error::FeatureUnavailable(t!("sled 存储"), "sled-storage")),
+            StorageBackend::Json => Ok(Arc::new(JsonFileStore::new()?)),
+            StorageBackend::Memory => Ok(Arc::new(MemoryStore::new())),
+        }
+    }
+}
+
 // 用于测试的辅助函数
 #[cfg(test)]
 #[allow(dead_code)]
-pub fn mock_storage() -> Result<Storage, Error> {
-    use tempfile::tempdir;
-    
-    let dir = tempdir()?;
-    let db_path = dir.path().join("var-gen-db");
-    
-    let config = SledConfig::new()
-        .path(db_path)
-        .temporary(true);
-    
-    let db = config.open()?;
-    let history_tree = db.open_tree("history")?;
-    let config_tree = db.open_tree("config")?;
-    
-    Ok(Storage {
-        db,
-        history_tree,
-        config_tree,
-    })
+pub fn mock_storage() -> Result<MemoryStore, Error> {
+    Ok(MemoryStore::new())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_expires_after_ttl() {
+        let store = MemoryStore::new();
+        let stale = CacheEntry {
+            value: "stale".to_string(),
+            timestamp: now().unwrap() - 120,
+        };
+        store.save_cache("old", &stale).unwrap();
+        store.save_cache("new", &CacheEntry::new("fresh").unwrap()).unwrap();
+
+        let ttl = Duration::from_secs(60);
+        assert_eq!(store.get_fresh_cache("old", ttl).unwrap(), None);
+        assert_eq!(store.get_fresh_cache("new", ttl).unwrap().as_deref(), Some("fresh"));
+
+        store.clear_cache().unwrap();
+        assert!(store.get_cache("new").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_backend_from_str() {
+        assert_eq!("JSON".parse::<StorageBackend>().unwrap(), StorageBackend::Json);
+        assert_eq!("memory".parse::<StorageBackend>().unwrap(), StorageBackend::Memory);
+        assert!("redis".parse::<StorageBackend>().is_err());
+    }
+}
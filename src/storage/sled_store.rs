@@ -0,0 +1,179 @@
+use sled::{Config as SledConfig, Db, Tree};
+use std::path::Path;
+
+use super::{CacheEntry, FileIndex, HistoryEntry, Store};
+use crate::utils::error::Error;
+
+/// 基于 sled 的存储，默认位于 `~/.var-gen/db`
+#[derive(Debug)]
+pub struct SledStore {
+    #[allow(dead_code)]
+    db: Db,
+    history_tree: Tree,
+    config_tree: Tree,
+    identifier_tree: Tree,
+    cache_tree: Tree,
+}
+
+impl SledStore {
+    /// 打开默认数据库；环境变量 DATABASE_URL 可指定数据库路径
+    pub fn new() -> Result<Self, Error> {
+        // 检查环境变量是否指定了数据库路径
+        let db_path = if let Ok(custom_db_path) = std::env::var("DATABASE_URL") {
+            std::path::PathBuf::from(custom_db_path)
+        } else {
+            // 获取用户目录
+            let home_dir = dirs::home_dir().ok_or_else(|| Error::StorageError(t!("无法确定用户目录").to_string()))?;
+            home_dir.join(".var-gen").join("db")
+        };
+        
+        Self::open(&db_path)
+    }
+    
+    pub fn open(db_path: &Path) -> Result<Self, Error> {
+        // 创建目录
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        
+        // 初始化数据库
+        let config = SledConfig::new()
+            .path(db_path)
+            .cache_capacity(1024 * 1024 * 10) // 10MB 缓存
+            .flush_every_ms(Some(1000)) // 每秒刷新
+            .use_compression(true);
+        
+        Self::from_db(config.open()?)
+    }
+    
+    /// 进程退出后自动删除的临时数据库
+    pub fn temporary() -> Result<Self, Error> {
+        Self::from_db(SledConfig::new().temporary(true).open()?)
+    }
+    
+    fn from_db(db: Db) -> Result<Self, Error> {
+        // 打开树
+        let history_tree = db.open_tree("history")?;
+        let config_tree = db.open_tree("config")?;
+        let identifier_tree = db.open_tree("identifiers")?;
+        let cache_tree = db.open_tree("cache")?;
+        
+        Ok(Self {
+            db,
+            history_tree,
+            config_tree,
+            identifier_tree,
+            cache_tree,
+        })
+    }
+}
+
+impl Store for SledStore {
+    fn add_history(&self, entry: &HistoryEntry) -> Result<(), Error> {
+        // 序列化
+        let entry_bytes = bincode::serialize(entry)?;
+        
+        // 生成键（使用时间戳确保唯一性）
+        let key = format!("{}:{}:{}", entry.timestamp, entry.style, entry.variable_name);
+        
+        // 保存到数据库
+        self.history_tree.insert(key, entry_bytes)?;
+        
+        Ok(())
+    }
+    
+    fn get_history(&self, limit: usize) -> Result<Vec<HistoryEntry>, Error> {
+        let mut entries = Vec::new();
+        
+        // 按时间戳倒序遍历
+        for result in self.history_tree.iter().rev().take(limit) {
+            let (_key, value) = result?;
+            let entry: HistoryEntry = bincode::deserialize(&value)?;
+            entries.push(entry);
+        }
+        
+        Ok(entries)
+    }
+    
+    fn clear_history(&self) -> Result<(), Error> {
+        self.history_tree.clear()?;
+        Ok(())
+    }
+    
+    fn save_config(&self, key: &str, value: &str) -> Result<(), Error> {
+        self.config_tree.insert(key, value.as_bytes())?;
+        Ok(())
+    }
+    
+    fn get_config(&self, key: &str) -> Result<Option<String>, Error> {
+        if let Some(value) = self.config_tree.get(key)? {
+            Ok(Some(String::from_utf8(value.to_vec())?))
+        } else {
+            Ok(None)
+        }
+    }
+    
+    fn get_file_index(&self, path: &str) -> Result<Option<FileIndex>, Error> {
+        match self.identifier_tree.get(path)? {
+            Some(value) => Ok(Some(bincode::deserialize(&value)?)),
+            None => Ok(None),
+        }
+    }
+    
+    fn save_file_index(&self, path: &str, index: &FileIndex) -> Result<(), Error> {
+        self.identifier_tree.insert(path, bincode::serialize(index)?)?;
+        Ok(())
+    }
+    
+    fn prune_file_index(&self, prefix: &str, existing: &[String]) -> Result<(), Error> {
+        for result in self.identifier_tree.scan_prefix(prefix) {
+            let (key, _) = result?;
+            let path = String::from_utf8(key.to_vec())?;
+            if !existing.contains(&path) {
+                self.identifier_tree.remove(key)?;
+            }
+        }
+        Ok(())
+    }
+    
+    fn get_cache(&self, key: &str) -> Result<Option<CacheEntry>, Error> {
+        match self.cache_tree.get(key)? {
+            Some(value) => Ok(Some(bincode::deserialize(&value)?)),
+            None => Ok(None),
+        }
+    }
+    
+    fn save_cache(&self, key: &str, entry: &CacheEntry) -> Result<(), Error> {
+        self.cache_tree.insert(key, bincode::serialize(entry)?)?;
+        Ok(())
+    }
+    
+    fn clear_cache(&self) -> Result<(), Error> {
+        self.cache_tree.clear()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::naming::NamingStyle;
+
+    #[test]
+    fn test_history_round_trip() {
+        let store = SledStore::temporary().unwrap();
+        store.save_history("用户名称", NamingStyle::Snake, "user_name").unwrap();
+        assert!(store.check_duplicate("user_name").unwrap());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.json");
+        store.export_history(&path).unwrap();
+        store.clear_history().unwrap();
+        assert!(store.get_history(10).unwrap().is_empty());
+
+        store.import_history(&path).unwrap();
+        let history = store.get_history(10).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].variable_name, "user_name");
+    }
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use crate::utils::error::Error;
+
+/// 解析时长：纯数字表示秒，也可以带单位 s/m/h/d 并组合，如 "90"、"12h"、"1h30m"
+pub fn parse_duration(text: &str) -> Result<Duration, Error> {
+    let text = text.trim();
+    let invalid = || Error::ConfigError(t!("无效的时长: \"{text}\"（示例: 3600、30m、12h、1d）", text = text));
+    if text.is_empty() {
+        return Err(invalid());
+    }
+    if let Ok(secs) = text.parse::<u64>() {
+        return Ok(Duration::from_secs(secs));
+    }
+
+    let mut total: u64 = 0;
+    let mut number = String::new();
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c.to_ascii_lowercase() {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            _ => return Err(invalid()),
+        };
+        let value: u64 = number.parse().map_err(|_| invalid())?;
+        total = value
+            .checked_mul(unit)
+            .and_then(|secs| total.checked_add(secs))
+            .ok_or_else(invalid)?;
+        number.clear();
+    }
+    if !number.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(Duration::from_secs(total))
+}
+
+/// 按最大的整除单位格式化时长，如 43200 秒为 "12h"
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match secs {
+        0 => "0s".to_string(),
+        _ if secs.is_multiple_of(86400) => format!("{}d", secs / 86400),
+        _ if secs.is_multiple_of(3600) => format!("{}h", secs / 3600),
+        _ if secs.is_multiple_of(60) => format!("{}m", secs / 60),
+        _ => format!("{}s", secs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_duration() {
+        assert_eq!(parse_duration("86400").unwrap(), Duration::from_secs(86400));
+        assert_eq!(parse_duration("12h").unwrap(), Duration::from_secs(43200));
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+        assert!(parse_duration("12").is_ok());
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("12x").is_err());
+        assert!(parse_duration("1h30").is_err());
+        assert_eq!(format_duration(Duration::from_secs(43200)), "12h");
+        assert_eq!(format_duration(Duration::from_secs(5400)), "90m");
+    }
+}
@@ -7,46 +7,80 @@
  */
 use thiserror::Error;
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("Generator error: {0}")]
+    #[error("{kind}: {0}", kind = t!("生成错误"))]
     GeneratorError(String),
     
-    #[error("LLM error: {0}")]
+    #[error("{kind}: {0}", kind = t!("大模型错误"))]
     LLMError(String),
     
-    #[error("Rule engine error: {0}")]
+    #[error("{kind}: {0}", kind = t!("规则引擎错误"))]
     RuleError(String),
     
-    #[error("Storage error: {0}")]
+    #[error("{kind}: {0}", kind = t!("存储错误"))]
     StorageError(String),
     
-    #[error("Network error: {0}")]
+    #[cfg(feature = "llm")]
+    #[error("{kind}: {0}", kind = t!("网络错误"))]
     NetworkError(#[from] reqwest::Error),
     
-    #[error("IO error: {0}")]
+    #[error("{kind}: {0}", kind = t!("文件读写错误"))]
     IoError(#[from] std::io::Error),
     
-    #[error("UTF-8 error: {0}")]
+    #[error("{kind}: {0}", kind = t!("UTF-8 编码错误"))]
     Utf8Error(#[from] std::string::FromUtf8Error),
     
-    #[error("JSON error: {0}")]
+    #[error("{kind}: {0}", kind = t!("JSON 错误"))]
     JsonError(#[from] serde_json::Error),
     
-    #[error("Bincode error: {0}")]
+    #[cfg(feature = "sled-storage")]
+    #[error("{kind}: {0}", kind = t!("Bincode 错误"))]
     BincodeError(#[from] bincode::Error),
     
-    #[error("Sled error: {0}")]
+    #[cfg(feature = "sled-storage")]
+    #[error("{kind}: {0}", kind = t!("Sled 错误"))]
     SledError(#[from] sled::Error),
     
-    #[error("Regex error: {0}")]
+    #[error("{kind}: {0}", kind = t!("正则表达式错误"))]
     RegexError(#[from] regex::Error),
     
-    #[error("Dialoguer error: {0}")]
+    #[cfg(feature = "interactive")]
+    #[error("{kind}: {0}", kind = t!("交互输入错误"))]
     DialoguerError(#[from] dialoguer::Error),
     
-    #[error("Config error: {0}")]
+    #[cfg(feature = "interactive")]
+    #[error("{kind}: {0}", kind = t!("行编辑错误"))]
+    ReadlineError(#[from] rustyline::error::ReadlineError),
+    
+    #[error("{kind}: {0}", kind = t!("配置错误"))]
     ConfigError(String),
+    
+    #[error("{kind}: {0}", kind = t!("命名检查错误"))]
+    LintError(String),
+    
+    /// 功能所需的 cargo 特性在编译时未启用
+    #[error("{}", t!("{feature} 不可用：编译时未启用 `{flag}` 特性，请使用 `cargo install var-gen --features {flag}` 重新安装", feature = .0, flag = .1))]
+    FeatureUnavailable(&'static str, &'static str),
+}
+
+impl Error {
+    /// 命令行的进程退出码：1 执行失败，3 配置错误，4 大模型服务不可用，5 存储错误，6 文件读写错误
+    /// （2 留给 clap 的参数错误）
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::ConfigError(_) | Error::FeatureUnavailable(..) => 3,
+            Error::LLMError(_) => 4,
+            #[cfg(feature = "llm")]
+            Error::NetworkError(_) => 4,
+            Error::StorageError(_) => 5,
+            #[cfg(feature = "sled-storage")]
+            Error::BincodeError(_) | Error::SledError(_) => 5,
+            Error::IoError(_) | Error::Utf8Error(_) => 6,
+            _ => 1,
+        }
+    }
 }
 
 // 为其他错误类型提供转换
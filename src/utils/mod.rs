@@ -1,2 +1,3 @@
+pub mod duration;
 pub mod error;
-pub mod string;
\ No newline at end of file
+pub mod string;
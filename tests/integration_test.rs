@@ -8,12 +8,22 @@
 use assert_cmd::Command;
 use predicates::prelude::*;
 use std::fs;
+use std::path::Path;
 
-#[test]
-fn test_basic_generation() -> Result<(), Box<dyn std::error::Error>> {
-
+/// 运行 var-gen：用户配置文件放在 `config_dir` 下，不读写开发者自己的配置
+fn var_gen_command(config_dir: &Path) -> Result<Command, Box<dyn std::error::Error>> {
     #[allow(deprecated)]
     let mut cmd = Command::cargo_bin("var-gen")?;
+    cmd.env("VAR_GEN_CONFIG", config_dir.join("config.toml"))
+       .env_remove("XDG_CONFIG_HOME")
+       .env_remove("VAR_GEN_DEFAULT_STYLE");
+    Ok(cmd)
+}
+
+#[test]
+fn test_basic_generation() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let mut cmd = var_gen_command(dir.path())?;
     
     cmd.arg("--description").arg("user name").arg("--style").arg("snake")
        .env("DATABASE_URL", "test_basic_generation.db");
@@ -26,8 +36,8 @@ fn test_basic_generation() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn test_chinese_generation() -> Result<(), Box<dyn std::error::Error>> {
-    #[allow(deprecated)]
-    let mut cmd = Command::cargo_bin("var-gen")?;
+    let dir = tempfile::tempdir()?;
+    let mut cmd = var_gen_command(dir.path())?;
     
     cmd.arg("--description").arg("用户名").arg("--style").arg("camel")
        .env("DATABASE_URL", "test_chinese_generation.db");
@@ -47,8 +57,7 @@ fn test_batch_processing() -> Result<(), Box<dyn std::error::Error>> {
     // 创建输入文件
     fs::write(&input_path, "user name\npassword\ndatabase connection")?;
     
-    #[allow(deprecated)]
-    let mut cmd = Command::cargo_bin("var-gen")?;
+    let mut cmd = var_gen_command(dir.path())?;
     cmd.arg("--file").arg(input_path.to_str().unwrap())
        .arg("--output").arg(output_path.to_str().unwrap())
        .arg("--style").arg("snake")
@@ -67,8 +76,8 @@ fn test_batch_processing() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn test_all_styles() -> Result<(), Box<dyn std::error::Error>> {
-    #[allow(deprecated)]
-    let mut cmd = Command::cargo_bin("var-gen")?;
+    let dir = tempfile::tempdir()?;
+    let mut cmd = var_gen_command(dir.path())?;
     
     cmd.arg("--all-styles")
        .env("DATABASE_URL", "test_all_styles.db");
@@ -82,4 +91,935 @@ fn test_all_styles() -> Result<(), Box<dyn std::error::Error>> {
         .stdout(predicate::str::contains("小驼峰命名法"));
     
     Ok(())
-}
\ No newline at end of file
+}
+#[test]
+fn test_profile_changes_translation() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let db_path = dir.path().join("test_profile.db");
+    
+    let mut cmd = var_gen_command(dir.path())?;
+    cmd.arg("--description").arg("取消单").arg("--profile").arg("commerce").arg("--force-rule")
+       .env("DATABASE_URL", &db_path);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("order"));
+    
+    let mut cmd = var_gen_command(dir.path())?;
+    cmd.arg("--description").arg("取消单").arg("--profile").arg("support").arg("--force-rule")
+       .env("DATABASE_URL", &db_path);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("ticket"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_unknown_profile_fails() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    
+    let mut cmd = var_gen_command(dir.path())?;
+    cmd.arg("--description").arg("user name").arg("--profile").arg("no-such-profile")
+       .env("DATABASE_URL", dir.path().join("test_unknown_profile.db"));
+    cmd.assert().failure();
+    
+    Ok(())
+}
+
+#[test]
+fn test_acronym_policy_by_language() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    
+    let mut cmd = var_gen_command(dir.path())?;
+    cmd.arg("--description").arg("HTTPServer userID").arg("--style").arg("pascal")
+       .arg("--lang").arg("go").arg("--force-rule")
+       .env("DATABASE_URL", dir.path().join("test_acronym.db"));
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("HTTPServerUserID"));
+    
+    let mut cmd = var_gen_command(dir.path())?;
+    cmd.arg("--description").arg("HTTPServer userID").arg("--style").arg("pascal")
+       .arg("--lang").arg("rust").arg("--force-rule")
+       .env("DATABASE_URL", dir.path().join("test_acronym.db"));
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("HttpServerUserId"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_java_keeps_standard_abbreviations() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    
+    // Java 偏好完整单词，但 id、info 这类通用缩写不展开
+    for (description, expected) in [("获取用户ID", "getUserId"), ("用户信息", "userInfo")] {
+        let mut cmd = var_gen_command(dir.path())?;
+        cmd.args(["gen", description, "--lang", "java", "--force-rule"])
+           .env("DATABASE_URL", dir.path().join("test_java.db"));
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(expected))
+            .stdout(predicate::str::contains("Identifier").not())
+            .stdout(predicate::str::contains("Information").not());
+    }
+    
+    // lint 不要求把 userId 改成 userIdentifier
+    let source = dir.path().join("User.java");
+    fs::write(&source, "class User {\n    int userId;\n    String identifier;\n}\n")?;
+    let mut cmd = var_gen_command(dir.path())?;
+    cmd.arg("lint").arg(&source);
+    cmd.assert().success().stdout(predicate::str::contains("inconsistent-abbreviation").not());
+    
+    Ok(())
+}
+
+#[test]
+fn test_additional_styles() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    
+    for (style, expected) in [
+        ("dot", "content.type"),
+        ("train", "Content-Type"),
+        ("cobol", "CONTENT-TYPE"),
+        ("namespace", "content::type"),
+    ] {
+        let mut cmd = var_gen_command(dir.path())?;
+        cmd.arg("--description").arg("content type").arg("--style").arg(style).arg("--force-rule")
+           .env("DATABASE_URL", dir.path().join("test_additional_styles.db"));
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(expected));
+    }
+    
+    Ok(())
+}
+
+#[test]
+fn test_convert_subcommand() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempfile::tempdir()?;
+    let mut cmd = var_gen_command(dir.path())?;
+    cmd.arg("convert").arg("getUserID").arg("XMLHttpRequest").arg("--to").arg("snake");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("get_user_id"))
+        .stdout(predicate::str::contains("xml_http_request"));
+    
+    let mut cmd = var_gen_command(dir.path())?;
+    cmd.arg("convert").arg("--to").arg("camel").arg("--json")
+       .write_stdin("user_name\nutf8_decoder\n");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let conversions: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(conversions[0]["output"], "userName");
+    assert_eq!(conversions[0]["from"], "snake");
+    assert_eq!(conversions[1]["output"], "utf8Decoder");
+    
+    Ok(())
+}
+
+#[test]
+fn test_lint_subcommand() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempfile::tempdir()?;
+    std::fs::write(
+        temp_dir.path().join("app.py"),
+        "def getUser():\n    pass\n\nyonghu_xinxi = {}\n",
+    )?;
+    std::fs::write(temp_dir.path().join("main.go"), "package main\n\nfunc loadUser() {}\n")?;
+    
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.arg("lint").arg(temp_dir.path());
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("app.py:1:5: [naming-style]"))
+        .stdout(predicate::str::contains("get_user"))
+        .stdout(predicate::str::contains("app.py:4:1: [pinyin-identifier]"))
+        .stdout(predicate::str::contains("main.go").not());
+    
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.arg("lint").arg(temp_dir.path()).arg("--format").arg("sarif");
+    let output = cmd.assert().failure().get_output().stdout.clone();
+    let sarif: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(sarif["version"], "2.1.0");
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["ruleId"], "naming-style");
+    assert_eq!(results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"], "app.py");
+    
+    // 没有问题时成功退出
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.arg("lint").arg(temp_dir.path().join("main.go"));
+    cmd.assert().success();
+    
+    Ok(())
+}
+
+#[test]
+fn test_suggest_rename_offline() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempfile::tempdir()?;
+    
+    // 拼音首字母缩写按映射词典解码，并沿用原标识符的风格
+    for (identifier, expected) in [("yhxx", "user_info"), ("getYhxxList", "getUserInfoList"), ("tmp", "temporary")] {
+        let mut cmd = var_gen_command(temp_dir.path())?;
+        cmd.arg("suggest-rename").arg(identifier).arg("--force-rule")
+           .env("DATABASE_URL", temp_dir.path().join("test_rename.db"));
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(expected));
+    }
+    
+    // 指定命名规范时按指定规范输出
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.arg("suggest-rename").arg("yhxx").arg("--style").arg("pascal").arg("--force-rule")
+       .env("DATABASE_URL", temp_dir.path().join("test_rename.db"));
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("UserInfo"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_context_file() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempfile::tempdir()?;
+    let source = temp_dir.path().join("cart.js");
+    fs::write(&source, "// header\nconst itemCount = 0;\nlet totalPrice = 0;\nlet orderId = load();\n")?;
+    
+    // 未指定风格时沿用上下文中的风格，并提示与已有名称重复
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.arg("--description").arg("total price").arg("--force-rule")
+       .arg("--context-file").arg(format!("{}:2-3", source.display()))
+       .env("DATABASE_URL", temp_dir.path().join("test_context.db"));
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("totalPrice"))
+        .stderr(predicate::str::contains("totalPrice"));
+    
+    // 从标准输入读取上下文
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.arg("--description").arg("user name").arg("--force-rule")
+       .arg("--context-file").arg("-").arg("--lang").arg("python")
+       .write_stdin("class OrderService:\n    def load_items(self):\n        item_count = 0\n")
+       .env("DATABASE_URL", temp_dir.path().join("test_context.db"));
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("user_name"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_check_collisions() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempfile::tempdir()?;
+    let project = temp_dir.path().join("project");
+    fs::create_dir_all(project.join("src"))?;
+    fs::write(project.join("src").join("user.rs"), "fn main() {\n    let user_name = load();\n}\n")?;
+    
+    // 重名的候选会标出位置并给出替代名称；第二次运行使用缓存的索引
+    for _ in 0..2 {
+        let mut cmd = var_gen_command(temp_dir.path())?;
+        cmd.arg("--description").arg("user name").arg("--force-rule").arg("--style").arg("snake")
+           .arg("--check-collisions").arg(&project)
+           .env("DATABASE_URL", temp_dir.path().join("test_collisions.db"));
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("已存在: src/user.rs:2"))
+            .stdout(predicate::str::contains("new_user_name"));
+    }
+    
+    Ok(())
+}
+
+/// 按 LSP 的格式封装一条消息
+fn lsp_message(message: serde_json::Value) -> String {
+    let body = message.to_string();
+    format!("Content-Length: {}\r\n\r\n{}", body.len(), body)
+}
+
+#[test]
+fn test_lsp_scripted_client() -> Result<(), Box<dyn std::error::Error>> {
+    use serde_json::json;
+    
+    let temp_dir = tempfile::tempdir()?;
+    let uri = "file:///project/src/main.rs";
+    let text = "fn main() {\n    // name: user name\n    let yhxx = load();\n}\n";
+    let script: String = [
+        json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {"capabilities": {}}}),
+        json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+        json!({"jsonrpc": "2.0", "method": "textDocument/didOpen", "params": {
+            "textDocument": {"uri": uri, "languageId": "rust", "version": 1, "text": text}
+        }}),
+        json!({"jsonrpc": "2.0", "id": 2, "method": "textDocument/completion", "params": {
+            "textDocument": {"uri": uri}, "position": {"line": 1, "character": 22}
+        }}),
+        json!({"jsonrpc": "2.0", "id": 3, "method": "textDocument/codeAction", "params": {
+            "textDocument": {"uri": uri},
+            "range": {"start": {"line": 2, "character": 9}, "end": {"line": 2, "character": 9}},
+            "context": {"diagnostics": [], "triggerKind": 1}
+        }}),
+        json!({"jsonrpc": "2.0", "id": 4, "method": "shutdown"}),
+        json!({"jsonrpc": "2.0", "method": "exit"}),
+    ]
+    .into_iter()
+    .map(lsp_message)
+    .collect();
+    
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.arg("lsp").arg("--force-rule")
+       .write_stdin(script)
+       .env("DATABASE_URL", temp_dir.path().join("test_lsp.db"));
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Content-Length"))
+        .stdout(predicate::str::contains("\"completionProvider\""))
+        .stdout(predicate::str::contains("\"label\":\"user_name\""))
+        .stdout(predicate::str::contains("转换为 Yhxx (pascal)"))
+        .stdout(predicate::str::contains("从注释生成名称: user_name"))
+        .stdout(predicate::str::contains("重命名为建议名称: user_info"))
+        .stdout(predicate::str::contains("\"result\":null"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_serve_http_api() -> Result<(), Box<dyn std::error::Error>> {
+    use serde_json::{json, Value};
+    
+    let temp_dir = tempfile::tempdir()?;
+    let port = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?.port();
+    let base = format!("http://127.0.0.1:{}", port);
+    
+    let mut server = std::process::Command::new(env!("CARGO_BIN_EXE_var-gen"))
+        .args(["serve", "--force-rule", "--token", "secret", "--bind"])
+        .arg(format!("127.0.0.1:{}", port))
+        .env("DATABASE_URL", temp_dir.path().join("test_serve.db"))
+        .env("VAR_GEN_CONFIG", temp_dir.path().join("config.toml"))
+        .env_remove("XDG_CONFIG_HOME")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+    
+    let result = (|| -> Result<(), Box<dyn std::error::Error>> {
+        let client = reqwest::blocking::Client::new();
+        
+        // 等待服务启动
+        let mut ready = false;
+        for _ in 0..100 {
+            if client.get(format!("{}/health", base)).send().is_ok_and(|r| r.status().is_success()) {
+                ready = true;
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        assert!(ready, "服务未能启动");
+        
+        let unauthorized = client.post(format!("{}/generate", base)).json(&json!({"description": "user name"})).send()?;
+        assert_eq!(unauthorized.status(), 401);
+        
+        let generation: Value = client
+            .post(format!("{}/generate", base))
+            .bearer_auth("secret")
+            .json(&json!({"description": "user name", "style": "camel"}))
+            .send()?
+            .json()?;
+        assert_eq!(generation["style"], "camel");
+        assert_eq!(generation["names"][0], "userName");
+        
+        let batch: Value = client
+            .post(format!("{}/batch", base))
+            .bearer_auth("secret")
+            .json(&json!({"descriptions": ["user name", "order id"], "style": "snake"}))
+            .send()?
+            .json()?;
+        assert_eq!(batch[1]["description"], "order id");
+        assert_eq!(batch[1]["names"][0], "order_id");
+        
+        let conversions: Value = client
+            .post(format!("{}/convert", base))
+            .bearer_auth("secret")
+            .json(&json!({"identifiers": ["userID"], "to": "snake"}))
+            .send()?
+            .json()?;
+        assert_eq!(conversions[0]["output"], "user_id");
+        
+        let history: Value = client.get(format!("{}/history?limit=5", base)).bearer_auth("secret").send()?.json()?;
+        assert_eq!(history[0]["variable_name"], "userName");
+        
+        let mappings: Value = client.get(format!("{}/mappings?q=用户", base)).bearer_auth("secret").send()?.json()?;
+        assert_eq!(mappings["用户"], "user");
+        
+        Ok(())
+    })();
+    
+    server.kill()?;
+    server.wait()?;
+    result
+}
+
+#[test]
+fn test_storage_backends() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempfile::tempdir()?;
+    
+    // 只列出命名规范时不打开存储，无效的后端也不影响
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.arg("--all-styles").env("VAR_GEN_STORAGE", "redis");
+    cmd.assert().success().stdout(predicate::str::contains("驼峰命名法"));
+    
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.args(["-d", "用户信息", "-s", "camel", "--force-rule"]).env("VAR_GEN_STORAGE", "redis");
+    cmd.assert().failure().stderr(predicate::str::contains("未知的存储后端"));
+    
+    // 内存后端不写任何文件
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.args(["-d", "用户信息", "-s", "camel", "--force-rule"])
+       .current_dir(temp_dir.path())
+       .env("VAR_GEN_STORAGE", "memory")
+       .env("DATABASE_URL", temp_dir.path().join("unused.db"));
+    cmd.assert().success().stdout(predicate::str::contains("userInfo"));
+    assert!(!temp_dir.path().join("unused.db").exists());
+    
+    // JSON 文件后端
+    let store_path = temp_dir.path().join("store.json");
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.args(["-d", "用户信息", "-s", "snake", "--force-rule"])
+       .env("VAR_GEN_STORAGE", "json")
+       .env("DATABASE_URL", &store_path);
+    cmd.assert().success();
+    let mut cmd = var_gen_command(temp_dir.path())?;
+    cmd.arg("--history")
+       .env("VAR_GEN_STORAGE", "json")
+       .env("DATABASE_URL", &store_path);
+    cmd.assert().success().stdout(predicate::str::contains("user_info"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_api_key_sources() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempfile::tempdir()?;
+    let db = home.path().join("test_keys.db");
+    
+    // 从标准输入读取密钥，写入 0600 的密钥文件
+    let mut cmd = var_gen_command(home.path())?;
+    cmd.args(["--set-api-key", "-"])
+       .env("HOME", home.path())
+       .env("DATABASE_URL", &db)
+       .env_remove("VAR_GEN_QWEN_API_KEY")
+       .write_stdin("sk-test-1234\n");
+    cmd.assert().success().stdout(predicate::str::contains("SHA256:")).stdout(predicate::str::contains("sk-test").not());
+    
+    let key_file = home.path().join(".var-gen").join("keys").join("qwen.key");
+    assert_eq!(fs::read_to_string(&key_file)?, "sk-test-1234");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(fs::metadata(&key_file)?.permissions().mode() & 0o777, 0o600);
+    }
+    
+    let mut cmd = var_gen_command(home.path())?;
+    cmd.args(["config", "show"])
+       .env("HOME", home.path())
+       .env("DATABASE_URL", &db)
+       .env_remove("VAR_GEN_QWEN_API_KEY");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("密钥文件"))
+        .stdout(predicate::str::contains("sk-test").not());
+    
+    // 环境变量优先于密钥文件
+    let mut cmd = var_gen_command(home.path())?;
+    cmd.args(["config", "show"])
+       .env("HOME", home.path())
+       .env("DATABASE_URL", &db)
+       .env("VAR_GEN_QWEN_API_KEY", "sk-from-env");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("环境变量 VAR_GEN_QWEN_API_KEY"))
+        .stdout(predicate::str::contains("sk-from-env").not());
+    
+    // 加密保存后需要口令才能读取
+    let mut cmd = var_gen_command(home.path())?;
+    cmd.args(["--set-api-key", "sk-encrypted", "--encrypt"])
+       .env("HOME", home.path())
+       .env("DATABASE_URL", &db)
+       .env("VAR_GEN_KEY_PASSPHRASE", "passphrase")
+       .env_remove("VAR_GEN_QWEN_API_KEY");
+    cmd.assert().success();
+    assert!(!key_file.exists());
+    
+    let mut cmd = var_gen_command(home.path())?;
+    cmd.args(["config", "show"])
+       .env("HOME", home.path())
+       .env("DATABASE_URL", &db)
+       .env_remove("VAR_GEN_QWEN_API_KEY")
+       .env_remove("VAR_GEN_KEY_PASSPHRASE");
+    cmd.assert().success().stdout(predicate::str::contains("已加密保存"));
+    
+    let mut cmd = var_gen_command(home.path())?;
+    cmd.args(["config", "show"])
+       .env("HOME", home.path())
+       .env("DATABASE_URL", &db)
+       .env("VAR_GEN_KEY_PASSPHRASE", "passphrase")
+       .env_remove("VAR_GEN_QWEN_API_KEY");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("加密存储"))
+        .stdout(predicate::str::contains("sk-encrypted").not());
+    
+    Ok(())
+}
+
+#[test]
+fn test_provider_selection() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempfile::tempdir()?;
+    let db = home.path().join("test_providers.db");
+    let var_gen = |args: &[&str]| -> Result<Command, Box<dyn std::error::Error>> {
+        let mut cmd = var_gen_command(home.path())?;
+        cmd.args(args)
+           .env("HOME", home.path())
+           .env("DATABASE_URL", &db)
+           .env_remove("VAR_GEN_QWEN_API_KEY")
+           .env("VAR_GEN_XINGHUO_API_KEY", "spark-password");
+        Ok(cmd)
+    };
+    
+    var_gen(&["provider", "list"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("* qwen"))
+        .stdout(predicate::str::contains("环境变量 VAR_GEN_XINGHUO_API_KEY"))
+        .stdout(predicate::str::contains("spark-password").not());
+    
+    // 切换默认服务商和模型
+    var_gen(&["provider", "use", "xinghuo", "--model", "generalv3.5"])?.assert().success();
+    var_gen(&["config", "show"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("服务商: xinghuo"))
+        .stdout(predicate::str::contains("模型: generalv3.5"));
+    
+    // 命令行选择只对本次生效
+    var_gen(&["--model", "qwen-max", "config", "show"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("服务商: qwen"))
+        .stdout(predicate::str::contains("模型: qwen-max"));
+    var_gen(&["config", "show"])?.assert().success().stdout(predicate::str::contains("服务商: xinghuo"));
+    
+    // 各服务商的密钥分别保存，格式按服务商检查
+    var_gen(&["--provider", "qwen", "--set-api-key", "not-a-qwen-key"])?
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("sk-"));
+    var_gen(&["--provider", "qwen", "--set-api-key", "sk-qwen"])?.assert().success();
+    assert!(home.path().join(".var-gen/keys/qwen.key").exists());
+    assert!(!home.path().join(".var-gen/keys/xinghuo.key").exists());
+    
+    // 未配置密钥的服务商检查失败，不发出请求
+    let empty_home = tempfile::tempdir()?;
+    var_gen(&["provider", "test", "qwen"])?
+        .env("HOME", empty_home.path())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("未配置"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_config_get_set_unset() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempfile::tempdir()?;
+    let store = home.path().join("store.json");
+    let var_gen = |args: &[&str]| -> Result<Command, Box<dyn std::error::Error>> {
+        let mut cmd = var_gen_command(home.path())?;
+        cmd.args(args)
+           .env("HOME", home.path())
+           // 检查默认位置的配置文件：HOME 指向临时目录，XDG_CONFIG_HOME 已移除
+           .env_remove("VAR_GEN_CONFIG")
+           .env("VAR_GEN_STORAGE", "json")
+           .env("DATABASE_URL", &store);
+        Ok(cmd)
+    };
+    
+    var_gen(&["config", "set", "default_style", "camel"])?.assert().success();
+    var_gen(&["config", "set", "cache_ttl", "12h"])?.assert().success();
+    var_gen(&["config", "get", "default_style"])?.assert().success().stdout("camel\n");
+    var_gen(&["config", "get", "cache_ttl"])?.assert().success().stdout("12h\n");
+    var_gen(&["config", "list"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("cache_enabled"))
+        .stdout(predicate::str::contains("mapping_config_path"));
+    
+    // 无效的值在保存前报错
+    var_gen(&["config", "set", "cache_ttl", "12x"])?
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("无效的时长"));
+    var_gen(&["config", "set", "default_style", "foo"])?.assert().failure();
+    var_gen(&["config", "get", "no_such_key"])?.assert().failure();
+    
+    var_gen(&["config", "unset", "default_style"])?.assert().success();
+    var_gen(&["config", "get", "default_style"])?.assert().success().stdout("");
+    
+    // 配置文件中被改坏的值直接报错，并提示如何恢复
+    let config_file = home.path().join(".config/var-gen/config.toml");
+    let content = std::fs::read_to_string(&config_file)?.replace("\"12h\"", "\"forever\"");
+    std::fs::write(&config_file, content)?;
+    var_gen(&["config", "show"])?
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("无效的时长"))
+        .stderr(predicate::str::contains("config unset"));
+    var_gen(&["config", "unset", "cache_ttl"])?.assert().success();
+    var_gen(&["config", "get", "cache_ttl"])?.assert().success().stdout("1d\n");
+    
+    Ok(())
+}
+
+#[test]
+fn test_default_style_precedence() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempfile::tempdir()?;
+    let project = tempfile::tempdir()?;
+    let store = home.path().join("store.json");
+    let var_gen = |args: &[&str]| -> Result<Command, Box<dyn std::error::Error>> {
+        let mut cmd = var_gen_command(home.path())?;
+        cmd.args(args)
+           .current_dir(project.path())
+           .env("HOME", home.path())
+           .env("VAR_GEN_STORAGE", "json")
+           .env("DATABASE_URL", &store)
+           .env_remove("VAR_GEN_QWEN_API_KEY");
+        Ok(cmd)
+    };
+    let generate = |extra: &[&str]| -> Result<Command, Box<dyn std::error::Error>> {
+        let mut args = vec!["--description", "用户信息", "--force-rule"];
+        args.extend_from_slice(extra);
+        var_gen(&args)
+    };
+    
+    // 没有任何配置时按语言惯例，否则为 snake
+    generate(&[])?.assert().success().stdout(predicate::str::contains("user_info"));
+    generate(&["--lang", "typescript"])?.assert().success().stdout(predicate::str::contains("userInfo"));
+    
+    // 用户配置优先于语言惯例
+    var_gen(&["config", "set", "default_style", "kebab"])?.assert().success();
+    generate(&["--lang", "typescript"])?.assert().success().stdout(predicate::str::contains("user-info"));
+    
+    // 项目配置优先于用户配置，命令行参数优先于所有配置
+    std::fs::write(project.path().join(".var-gen.toml"), "default_style = \"pascal\"\n")?;
+    generate(&[])?.assert().success().stdout(predicate::str::contains("UserInfo"));
+    generate(&["--style", "upper_snake"])?.assert().success().stdout(predicate::str::contains("USER_INFO"));
+    
+    var_gen(&["config", "explain"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("生成时使用的命名规范: pascal（来自项目配置 .var-gen.toml）"))
+        .stdout(predicate::str::contains("* 项目配置"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_config_file_layers() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempfile::tempdir()?;
+    let config_file = home.path().join("config.toml");
+    let var_gen = |args: &[&str]| -> Result<Command, Box<dyn std::error::Error>> {
+        let mut cmd = var_gen_command(home.path())?;
+        cmd.args(args)
+           .current_dir(home.path())
+           .env("HOME", home.path())
+           .env("VAR_GEN_CONFIG", &config_file)
+           .env("VAR_GEN_STORAGE", "memory")
+           .env_remove("VAR_GEN_CACHE_TTL");
+        Ok(cmd)
+    };
+    
+    // 手写的配置文件，有效期可以写秒数
+    std::fs::write(&config_file, "# 团队共享的配置\ndefault_style = \"kebab\"\ncache_ttl = 7200\n")?;
+    var_gen(&["config", "get", "cache_ttl"])?.assert().success().stdout("2h\n");
+    var_gen(&["config", "path"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("config.toml"));
+    
+    // 环境变量优先于配置文件，命令行参数优先于环境变量
+    var_gen(&["config", "explain"])?
+        .env("VAR_GEN_DEFAULT_STYLE", "pascal")
+        .env("VAR_GEN_CACHE_TTL", "30m")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("cache_ttl            30m                      来自环境变量"))
+        .stdout(predicate::str::contains("生成时使用的命名规范: pascal（来自环境变量）"));
+    var_gen(&["--description", "用户信息", "--force-rule", "--style", "camel"])?
+        .env("VAR_GEN_DEFAULT_STYLE", "pascal")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("userInfo"));
+    var_gen(&["--description", "用户信息", "--force-rule"])?
+        .env("VAR_GEN_DEFAULT_STYLE", "not-a-style")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("VAR_GEN_DEFAULT_STYLE"));
+    
+    // 旧版本保存在存储中的配置在首次运行时迁移到配置文件，明文密钥迁移到密钥文件
+    std::fs::remove_file(&config_file)?;
+    let store = home.path().join("store.json");
+    std::fs::write(
+        &store,
+        r#"{"config": {"default_style": "camel", "model": "generalv3.5", "api_key": "spark-password"}}"#,
+    )?;
+    var_gen(&["config", "show"])?
+        .env("VAR_GEN_STORAGE", "json")
+        .env("DATABASE_URL", &store)
+        .env_remove("VAR_GEN_XINGHUO_API_KEY")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("服务商: xinghuo"))
+        .stdout(predicate::str::contains("默认命名规范: camel"))
+        .stdout(predicate::str::contains("xinghuo.key"))
+        .stderr(predicate::str::contains("已将配置从存储迁移到"));
+    let migrated = std::fs::read_to_string(&config_file)?;
+    assert!(migrated.contains("default_style = \"camel\""));
+    assert!(migrated.contains("xinghuo = \"generalv3.5\""));
+    assert!(!std::fs::read_to_string(&store)?.contains("spark-password"));
+    
+    // 拼错的配置项报错而不是被忽略
+    std::fs::write(&config_file, "default_styel = \"kebab\"\n")?;
+    var_gen(&["config", "show"])?
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("default_styel"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_subcommands_and_legacy_flags() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempfile::tempdir()?;
+    let store = home.path().join("store.json");
+    let var_gen = |args: &[&str]| -> Result<Command, Box<dyn std::error::Error>> {
+        let mut cmd = var_gen_command(home.path())?;
+        cmd.args(args)
+           .env("HOME", home.path())
+           .env("VAR_GEN_STORAGE", "json")
+           .env("DATABASE_URL", &store);
+        Ok(cmd)
+    };
+    
+    var_gen(&["gen", "用户信息", "-s", "camel", "--force-rule"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("userInfo"))
+        .stderr(predicate::str::contains("已弃用").not());
+    
+    let input = home.path().join("input.txt");
+    fs::write(&input, "用户信息\n订单编号\n")?;
+    var_gen(&["batch", input.to_str().unwrap(), "-s", "kebab", "--force-rule", "--json"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("user-info"));
+    
+    var_gen(&["history", "--limit", "1", "--json"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("userInfo"));
+    let exported = home.path().join("history.json");
+    var_gen(&["history", "export", exported.to_str().unwrap()])?.assert().success();
+    var_gen(&["history", "clear", "--yes"])?.assert().success();
+    var_gen(&["history"])?.assert().success().stdout(predicate::str::contains("暂无历史记录"));
+    var_gen(&["history", "import", exported.to_str().unwrap()])?.assert().success();
+    var_gen(&["history"])?.assert().success().stdout(predicate::str::contains("userInfo"));
+    
+    var_gen(&["styles"])?.assert().success().stdout(predicate::str::contains("驼峰命名法"));
+    var_gen(&["--profile", "commerce", "profiles"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("commerce"));
+    var_gen(&["cache", "clear", "--yes"])?.assert().success().stdout(predicate::str::contains("缓存已清除"));
+    
+    let mapping = home.path().join("mapping.toml");
+    var_gen(&["mapping", "init", mapping.to_str().unwrap()])?.assert().success();
+    var_gen(&["mapping", "init", mapping.to_str().unwrap()])?.assert().code(3);
+    var_gen(&["--mapping-config", mapping.to_str().unwrap(), "mapping", "check"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("条映射"));
+    
+    // 旧写法仍可使用，但会提示改用子命令
+    var_gen(&["-d", "用户信息", "-s", "snake", "--force-rule"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("user_info"))
+        .stderr(predicate::str::contains("请改用 `var-gen gen <描述>`"));
+    
+    // 互相冲突的参数报错，而不是忽略其中一个
+    var_gen(&["--history", "--all-styles"])?.assert().code(2);
+    var_gen(&["--history", "-s", "camel"])?.assert().code(2);
+    var_gen(&["-d", "用户信息", "--file", input.to_str().unwrap()])?.assert().code(2);
+    var_gen(&["--history", "styles"])?.assert().code(2).stderr(predicate::str::contains("子命令"));
+    
+    // 不同类型的错误使用不同的退出码
+    var_gen(&["config", "set", "cache_ttl", "forever"])?.assert().code(3);
+    var_gen(&["batch", home.path().join("missing.txt").to_str().unwrap(), "--force-rule"])?.assert().code(6);
+    
+    Ok(())
+}
+
+#[test]
+fn test_completions_and_man() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempfile::tempdir()?;
+    let var_gen = |args: &[&str]| -> Result<Command, Box<dyn std::error::Error>> {
+        let mut cmd = var_gen_command(home.path())?;
+        cmd.args(args)
+           .env("HOME", home.path())
+           .env_remove("VAR_GEN_MAPPING_CONFIG_PATH")
+           .env("VAR_GEN_STORAGE", "memory");
+        Ok(cmd)
+    };
+    
+    var_gen(&["completions", "bash"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("VAR_GEN_COMPLETE=\"bash\""));
+    var_gen(&["completions", "zsh", "--static"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("#compdef var-gen"));
+    
+    // 补全脚本回调时给出配置档、配置项、服务商和映射词
+    let complete = |words: &[&str]| -> Result<Command, Box<dyn std::error::Error>> {
+        let mut cmd = var_gen(&[&["--", "var-gen"], words].concat())?;
+        cmd.env("VAR_GEN_COMPLETE", "fish");
+        Ok(cmd)
+    };
+    complete(&["--profile", ""])?.assert().success().stdout(predicate::str::contains("commerce"));
+    complete(&["config", "set", ""])?.assert().success().stdout(predicate::str::contains("cache_ttl"));
+    complete(&["--provider", ""])?.assert().success().stdout(predicate::str::contains("xinghuo"));
+    complete(&["gen", "-s", ""])?.assert().success().stdout(predicate::str::contains("upper_snake"));
+    complete(&["gen", "用户"])?.assert().success().stdout(predicate::str::contains("用户名\tusername"));
+    
+    let man_dir = home.path().join("man1");
+    var_gen(&["man", "--output", man_dir.to_str().unwrap()])?.assert().success();
+    assert!(fs::read_to_string(man_dir.join("var-gen.1"))?.contains("退出码"));
+    assert!(man_dir.join("var-gen-gen.1").exists());
+    assert!(man_dir.join("var-gen-config-set.1").exists());
+    var_gen(&["man"])?.assert().success().stdout(predicate::str::contains(".TH var-gen 1"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_ui_language() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempfile::tempdir()?;
+    let var_gen = |args: &[&str]| -> Result<Command, Box<dyn std::error::Error>> {
+        let mut cmd = var_gen_command(home.path())?;
+        cmd.args(args)
+           .env("HOME", home.path())
+           .env_remove("VAR_GEN_UI_LANG")
+           .env_remove("LC_ALL")
+           .env_remove("LC_MESSAGES")
+           .env_remove("LANG")
+           .env("VAR_GEN_STORAGE", "memory");
+        Ok(cmd)
+    };
+    
+    // 默认使用中文
+    var_gen(&["styles"])?.assert().success().stdout(predicate::str::contains("驼峰命名法"));
+    var_gen(&["--lang-ui", "en-US", "styles"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Camel case"));
+    var_gen(&["styles"])?
+        .env("LANG", "en_US.UTF-8")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Camel case"));
+    // 命令行参数优先于环境变量
+    var_gen(&["--lang-ui", "zh", "styles"])?
+        .env("VAR_GEN_UI_LANG", "en-US")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("驼峰命名法"));
+    
+    // 帮助和错误信息同样翻译
+    var_gen(&["--lang-ui", "en-US", "--help"])?
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Generate identifiers from a description"))
+        .stdout(predicate::str::contains("Exit codes:"));
+    var_gen(&["--lang-ui", "en-US", "config", "set", "cache_ttl", "abc"])?
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("Error: Config error: Invalid duration"));
+    
+    // 保存到用户配置后持续生效
+    var_gen(&["config", "set", "ui_lang", "en-US"])?.assert().success();
+    var_gen(&["cache", "clear", "--yes"])?.assert().success().stdout(predicate::str::contains("Cache cleared"));
+    var_gen(&["config", "get", "ui_lang"])?.assert().success().stdout(predicate::str::contains("en-US"));
+    var_gen(&["config", "set", "ui_lang", "fr"])?
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("Unknown UI language: fr"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_interactive_commands() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempfile::tempdir()?;
+    let names = home.path().join("names.txt");
+    let input = format!(
+        "用户信息\n:style camel\n:kind fn\n:regen\n:style kebab\n:pick 1\n:copy {}\n:pick 9\n:bogus\n订单列表\n:copy\n:history\n",
+        names.display()
+    );
+    let mut cmd = var_gen_command(home.path())?;
+    cmd.args(["interactive", "--force-rule"])
+       .env("HOME", home.path())
+       .env("VAR_GEN_UI_LANG", "zh-CN")
+       .env("VAR_GEN_STORAGE", "json")
+       .write_stdin(input);
+    
+    // 设置一直生效，生成后不再询问命名规范和是否保存；历史记录保存生成候选时的命名规范
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1. user_info"))
+        .stdout(predicate::str::contains("1. userInfo"))
+        .stdout(predicate::str::contains("标识符类型: function"))
+        .stdout(predicate::str::contains("已选择 userInfo"))
+        .stdout(predicate::str::contains("(描述: \"用户信息\", 风格: 驼峰命名法 (camelCase))"))
+        .stderr(predicate::str::contains("没有第 9 个候选名称"))
+        .stderr(predicate::str::contains("未知的命令: :bogus"));
+    
+    // :copy 记住上次的文件，未选择时追加第一个候选
+    let copied = fs::read_to_string(&names)?;
+    assert_eq!(copied.lines().count(), 2);
+    assert_eq!(copied.lines().next(), Some("userInfo"));
+    
+    // 输入过的描述保存下来，下次可以用 ↑ 回溯
+    let history = fs::read_to_string(home.path().join(".var-gen").join("repl_history"))?;
+    assert!(history.contains("用户信息") && history.contains("订单列表"));
+    assert!(!history.contains(":pick"));
+    
+    Ok(())
+}
+
+#[test]
+fn test_tui_falls_back_without_terminal() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempfile::tempdir()?;
+    let mut cmd = var_gen_command(home.path())?;
+    cmd.args(["tui", "--force-rule", "--style", "camel"])
+       .env("HOME", home.path())
+       .env("VAR_GEN_UI_LANG", "zh-CN")
+       .env("VAR_GEN_STORAGE", "memory")
+       .write_stdin("用户信息\n:pick 1\n");
+    
+    // 输出被管道捕获时不进入全屏界面，而是按交互式模式处理输入
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("改用交互式模式"))
+        .stdout(predicate::str::contains("1. userInfo"))
+        .stdout(predicate::str::contains("已选择 userInfo"));
+    
+    Ok(())
+}